@@ -29,6 +29,17 @@ pub trait LockWithEnergyModule {
         energy_address: ManagedAddress,
     ) -> EsdtTokenPayment {
         let lock_epochs = self.lock_epochs().get();
+        self.lock_virtual_for_epochs(token_id, amount, lock_epochs, dest_address, energy_address)
+    }
+
+    fn lock_virtual_for_epochs(
+        &self,
+        token_id: TokenIdentifier,
+        amount: BigUint,
+        lock_epochs: u64,
+        dest_address: ManagedAddress,
+        energy_address: ManagedAddress,
+    ) -> EsdtTokenPayment {
         let mut proxy_instance = self.get_locking_sc_proxy_instance();
 
         proxy_instance
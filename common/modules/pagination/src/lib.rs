@@ -0,0 +1,71 @@
+#![no_std]
+
+use core::ops::Range;
+
+multiversx_sc::imports!();
+
+use multiversx_sc::api::{ErrorApiImpl, StorageMapperApi};
+
+pub const UNKNOWN_START_ITEM_ERR_MSG: &[u8] = b"Unknown start item";
+
+pub type PaginatedResult<M, T> = MultiValue2<usize, MultiValueEncoded<M, T>>;
+
+pub type KeyPaginatedResult<M, K, T> = MultiValue2<Option<K>, MultiValueEncoded<M, T>>;
+
+/// A page of up to `limit` items, out of `total_len`, starting from `start_index`.
+///
+/// The indexes are only a stable cursor while no items are removed between the calls.
+/// The unordered set mappers move their last item into the removed item's place,
+/// so a removal can make the following pages skip or repeat items.
+pub struct Page {
+    /// The 0-based indexes of the items in the page.
+    pub indexes: Range<usize>,
+    /// The index to continue from, which is 0 once the end was reached.
+    pub next_index: usize,
+}
+
+pub fn get_page(start_index: usize, limit: usize, total_len: usize) -> Page {
+    let end_index = core::cmp::min(start_index.saturating_add(limit), total_len);
+    let next_index = if end_index < total_len { end_index } else { 0 };
+
+    Page {
+        indexes: start_index..end_index,
+        next_index,
+    }
+}
+
+/// Calls `f` for up to `limit` items of the set, starting from `opt_start_item`, or from the first one.
+/// The set keeps the order of the other items on removals, so the items themselves are a stable cursor.
+///
+/// Returns the item to continue from, which is None once the end was reached.
+pub fn for_each_in_key_page<SA, T, F>(
+    set: &SetMapper<SA, T>,
+    limit: usize,
+    opt_start_item: OptionalValue<T>,
+    mut f: F,
+) -> Option<T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode,
+    F: FnMut(T),
+{
+    let mut items_iter = match opt_start_item {
+        OptionalValue::Some(start_item) => {
+            if !set.contains(&start_item) {
+                SA::error_api_impl().signal_error(UNKNOWN_START_ITEM_ERR_MSG);
+            }
+
+            set.iter_from(&start_item)
+        }
+        OptionalValue::None => set.iter(),
+    };
+
+    for _ in 0..limit {
+        match items_iter.next() {
+            Some(item) => f(item),
+            None => return None,
+        }
+    }
+
+    items_iter.next()
+}
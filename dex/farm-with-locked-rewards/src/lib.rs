@@ -13,6 +13,7 @@ use farm::{
     exit_penalty::{
         DEFAULT_BURN_GAS_LIMIT, DEFAULT_MINUMUM_FARMING_EPOCHS, DEFAULT_PENALTY_PERCENT,
     },
+    migration::MigrateToFarmResultType,
     EnterFarmResultType, ExitFarmWithPartialPosResultType, MAX_PERCENT,
 };
 use farm_base_impl::base_traits_impl::FarmContract;
@@ -32,6 +33,7 @@ pub trait Farm:
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + farm::base_functions::BaseFunctionsModule
     + farm::exit_penalty::ExitPenaltyModule
+    + farm::migration::FarmMigrationModule
     + farm_base_impl::base_farm_init::BaseFarmInitModule
     + farm_base_impl::base_farm_validation::BaseFarmValidationModule
     + farm_base_impl::enter_farm::BaseEnterFarmModule
@@ -174,6 +176,36 @@ pub trait Farm:
         (exit_farm_result.farming_tokens, locked_rewards_payment).into()
     }
 
+    /// Exits the farm without penalty and enters the given target farm with the farming tokens,
+    /// on behalf of the original caller. The target farm must be a whitelisted migration target.
+    /// Returns the new farm token, the locked rewards of this farm and the boosted rewards of the target farm.
+    #[payable("*")]
+    #[endpoint(migrateToFarm)]
+    fn migrate_to_farm_endpoint(
+        &self,
+        target_farm: ManagedAddress,
+        opt_orig_caller: OptionalValue<ManagedAddress>,
+    ) -> MigrateToFarmResultType<Self::Api> {
+        let caller = self.blockchain().get_caller();
+        let migrate_result =
+            self.migrate_to_farm::<NoMintWrapper<Self>>(&caller, target_farm, opt_orig_caller);
+
+        let rewards = migrate_result.rewards;
+        let locked_rewards_payment = self.send_to_lock_contract_non_zero(
+            rewards.token_identifier.clone(),
+            rewards.amount,
+            caller,
+            migrate_result.orig_caller,
+        );
+
+        (
+            migrate_result.new_farm_token,
+            locked_rewards_payment,
+            migrate_result.boosted_rewards,
+        )
+            .into()
+    }
+
     #[payable("*")]
     #[endpoint(mergeFarmTokens)]
     fn merge_farm_tokens_endpoint(
@@ -151,6 +151,23 @@ pub trait BaseFunctionsModule:
         &self,
         caller: ManagedAddress,
         payment: EsdtTokenPayment,
+    ) -> ExitFarmResultWrapper<Self::Api> {
+        self.exit_farm_common::<FC>(caller, payment, true)
+    }
+
+    fn exit_farm_without_penalty<FC: FarmContract<FarmSc = Self>>(
+        &self,
+        caller: ManagedAddress,
+        payment: EsdtTokenPayment,
+    ) -> ExitFarmResultWrapper<Self::Api> {
+        self.exit_farm_common::<FC>(caller, payment, false)
+    }
+
+    fn exit_farm_common<FC: FarmContract<FarmSc = Self>>(
+        &self,
+        caller: ManagedAddress,
+        payment: EsdtTokenPayment,
+        apply_penalty: bool,
     ) -> ExitFarmResultWrapper<Self::Api> {
         let base_exit_farm_result = self.exit_farm_base::<FC>(caller.clone(), payment);
 
@@ -161,12 +178,14 @@ pub trait BaseFunctionsModule:
             &base_exit_farm_result.storage_cache.farm_token_supply,
         );
 
-        FC::apply_penalty(
-            self,
-            &mut farming_token_payment.amount,
-            &base_exit_farm_result.context.farm_token.attributes,
-            &base_exit_farm_result.storage_cache,
-        );
+        if apply_penalty {
+            FC::apply_penalty(
+                self,
+                &mut farming_token_payment.amount,
+                &base_exit_farm_result.context.farm_token.attributes,
+                &base_exit_farm_result.storage_cache,
+            );
+        }
 
         self.emit_exit_farm_event(
             &caller,
@@ -5,6 +5,7 @@ multiversx_sc::derive_imports!();
 
 pub mod base_functions;
 pub mod exit_penalty;
+pub mod migration;
 
 use base_functions::{ClaimRewardsResultType, DoubleMultiPayment, Wrapper};
 use common_structs::FarmTokenAttributes;
@@ -15,6 +16,7 @@ use exit_penalty::{
 };
 use farm_base_impl::base_traits_impl::FarmContract;
 use fixed_supply_token::FixedSupplyToken;
+use migration::MigrateToFarmResultType;
 
 pub type EnterFarmResultType<M> = DoubleMultiPayment<M>;
 pub type ExitFarmWithPartialPosResultType<M> = DoubleMultiPayment<M>;
@@ -34,6 +36,7 @@ pub trait Farm:
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + base_functions::BaseFunctionsModule
     + exit_penalty::ExitPenaltyModule
+    + migration::FarmMigrationModule
     + farm_base_impl::base_farm_init::BaseFarmInitModule
     + farm_base_impl::base_farm_validation::BaseFarmValidationModule
     + farm_base_impl::enter_farm::BaseEnterFarmModule
@@ -180,6 +183,30 @@ pub trait Farm:
         (exit_farm_result.farming_tokens, exit_farm_result.rewards).into()
     }
 
+    /// Exits the farm without penalty and enters the given target farm with the farming tokens,
+    /// on behalf of the original caller. The target farm must be a whitelisted migration target.
+    /// Returns the new farm token, the rewards of this farm and the boosted rewards of the target farm.
+    #[payable("*")]
+    #[endpoint(migrateToFarm)]
+    fn migrate_to_farm_endpoint(
+        &self,
+        target_farm: ManagedAddress,
+        opt_orig_caller: OptionalValue<ManagedAddress>,
+    ) -> MigrateToFarmResultType<Self::Api> {
+        let caller = self.blockchain().get_caller();
+        let migrate_result =
+            self.migrate_to_farm::<Wrapper<Self>>(&caller, target_farm, opt_orig_caller);
+
+        self.send_payment_non_zero(&caller, &migrate_result.rewards);
+
+        (
+            migrate_result.new_farm_token,
+            migrate_result.rewards,
+            migrate_result.boosted_rewards,
+        )
+            .into()
+    }
+
     #[payable("*")]
     #[endpoint(mergeFarmTokens)]
     fn merge_farm_tokens_endpoint(
@@ -0,0 +1,149 @@
+multiversx_sc::imports!();
+
+use farm_base_impl::base_traits_impl::FarmContract;
+
+pub type MigrateToFarmResultType<M> =
+    MultiValue3<EsdtTokenPayment<M>, EsdtTokenPayment<M>, EsdtTokenPayment<M>>;
+
+pub const ENTER_FARM_FUNC_NAME: &[u8] = b"enterFarm";
+
+pub struct MigrationEnterFarmResult<M: ManagedTypeApi> {
+    pub new_farm_token: EsdtTokenPayment<M>,
+    pub boosted_rewards: EsdtTokenPayment<M>,
+}
+
+pub struct MigrateToFarmResultWrapper<M: ManagedTypeApi> {
+    pub orig_caller: ManagedAddress<M>,
+    pub new_farm_token: EsdtTokenPayment<M>,
+    pub rewards: EsdtTokenPayment<M>,
+    pub boosted_rewards: EsdtTokenPayment<M>,
+}
+
+#[multiversx_sc::module]
+pub trait FarmMigrationModule:
+    crate::base_functions::BaseFunctionsModule
+    + rewards::RewardsModule
+    + config::ConfigModule
+    + token_send::TokenSendModule
+    + farm_token::FarmTokenModule
+    + pausable::PausableModule
+    + permissions_module::PermissionsModule
+    + sc_whitelist_module::SCWhitelistModule
+    + events::EventsModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + crate::exit_penalty::ExitPenaltyModule
+    + farm_base_impl::base_farm_init::BaseFarmInitModule
+    + farm_base_impl::base_farm_validation::BaseFarmValidationModule
+    + farm_base_impl::enter_farm::BaseEnterFarmModule
+    + farm_base_impl::claim_rewards::BaseClaimRewardsModule
+    + farm_base_impl::compound_rewards::BaseCompoundRewardsModule
+    + farm_base_impl::exit_farm::BaseExitFarmModule
+    + utils::UtilsModule
+    + farm_boosted_yields::FarmBoostedYieldsModule
+    + farm_boosted_yields::boosted_yields_factors::BoostedYieldsFactorsModule
+    + week_timekeeping::WeekTimekeepingModule
+    + weekly_rewards_splitting::WeeklyRewardsSplittingModule
+    + weekly_rewards_splitting::events::WeeklyRewardsSplittingEventsModule
+    + weekly_rewards_splitting::global_info::WeeklyRewardsGlobalInfo
+    + weekly_rewards_splitting::locked_token_buckets::WeeklyRewardsLockedTokenBucketsModule
+    + weekly_rewards_splitting::update_claim_progress_energy::UpdateClaimProgressEnergyModule
+    + energy_query::EnergyQueryModule
+{
+    /// Target farms are the only ones to which positions can be migrated without exit penalty.
+    /// This farm must also be whitelisted in each target farm, in order to enter on behalf of users.
+    #[only_owner]
+    #[endpoint(addMigrationTargetFarms)]
+    fn add_migration_target_farms(&self, farm_addresses: MultiValueEncoded<ManagedAddress>) {
+        let own_address = self.blockchain().get_sc_address();
+        let mut target_farms = self.migration_target_farms();
+        for farm_address in farm_addresses {
+            self.require_sc_address(&farm_address);
+            require!(
+                farm_address != own_address,
+                "Cannot migrate to the same farm"
+            );
+
+            let _ = target_farms.insert(farm_address);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeMigrationTargetFarms)]
+    fn remove_migration_target_farms(&self, farm_addresses: MultiValueEncoded<ManagedAddress>) {
+        let mut target_farms = self.migration_target_farms();
+        for farm_address in farm_addresses {
+            let _ = target_farms.swap_remove(&farm_address);
+        }
+    }
+
+    fn require_migration_target_farm(&self, farm_address: &ManagedAddress) {
+        require!(
+            self.migration_target_farms().contains(farm_address),
+            "Invalid migration target farm"
+        );
+    }
+
+    /// Exits the farm without penalty with the received farm tokens, and enters the target farm
+    /// on behalf of the original caller. The new farm token and the boosted rewards of the target farm
+    /// are sent to the caller, while the rewards of this farm are returned, to be sent by each farm.
+    fn migrate_to_farm<FC: FarmContract<FarmSc = Self>>(
+        &self,
+        caller: &ManagedAddress,
+        target_farm: ManagedAddress,
+        opt_orig_caller: OptionalValue<ManagedAddress>,
+    ) -> MigrateToFarmResultWrapper<Self::Api> {
+        self.require_migration_target_farm(&target_farm);
+
+        let orig_caller = self.get_orig_caller_from_opt(caller, opt_orig_caller);
+
+        let payment = self.call_value().single_esdt();
+
+        let migrated_amount = self.migrate_old_farm_positions(&orig_caller);
+
+        let exit_farm_result = self.exit_farm_without_penalty::<FC>(orig_caller.clone(), payment);
+
+        self.decrease_old_farm_positions(migrated_amount, &orig_caller);
+
+        self.clear_user_energy_if_needed(&orig_caller);
+
+        let enter_farm_result = self.enter_migration_target_farm(
+            target_farm,
+            orig_caller.clone(),
+            exit_farm_result.farming_tokens,
+        );
+
+        self.send_payment_non_zero(caller, &enter_farm_result.new_farm_token);
+        self.send_payment_non_zero(caller, &enter_farm_result.boosted_rewards);
+
+        MigrateToFarmResultWrapper {
+            orig_caller,
+            new_farm_token: enter_farm_result.new_farm_token,
+            rewards: exit_farm_result.rewards,
+            boosted_rewards: enter_farm_result.boosted_rewards,
+        }
+    }
+
+    fn enter_migration_target_farm(
+        &self,
+        target_farm: ManagedAddress,
+        orig_caller: ManagedAddress,
+        farming_tokens: EsdtTokenPayment,
+    ) -> MigrationEnterFarmResult<Self::Api> {
+        let enter_farm_result: MultiValue2<EsdtTokenPayment, EsdtTokenPayment> = self
+            .send()
+            .contract_call::<()>(target_farm, ENTER_FARM_FUNC_NAME)
+            .argument(&orig_caller)
+            .with_esdt_transfer(farming_tokens)
+            .execute_on_dest_context();
+        let (new_farm_token, boosted_rewards) = enter_farm_result.into_tuple();
+
+        MigrationEnterFarmResult {
+            new_farm_token,
+            boosted_rewards,
+        }
+    }
+
+    #[view(getMigrationTargetFarms)]
+    #[storage_mapper("migrationTargetFarms")]
+    fn migration_target_farms(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
@@ -0,0 +1,196 @@
+#![allow(deprecated)]
+
+mod farm_setup;
+
+use common_structs::FarmTokenAttributes;
+use config::ConfigModule;
+use farm::{migration::FarmMigrationModule, Farm};
+use farm_setup::multi_user_farm_setup::{
+    MultiUserFarmSetup, DIV_SAFETY, FARMING_TOKEN_BALANCE, FARMING_TOKEN_ID, FARM_TOKEN_ID,
+    PER_BLOCK_REWARD_AMOUNT, REWARD_TOKEN_ID,
+};
+use farm_token::FarmTokenModule;
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    storage::mappers::StorageTokenWrapper,
+    types::{Address, EsdtLocalRole, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint, DebugApi,
+};
+use pausable::{PausableModule, State};
+use sc_whitelist_module::SCWhitelistModule;
+
+const NEW_FARM_TOKEN_ID: &[u8] = b"NFARM-123456";
+
+#[test]
+fn farm_migration_test() {
+    DebugApi::dummy();
+    let mut farm_setup = MultiUserFarmSetup::new(
+        farm::contract_obj,
+        energy_factory_mock::contract_obj,
+        energy_update::contract_obj,
+    );
+    let rust_zero = rust_biguint!(0u64);
+    let owner = farm_setup.owner.clone();
+    let first_user = farm_setup.first_user.clone();
+    let old_farm_addr = farm_setup.farm_wrapper.address_ref().clone();
+
+    // setup the new farm
+    let new_farm_wrapper = farm_setup.b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        farm::contract_obj,
+        "new farm",
+    );
+    farm_setup
+        .b_mock
+        .execute_tx(&owner, &new_farm_wrapper, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(REWARD_TOKEN_ID),
+                managed_token_id!(FARMING_TOKEN_ID),
+                managed_biguint!(DIV_SAFETY),
+                managed_address!(&Address::zero()),
+                managed_address!(&owner),
+                MultiValueEncoded::new(),
+            );
+
+            sc.farm_token()
+                .set_token_id(managed_token_id!(NEW_FARM_TOKEN_ID));
+            sc.state().set(State::Active);
+            sc.add_sc_address_to_whitelist(managed_address!(&old_farm_addr));
+        })
+        .assert_ok();
+    farm_setup.b_mock.set_esdt_local_roles(
+        new_farm_wrapper.address_ref(),
+        NEW_FARM_TOKEN_ID,
+        &[
+            EsdtLocalRole::NftCreate,
+            EsdtLocalRole::NftAddQuantity,
+            EsdtLocalRole::NftBurn,
+        ],
+    );
+
+    // user enters the old farm
+    let farm_in_amount = 100_000_000;
+    farm_setup.enter_farm(&first_user, farm_in_amount);
+    farm_setup.b_mock.set_block_nonce(10);
+
+    // target farm not whitelisted
+    farm_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &farm_setup.farm_wrapper,
+            FARM_TOKEN_ID,
+            1,
+            &rust_biguint!(farm_in_amount),
+            |sc| {
+                let _ = sc.migrate_to_farm_endpoint(
+                    managed_address!(new_farm_wrapper.address_ref()),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Invalid migration target farm");
+
+    farm_setup
+        .b_mock
+        .execute_tx(&owner, &farm_setup.farm_wrapper, &rust_zero, |sc| {
+            let mut farm_addresses = MultiValueEncoded::new();
+            farm_addresses.push(managed_address!(&old_farm_addr));
+            sc.add_migration_target_farms(farm_addresses);
+        })
+        .assert_user_error("Cannot migrate to the same farm");
+
+    farm_setup
+        .b_mock
+        .execute_tx(&owner, &farm_setup.farm_wrapper, &rust_zero, |sc| {
+            let mut farm_addresses = MultiValueEncoded::new();
+            farm_addresses.push(managed_address!(new_farm_wrapper.address_ref()));
+            sc.add_migration_target_farms(farm_addresses);
+        })
+        .assert_ok();
+
+    // migrate before the minimum farming epochs passed, no penalty is applied
+    farm_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &farm_setup.farm_wrapper,
+            FARM_TOKEN_ID,
+            1,
+            &rust_biguint!(farm_in_amount),
+            |sc| {
+                let (new_farm_token, rewards, boosted_rewards) = sc
+                    .migrate_to_farm_endpoint(
+                        managed_address!(new_farm_wrapper.address_ref()),
+                        OptionalValue::None,
+                    )
+                    .into_tuple();
+                assert_eq!(
+                    new_farm_token.token_identifier,
+                    managed_token_id!(NEW_FARM_TOKEN_ID)
+                );
+                assert_eq!(new_farm_token.amount, managed_biguint!(farm_in_amount));
+                assert_eq!(
+                    rewards.amount,
+                    managed_biguint!(10 * PER_BLOCK_REWARD_AMOUNT)
+                );
+                assert_eq!(boosted_rewards.amount, managed_biguint!(0));
+            },
+        )
+        .assert_ok();
+
+    farm_setup.b_mock.check_esdt_balance(
+        &first_user,
+        FARMING_TOKEN_ID,
+        &rust_biguint!(FARMING_TOKEN_BALANCE - farm_in_amount),
+    );
+    farm_setup.b_mock.check_esdt_balance(
+        &first_user,
+        REWARD_TOKEN_ID,
+        &rust_biguint!(10 * PER_BLOCK_REWARD_AMOUNT),
+    );
+    farm_setup.b_mock.check_nft_balance(
+        &first_user,
+        NEW_FARM_TOKEN_ID,
+        1,
+        &rust_biguint!(farm_in_amount),
+        Some(&FarmTokenAttributes::<DebugApi> {
+            reward_per_share: managed_biguint!(0),
+            compounded_reward: managed_biguint!(0),
+            entering_epoch: 0,
+            current_farm_amount: managed_biguint!(farm_in_amount),
+            original_owner: managed_address!(&first_user),
+        }),
+    );
+    farm_setup.b_mock.check_esdt_balance(
+        new_farm_wrapper.address_ref(),
+        FARMING_TOKEN_ID,
+        &rust_biguint!(farm_in_amount),
+    );
+
+    // the user's total farm position moved to the new farm
+    farm_setup
+        .b_mock
+        .execute_query(&farm_setup.farm_wrapper, |sc| {
+            assert_eq!(
+                sc.user_total_farm_position(&managed_address!(&first_user))
+                    .get(),
+                managed_biguint!(0)
+            );
+            assert_eq!(sc.farm_token_supply().get(), managed_biguint!(0));
+        })
+        .assert_ok();
+    farm_setup
+        .b_mock
+        .execute_query(&new_farm_wrapper, |sc| {
+            assert_eq!(
+                sc.user_total_farm_position(&managed_address!(&first_user))
+                    .get(),
+                managed_biguint!(farm_in_amount)
+            );
+        })
+        .assert_ok();
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           63
+// Endpoints:                           67
 // Async Callback:                       1
-// Total number of exported functions:  66
+// Total number of exported functions:  70
 
 #![no_std]
 
@@ -24,6 +24,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         claimRewards => claim_rewards_endpoint
         compoundRewards => compound_rewards_endpoint
         exitFarm => exit_farm_endpoint
+        migrateToFarm => migrate_to_farm_endpoint
         mergeFarmTokens => merge_farm_tokens_endpoint
         claimBoostedRewards => claim_boosted_rewards
         startProduceRewards => start_produce_rewards_endpoint
@@ -63,6 +64,9 @@ multiversx_sc_wasm_adapter::endpoints! {
         getMinimumFarmingEpoch => minimum_farming_epochs
         getBurnGasLimit => burn_gas_limit
         getPairContractManagedAddress => pair_contract_address
+        addMigrationTargetFarms => add_migration_target_farms
+        removeMigrationTargetFarms => remove_migration_target_farms
+        getMigrationTargetFarms => migration_target_farms
         collectUndistributedBoostedRewards => collect_undistributed_boosted_rewards
         getBoostedYieldsRewardsPercentage => boosted_yields_rewards_percentage
         getAccumulatedRewardsForWeek => accumulated_rewards_for_week
@@ -0,0 +1,77 @@
+use crate::order::{Order, OrderId};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(TypeAbi, TopEncode)]
+pub struct ExecuteOrderEvent<M: ManagedTypeApi> {
+    keeper: ManagedAddress<M>,
+    input_amount: BigUint<M>,
+    output_amount: BigUint<M>,
+    keeper_fee: BigUint<M>,
+    order: Order<M>,
+}
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_create_order_event(&self, order_id: OrderId, order: &Order<Self::Api>) {
+        let epoch = self.blockchain().get_block_epoch();
+        self.create_order_event(&order.creator, order_id, epoch, order);
+    }
+
+    fn emit_cancel_order_event(&self, order_id: OrderId, order: &Order<Self::Api>) {
+        let epoch = self.blockchain().get_block_epoch();
+        self.cancel_order_event(&order.creator, order_id, epoch, order);
+    }
+
+    fn emit_execute_order_event(
+        &self,
+        order_id: OrderId,
+        input_amount: BigUint,
+        output_amount: BigUint,
+        keeper_fee: BigUint,
+        order: Order<Self::Api>,
+    ) {
+        let epoch = self.blockchain().get_block_epoch();
+        let keeper = self.blockchain().get_caller();
+        self.execute_order_event(
+            &order.creator.clone(),
+            order_id,
+            epoch,
+            &ExecuteOrderEvent {
+                keeper,
+                input_amount,
+                output_amount,
+                keeper_fee,
+                order,
+            },
+        );
+    }
+
+    #[event("createOrder")]
+    fn create_order_event(
+        &self,
+        #[indexed] creator: &ManagedAddress,
+        #[indexed] order_id: OrderId,
+        #[indexed] epoch: u64,
+        order: &Order<Self::Api>,
+    );
+
+    #[event("cancelOrder")]
+    fn cancel_order_event(
+        &self,
+        #[indexed] creator: &ManagedAddress,
+        #[indexed] order_id: OrderId,
+        #[indexed] epoch: u64,
+        order: &Order<Self::Api>,
+    );
+
+    #[event("executeOrder")]
+    fn execute_order_event(
+        &self,
+        #[indexed] creator: &ManagedAddress,
+        #[indexed] order_id: OrderId,
+        #[indexed] epoch: u64,
+        execute_order_event: &ExecuteOrderEvent<Self::Api>,
+    );
+}
@@ -0,0 +1,291 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod events;
+pub mod order;
+
+use order::{Order, OrderId};
+use pair::pair_actions::{swap::ProxyTrait as _, views::ProxyTrait as _};
+use router::factory::ProxyTrait as _;
+
+pub const MAX_PERCENTAGE: u64 = 10_000;
+pub const MAX_KEEPER_FEE_PERCENTAGE: u64 = 1_000;
+pub const MAX_ORDER_CHUNKS: u64 = 100;
+
+/// Users escrow tokens in limit orders or TWAP orders,
+/// which are executed by permissionless keepers against the router's pairs.
+#[multiversx_sc::contract]
+pub trait OrderBook: order::OrderModule + events::EventsModule {
+    #[init]
+    fn init(&self, router_address: ManagedAddress, keeper_fee_percentage: u64) {
+        self.set_router_address(router_address);
+        self.set_keeper_fee_percentage(keeper_fee_percentage);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[only_owner]
+    #[endpoint(setRouterAddress)]
+    fn set_router_address(&self, router_address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&router_address),
+            "Invalid router address"
+        );
+
+        self.router_address().set(router_address);
+    }
+
+    #[only_owner]
+    #[endpoint(setKeeperFeePercentage)]
+    fn set_keeper_fee_percentage(&self, keeper_fee_percentage: u64) {
+        require!(
+            keeper_fee_percentage <= MAX_KEEPER_FEE_PERCENTAGE,
+            "Invalid keeper fee percentage"
+        );
+
+        self.keeper_fee_percentage().set(keeper_fee_percentage);
+    }
+
+    /// Escrows the payment until the order is fully executed, cancelled or expired.
+    ///
+    /// Arguments:
+    /// - output_token_id - the token to receive
+    /// - min_output_amount - the min amount of output tokens for the whole payment, i.e. the limit price
+    /// - expiry_block - the order can no longer be executed starting with this block
+    /// - opt_twap_params - the number of chunks and the min blocks between chunk executions.
+    ///     If missing, the order is a limit order, executed in one go.
+    #[payable("*")]
+    #[endpoint(createOrder)]
+    fn create_order(
+        &self,
+        output_token_id: TokenIdentifier,
+        min_output_amount: BigUint,
+        expiry_block: u64,
+        opt_twap_params: OptionalValue<MultiValue2<u64, u64>>,
+    ) -> OrderId {
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_nonce == 0 && payment.amount > 0,
+            "Invalid payment"
+        );
+        require!(
+            output_token_id.is_valid_esdt_identifier()
+                && output_token_id != payment.token_identifier,
+            "Invalid output token"
+        );
+        require!(min_output_amount > 0, "Invalid min output amount");
+
+        let current_block = self.blockchain().get_block_nonce();
+        require!(expiry_block > current_block, "Invalid expiry block");
+
+        let (nr_chunks, chunk_interval_blocks) = match opt_twap_params {
+            OptionalValue::Some(twap_params) => twap_params.into_tuple(),
+            OptionalValue::None => (1, 0),
+        };
+        require!(
+            nr_chunks > 0 && nr_chunks <= MAX_ORDER_CHUNKS,
+            "Invalid number of chunks"
+        );
+        require!(
+            payment.amount >= nr_chunks,
+            "Payment too low for the number of chunks"
+        );
+
+        let order_id = self.last_order_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let caller = self.blockchain().get_caller();
+        let order = Order {
+            creator: caller.clone(),
+            input_token_id: payment.token_identifier,
+            initial_input_amount: payment.amount.clone(),
+            remaining_input_amount: payment.amount,
+            output_token_id,
+            min_output_amount,
+            expiry_block,
+            nr_chunks,
+            executed_chunks: 0,
+            chunk_interval_blocks,
+            last_execution_block: 0,
+        };
+        self.orders(order_id).set(&order);
+        let _ = self.user_orders(&caller).insert(order_id);
+
+        self.emit_create_order_event(order_id, &order);
+
+        order_id
+    }
+
+    /// Refunds the remaining input tokens of the order. Only the order's creator may cancel it.
+    #[endpoint(cancelOrder)]
+    fn cancel_order(&self, order_id: OrderId) -> EsdtTokenPayment {
+        let order = self.get_order_or_panic(order_id);
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == order.creator,
+            "Only the creator may cancel the order"
+        );
+
+        self.remove_order(order_id, &caller);
+
+        let refund = EsdtTokenPayment::new(
+            order.input_token_id.clone(),
+            0,
+            order.remaining_input_amount.clone(),
+        );
+        self.send().direct_non_zero_esdt_payment(&caller, &refund);
+
+        self.emit_cancel_order_event(order_id, &order);
+
+        refund
+    }
+
+    /// Executes the next chunk of the order, if the creator's part of the pair's output meets the limit price.
+    /// The caller receives keeper_fee_percentage of the output, the rest goes to the order's creator.
+    #[endpoint(executeOrder)]
+    fn execute_order(&self, order_id: OrderId) -> EsdtTokenPayment {
+        let mut order = self.get_order_or_panic(order_id);
+        let current_block = self.blockchain().get_block_nonce();
+        require!(current_block < order.expiry_block, "Order expired");
+        require!(
+            order.executed_chunks == 0
+                || current_block >= order.last_execution_block + order.chunk_interval_blocks,
+            "Chunk interval not passed"
+        );
+
+        let input_amount = order.next_chunk_input_amount();
+        let min_output_amount =
+            self.get_min_output_with_keeper_fee(&order.min_output_for_input(&input_amount));
+        let pair_address = self.get_pair_or_panic(&order.input_token_id, &order.output_token_id);
+        let amount_out: BigUint = self
+            .pair_proxy(pair_address.clone())
+            .get_amount_out_view(order.input_token_id.clone(), input_amount.clone())
+            .execute_on_dest_context();
+        require!(amount_out >= min_output_amount, "Limit price not reached");
+
+        let output_payment: EsdtTokenPayment = self
+            .pair_proxy(pair_address)
+            .swap_tokens_fixed_input(
+                order.output_token_id.clone(),
+                min_output_amount,
+                OptionalValue::<ManagedAddress>::None,
+            )
+            .with_esdt_transfer(EsdtTokenPayment::new(
+                order.input_token_id.clone(),
+                0,
+                input_amount.clone(),
+            ))
+            .execute_on_dest_context();
+
+        let keeper_fee =
+            &output_payment.amount * self.keeper_fee_percentage().get() / MAX_PERCENTAGE;
+        let creator_payment = EsdtTokenPayment::new(
+            output_payment.token_identifier.clone(),
+            0,
+            &output_payment.amount - &keeper_fee,
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.send().direct_non_zero_esdt_payment(
+            &caller,
+            &EsdtTokenPayment::new(output_payment.token_identifier, 0, keeper_fee.clone()),
+        );
+        self.send()
+            .direct_non_zero_esdt_payment(&order.creator, &creator_payment);
+
+        order.remaining_input_amount -= &input_amount;
+        order.executed_chunks += 1;
+        order.last_execution_block = current_block;
+        if order.executed_chunks == order.nr_chunks {
+            self.remove_order(order_id, &order.creator);
+        } else {
+            self.orders(order_id).set(&order);
+        }
+
+        self.emit_execute_order_event(
+            order_id,
+            input_amount,
+            output_payment.amount,
+            keeper_fee,
+            order,
+        );
+
+        creator_payment
+    }
+
+    /// Returns true if executeOrder would succeed for the given order in the current block
+    #[view(canExecuteOrder)]
+    fn can_execute_order(&self, order_id: OrderId) -> bool {
+        let order_mapper = self.orders(order_id);
+        if order_mapper.is_empty() {
+            return false;
+        }
+
+        let order = order_mapper.get();
+        let current_block = self.blockchain().get_block_nonce();
+        if current_block >= order.expiry_block {
+            return false;
+        }
+        if order.executed_chunks > 0
+            && current_block < order.last_execution_block + order.chunk_interval_blocks
+        {
+            return false;
+        }
+
+        let router_address = self.router_address().get();
+        let pair_address: ManagedAddress = self
+            .router_proxy(router_address)
+            .get_pair(order.input_token_id.clone(), order.output_token_id.clone())
+            .execute_on_dest_context();
+        if pair_address.is_zero() {
+            return false;
+        }
+
+        let input_amount = order.next_chunk_input_amount();
+        let amount_out: BigUint = self
+            .pair_proxy(pair_address)
+            .get_amount_out_view(order.input_token_id.clone(), input_amount.clone())
+            .execute_on_dest_context();
+
+        amount_out
+            >= self.get_min_output_with_keeper_fee(&order.min_output_for_input(&input_amount))
+    }
+
+    /// The output needed for the creator to still get min_output_amount after the keeper fee
+    fn get_min_output_with_keeper_fee(&self, min_output_amount: &BigUint) -> BigUint {
+        let creator_percentage = MAX_PERCENTAGE - self.keeper_fee_percentage().get();
+        (min_output_amount * MAX_PERCENTAGE + creator_percentage - 1u64) / creator_percentage
+    }
+
+    fn get_pair_or_panic(
+        &self,
+        first_token_id: &TokenIdentifier,
+        second_token_id: &TokenIdentifier,
+    ) -> ManagedAddress {
+        let router_address = self.router_address().get();
+        let pair_address: ManagedAddress = self
+            .router_proxy(router_address)
+            .get_pair(first_token_id.clone(), second_token_id.clone())
+            .execute_on_dest_context();
+        require!(!pair_address.is_zero(), "No pair for order tokens");
+
+        pair_address
+    }
+
+    #[proxy]
+    fn router_proxy(&self, sc_address: ManagedAddress) -> router::Proxy<Self::Api>;
+
+    #[proxy]
+    fn pair_proxy(&self, sc_address: ManagedAddress) -> pair::Proxy<Self::Api>;
+
+    #[view(getRouterAddress)]
+    #[storage_mapper("routerAddress")]
+    fn router_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getKeeperFeePercentage)]
+    #[storage_mapper("keeperFeePercentage")]
+    fn keeper_fee_percentage(&self) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,62 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type OrderId = u64;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Order<M: ManagedTypeApi> {
+    pub creator: ManagedAddress<M>,
+    pub input_token_id: TokenIdentifier<M>,
+    pub initial_input_amount: BigUint<M>,
+    pub remaining_input_amount: BigUint<M>,
+    pub output_token_id: TokenIdentifier<M>,
+    pub min_output_amount: BigUint<M>,
+    pub expiry_block: u64,
+    pub nr_chunks: u64,
+    pub executed_chunks: u64,
+    pub chunk_interval_blocks: u64,
+    pub last_execution_block: u64,
+}
+
+impl<M: ManagedTypeApi> Order<M> {
+    pub fn next_chunk_input_amount(&self) -> BigUint<M> {
+        if self.executed_chunks + 1 == self.nr_chunks {
+            return self.remaining_input_amount.clone();
+        }
+
+        &self.initial_input_amount / self.nr_chunks
+    }
+
+    /// The limit price is kept for every chunk, rounded up in the creator's favor
+    pub fn min_output_for_input(&self, input_amount: &BigUint<M>) -> BigUint<M> {
+        let numerator = &self.min_output_amount * input_amount;
+        (numerator + &self.initial_input_amount - 1u32) / &self.initial_input_amount
+    }
+}
+
+#[multiversx_sc::module]
+pub trait OrderModule {
+    fn get_order_or_panic(&self, order_id: OrderId) -> Order<Self::Api> {
+        let mapper = self.orders(order_id);
+        require!(!mapper.is_empty(), "Order does not exist");
+
+        mapper.get()
+    }
+
+    fn remove_order(&self, order_id: OrderId, creator: &ManagedAddress) {
+        self.orders(order_id).clear();
+        let _ = self.user_orders(creator).swap_remove(&order_id);
+    }
+
+    #[view(getOrder)]
+    #[storage_mapper("orders")]
+    fn orders(&self, order_id: OrderId) -> SingleValueMapper<Order<Self::Api>>;
+
+    #[view(getUserOrders)]
+    #[storage_mapper("userOrders")]
+    fn user_orders(&self, user: &ManagedAddress) -> UnorderedSetMapper<OrderId>;
+
+    #[view(getLastOrderId)]
+    #[storage_mapper("lastOrderId")]
+    fn last_order_id(&self) -> SingleValueMapper<OrderId>;
+}
@@ -0,0 +1,383 @@
+#![allow(deprecated)]
+
+use multiversx_sc::{
+    codec::multi_types::{MultiValue2, OptionalValue},
+    types::{Address, EsdtLocalRole, ManagedAddress, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint,
+    whitebox_legacy::{BlockchainStateWrapper, ContractObjWrapper, TxTokenTransfer},
+    DebugApi,
+};
+use order_book::{order::OrderModule, OrderBook};
+use pair::{
+    config::ConfigModule as PairConfigModule, pair_actions::initial_liq::InitialLiquidityModule,
+    Pair,
+};
+use pausable::{PausableModule, State};
+use router::{config::ConfigModule, factory::PairTokens, Router};
+
+const FIRST_TOKEN_ID: &[u8] = b"WEGLD-123456";
+const SECOND_TOKEN_ID: &[u8] = b"MEX-123456";
+const LP_TOKEN_ID: &[u8] = b"LPTOK-123456";
+const POOL_RESERVE: u64 = 1_000_000_000_000;
+const USER_BALANCE: u64 = 10_000_000;
+const KEEPER_FEE_PERCENTAGE: u64 = 100; // 1%
+
+struct OrderBookSetup<OrderBookObjBuilder, RouterObjBuilder, PairObjBuilder>
+where
+    OrderBookObjBuilder: 'static + Copy + Fn() -> order_book::ContractObj<DebugApi>,
+    RouterObjBuilder: 'static + Copy + Fn() -> router::ContractObj<DebugApi>,
+    PairObjBuilder: 'static + Copy + Fn() -> pair::ContractObj<DebugApi>,
+{
+    b_mock: BlockchainStateWrapper,
+    user: Address,
+    keeper: Address,
+    order_book_wrapper: ContractObjWrapper<order_book::ContractObj<DebugApi>, OrderBookObjBuilder>,
+    _router_wrapper: ContractObjWrapper<router::ContractObj<DebugApi>, RouterObjBuilder>,
+    _pair_wrapper: ContractObjWrapper<pair::ContractObj<DebugApi>, PairObjBuilder>,
+}
+
+impl<OrderBookObjBuilder, RouterObjBuilder, PairObjBuilder>
+    OrderBookSetup<OrderBookObjBuilder, RouterObjBuilder, PairObjBuilder>
+where
+    OrderBookObjBuilder: 'static + Copy + Fn() -> order_book::ContractObj<DebugApi>,
+    RouterObjBuilder: 'static + Copy + Fn() -> router::ContractObj<DebugApi>,
+    PairObjBuilder: 'static + Copy + Fn() -> pair::ContractObj<DebugApi>,
+{
+    fn new(
+        order_book_builder: OrderBookObjBuilder,
+        router_builder: RouterObjBuilder,
+        pair_builder: PairObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let mut b_mock = BlockchainStateWrapper::new();
+        let owner = b_mock.create_user_account(&rust_zero);
+        let user = b_mock.create_user_account(&rust_zero);
+        let keeper = b_mock.create_user_account(&rust_zero);
+
+        let router_wrapper =
+            b_mock.create_sc_account(&rust_zero, Some(&owner), router_builder, "router.wasm");
+        let pair_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(router_wrapper.address_ref()),
+            pair_builder,
+            "pair.wasm",
+        );
+        let order_book_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner),
+            order_book_builder,
+            "order-book.wasm",
+        );
+
+        b_mock
+            .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+                sc.init(OptionalValue::None);
+                sc.pair_map().insert(
+                    PairTokens {
+                        first_token_id: managed_token_id!(FIRST_TOKEN_ID),
+                        second_token_id: managed_token_id!(SECOND_TOKEN_ID),
+                    },
+                    managed_address!(pair_wrapper.address_ref()),
+                );
+            })
+            .assert_ok();
+
+        b_mock
+            .execute_tx(&owner, &pair_wrapper, &rust_zero, |sc| {
+                sc.init(
+                    managed_token_id!(FIRST_TOKEN_ID),
+                    managed_token_id!(SECOND_TOKEN_ID),
+                    managed_address!(router_wrapper.address_ref()),
+                    managed_address!(&owner),
+                    0,
+                    0,
+                    ManagedAddress::<DebugApi>::zero(),
+                    MultiValueEncoded::<DebugApi, ManagedAddress<DebugApi>>::new(),
+                );
+                sc.lp_token_identifier()
+                    .set(&managed_token_id!(LP_TOKEN_ID));
+            })
+            .assert_ok();
+        b_mock.set_esdt_local_roles(
+            pair_wrapper.address_ref(),
+            LP_TOKEN_ID,
+            &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+        );
+
+        b_mock.set_esdt_balance(&owner, FIRST_TOKEN_ID, &rust_biguint!(POOL_RESERVE));
+        b_mock.set_esdt_balance(&owner, SECOND_TOKEN_ID, &rust_biguint!(POOL_RESERVE));
+        let payments = vec![
+            TxTokenTransfer {
+                token_identifier: FIRST_TOKEN_ID.to_vec(),
+                nonce: 0,
+                value: rust_biguint!(POOL_RESERVE),
+            },
+            TxTokenTransfer {
+                token_identifier: SECOND_TOKEN_ID.to_vec(),
+                nonce: 0,
+                value: rust_biguint!(POOL_RESERVE),
+            },
+        ];
+        b_mock
+            .execute_esdt_multi_transfer(&owner, &pair_wrapper, &payments, |sc| {
+                let _ = sc.add_initial_liquidity();
+                sc.state().set(State::Active);
+            })
+            .assert_ok();
+
+        b_mock
+            .execute_tx(&owner, &order_book_wrapper, &rust_zero, |sc| {
+                sc.init(
+                    managed_address!(router_wrapper.address_ref()),
+                    KEEPER_FEE_PERCENTAGE,
+                );
+            })
+            .assert_ok();
+
+        b_mock.set_esdt_balance(&user, FIRST_TOKEN_ID, &rust_biguint!(USER_BALANCE));
+
+        OrderBookSetup {
+            b_mock,
+            user,
+            keeper,
+            order_book_wrapper,
+            _router_wrapper: router_wrapper,
+            _pair_wrapper: pair_wrapper,
+        }
+    }
+
+    fn create_order(
+        &mut self,
+        amount: u64,
+        min_output_amount: u64,
+        expiry_block: u64,
+        opt_twap_params: Option<(u64, u64)>,
+    ) -> u64 {
+        let mut order_id = 0;
+        self.b_mock
+            .execute_esdt_transfer(
+                &self.user,
+                &self.order_book_wrapper,
+                FIRST_TOKEN_ID,
+                0,
+                &rust_biguint!(amount),
+                |sc| {
+                    let twap_params = match opt_twap_params {
+                        Some((nr_chunks, interval)) => {
+                            OptionalValue::Some(MultiValue2::from((nr_chunks, interval)))
+                        }
+                        None => OptionalValue::None,
+                    };
+                    order_id = sc.create_order(
+                        managed_token_id!(SECOND_TOKEN_ID),
+                        managed_biguint!(min_output_amount),
+                        expiry_block,
+                        twap_params,
+                    );
+                },
+            )
+            .assert_ok();
+
+        order_id
+    }
+
+    fn execute_order(
+        &mut self,
+        order_id: u64,
+    ) -> multiversx_sc_scenario::whitebox_legacy::TxResult {
+        self.b_mock.execute_tx(
+            &self.keeper,
+            &self.order_book_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.execute_order(order_id);
+            },
+        )
+    }
+}
+
+#[test]
+fn limit_order_execute_test() {
+    let mut setup = OrderBookSetup::new(
+        order_book::contract_obj,
+        router::contract_obj,
+        pair::contract_obj,
+    );
+
+    // with no fees, 1_000_000 in gives 999_999 out, of which the keeper gets 1%
+    let min_output_amount = 989_000;
+    let order_id = setup.create_order(1_000_000, min_output_amount, 100, None);
+    assert_eq!(order_id, 1);
+
+    setup
+        .b_mock
+        .execute_query(&setup.order_book_wrapper, |sc| {
+            assert!(sc.can_execute_order(order_id));
+        })
+        .assert_ok();
+
+    setup.execute_order(order_id).assert_ok();
+
+    let keeper_fee = 999_999 * KEEPER_FEE_PERCENTAGE / 10_000;
+    assert!(999_999 - keeper_fee >= min_output_amount);
+    setup
+        .b_mock
+        .check_esdt_balance(&setup.keeper, SECOND_TOKEN_ID, &rust_biguint!(keeper_fee));
+    setup.b_mock.check_esdt_balance(
+        &setup.user,
+        SECOND_TOKEN_ID,
+        &rust_biguint!(999_999 - keeper_fee),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.user,
+        FIRST_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - 1_000_000),
+    );
+
+    // order removed after full execution
+    let user = setup.user.clone();
+    setup
+        .b_mock
+        .execute_query(&setup.order_book_wrapper, |sc| {
+            assert!(sc.orders(order_id).is_empty());
+            assert_eq!(sc.user_orders(&managed_address!(&user)).len(), 0);
+        })
+        .assert_ok();
+
+    setup
+        .execute_order(order_id)
+        .assert_user_error("Order does not exist");
+}
+
+#[test]
+fn limit_order_not_reached_and_cancel_test() {
+    let mut setup = OrderBookSetup::new(
+        order_book::contract_obj,
+        router::contract_obj,
+        pair::contract_obj,
+    );
+
+    let order_id = setup.create_order(1_000_000, 1_000_000, 100, None);
+
+    setup
+        .b_mock
+        .execute_query(&setup.order_book_wrapper, |sc| {
+            assert!(!sc.can_execute_order(order_id));
+        })
+        .assert_ok();
+    setup
+        .execute_order(order_id)
+        .assert_user_error("Limit price not reached");
+
+    // expired orders can only be cancelled
+    setup.b_mock.set_block_nonce(100);
+    setup.execute_order(order_id).assert_user_error("Order expired");
+
+    // only the creator may cancel
+    let keeper = setup.keeper.clone();
+    setup
+        .b_mock
+        .execute_tx(
+            &keeper,
+            &setup.order_book_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.cancel_order(order_id);
+            },
+        )
+        .assert_user_error("Only the creator may cancel the order");
+
+    let user = setup.user.clone();
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.order_book_wrapper, &rust_biguint!(0), |sc| {
+            let refund = sc.cancel_order(order_id);
+            assert_eq!(refund.amount, managed_biguint!(1_000_000));
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .check_esdt_balance(&user, FIRST_TOKEN_ID, &rust_biguint!(USER_BALANCE));
+}
+
+#[test]
+fn limit_order_keeper_fee_below_limit_test() {
+    let mut setup = OrderBookSetup::new(
+        order_book::contract_obj,
+        router::contract_obj,
+        pair::contract_obj,
+    );
+
+    // the pair's output reaches the limit, but the creator's part after the keeper fee does not
+    let order_id = setup.create_order(1_000_000, 999_999, 100, None);
+
+    setup
+        .b_mock
+        .execute_query(&setup.order_book_wrapper, |sc| {
+            assert!(!sc.can_execute_order(order_id));
+        })
+        .assert_ok();
+    setup
+        .execute_order(order_id)
+        .assert_user_error("Limit price not reached");
+
+    setup
+        .b_mock
+        .check_esdt_balance(&setup.user, SECOND_TOKEN_ID, &rust_biguint!(0));
+    setup
+        .b_mock
+        .check_esdt_balance(&setup.keeper, SECOND_TOKEN_ID, &rust_biguint!(0));
+}
+
+#[test]
+fn twap_order_test() {
+    let mut setup = OrderBookSetup::new(
+        order_book::contract_obj,
+        router::contract_obj,
+        pair::contract_obj,
+    );
+
+    setup.b_mock.set_block_nonce(10);
+    let order_id = setup.create_order(3_000_000, 2_900_000, 50, Some((3, 10)));
+
+    setup.execute_order(order_id).assert_ok();
+    setup
+        .execute_order(order_id)
+        .assert_user_error("Chunk interval not passed");
+
+    setup.b_mock.set_block_nonce(20);
+    setup.execute_order(order_id).assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.order_book_wrapper, |sc| {
+            let order = sc.orders(order_id).get();
+            assert_eq!(order.executed_chunks, 2);
+            assert_eq!(order.remaining_input_amount, managed_biguint!(1_000_000));
+        })
+        .assert_ok();
+
+    // expired orders can only be cancelled
+    setup.b_mock.set_block_nonce(50);
+    setup
+        .execute_order(order_id)
+        .assert_user_error("Order expired");
+
+    setup.b_mock.set_block_nonce(30);
+    setup.execute_order(order_id).assert_ok();
+
+    setup.b_mock.check_esdt_balance(
+        &setup.user,
+        FIRST_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - 3_000_000),
+    );
+    setup
+        .b_mock
+        .execute_query(&setup.order_book_wrapper, |sc| {
+            assert!(sc.orders(order_id).is_empty());
+        })
+        .assert_ok();
+}
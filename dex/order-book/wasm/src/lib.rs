@@ -0,0 +1,37 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           11
+// Async Callback (empty):               1
+// Total number of exported functions:  14
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    order_book
+    (
+        init => init
+        upgrade => upgrade
+        setRouterAddress => set_router_address
+        setKeeperFeePercentage => set_keeper_fee_percentage
+        createOrder => create_order
+        cancelOrder => cancel_order
+        executeOrder => execute_order
+        canExecuteOrder => can_execute_order
+        getRouterAddress => router_address
+        getKeeperFeePercentage => keeper_fee_percentage
+        getOrder => orders
+        getUserOrders => user_orders
+        getLastOrderId => last_order_id
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
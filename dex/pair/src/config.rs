@@ -18,6 +18,13 @@ pub trait ConfigModule:
         self.state().set(State::PartialActive);
     }
 
+    /// Used when retiring the pair. Liquidity can still be removed.
+    #[endpoint(setAddLiquidityDisabled)]
+    fn set_add_liquidity_disabled(&self, disabled: bool) {
+        self.require_caller_has_owner_permissions();
+        self.add_liquidity_disabled().set(disabled);
+    }
+
     #[endpoint(setFeePercents)]
     fn set_fee_percent(&self, total_fee_percent: u64, special_fee_percent: u64) {
         self.require_caller_has_owner_or_admin_permissions();
@@ -69,6 +76,10 @@ pub trait ConfigModule:
     #[storage_mapper("initial_liquidity_adder")]
     fn initial_liquidity_adder(&self) -> SingleValueMapper<Option<ManagedAddress>>;
 
+    #[view(isAddLiquidityDisabled)]
+    #[storage_mapper("addLiquidityDisabled")]
+    fn add_liquidity_disabled(&self) -> SingleValueMapper<bool>;
+
     #[view(getReserve)]
     #[storage_mapper("reserve")]
     fn pair_reserve(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
@@ -43,8 +43,10 @@ pub static ERROR_LP_TOKEN_SAME_AS_POOL_TOKENS: &[u8] =
     b"LP token should differ from the exchange tokens";
 
 pub static ERROR_SWAP_NOT_ENABLED: &[u8] = b"Swap is not enabled";
+pub static ERROR_FLASH_SWAP_CALLER_NOT_SC: &[u8] = b"Flash swaps may only be done by contracts";
 pub static ERROR_SLIPPAGE_EXCEEDED: &[u8] = b"Slippage exceeded";
 pub static ERROR_NOTHING_TO_DO_WITH_FEE_SLICE: &[u8] = b"Nothing to do with fee slice";
+pub static ERROR_ADD_LIQUIDITY_DISABLED: &[u8] = b"Adding liquidity is disabled";
 
 pub static ERROR_SAFE_PRICE_SAME_ROUNDS: &[u8] =
     b"The safe price can be computed only between different rounds";
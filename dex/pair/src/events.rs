@@ -1,5 +1,5 @@
 use crate::contexts::add_liquidity::AddLiquidityContext;
-use crate::contexts::base::StorageCache;
+use crate::contexts::base::{StorageCache, SwapTokensOrder};
 use crate::contexts::remove_liquidity::RemoveLiquidityContext;
 use crate::contexts::swap::SwapContext;
 
@@ -34,6 +34,21 @@ pub struct SwapNoFeeAndForwardEvent<M: ManagedTypeApi> {
     timestamp: u64,
 }
 
+#[derive(TypeAbi, TopEncode)]
+pub struct FlashSwapEvent<M: ManagedTypeApi> {
+    caller: ManagedAddress<M>,
+    token_id_out: TokenIdentifier<M>,
+    token_amount_out: BigUint<M>,
+    token_id_in: TokenIdentifier<M>,
+    repaid_amount_in: BigUint<M>,
+    repaid_amount_out: BigUint<M>,
+    token_in_reserve: BigUint<M>,
+    token_out_reserve: BigUint<M>,
+    block: u64,
+    epoch: u64,
+    timestamp: u64,
+}
+
 #[derive(TypeAbi, TopEncode)]
 pub struct AddLiquidityEvent<M: ManagedTypeApi> {
     caller: ManagedAddress<M>,
@@ -128,6 +143,46 @@ pub trait EventsModule:
         )
     }
 
+    fn emit_flash_swap_event(
+        &self,
+        storage_cache: &StorageCache<Self>,
+        swap_tokens_order: SwapTokensOrder,
+        token_amount_out: BigUint,
+        repaid_amount_in: BigUint,
+        repaid_amount_out: BigUint,
+    ) {
+        let epoch = self.blockchain().get_block_epoch();
+        let caller = self.blockchain().get_caller();
+        let (token_id_in, token_id_out) = match swap_tokens_order {
+            SwapTokensOrder::PoolOrder => (
+                storage_cache.first_token_id.clone(),
+                storage_cache.second_token_id.clone(),
+            ),
+            SwapTokensOrder::ReverseOrder => (
+                storage_cache.second_token_id.clone(),
+                storage_cache.first_token_id.clone(),
+            ),
+        };
+        self.flash_swap_event(
+            &token_id_out,
+            &caller,
+            epoch,
+            &FlashSwapEvent {
+                caller: caller.clone(),
+                token_id_out: token_id_out.clone(),
+                token_amount_out,
+                token_id_in,
+                repaid_amount_in,
+                repaid_amount_out,
+                token_in_reserve: storage_cache.get_reserve_in(swap_tokens_order).clone(),
+                token_out_reserve: storage_cache.get_reserve_out(swap_tokens_order).clone(),
+                block: self.blockchain().get_block_nonce(),
+                epoch,
+                timestamp: self.blockchain().get_block_timestamp(),
+            },
+        )
+    }
+
     fn emit_add_liquidity_event(
         &self,
         storage_cache: &StorageCache<Self>,
@@ -207,6 +262,15 @@ pub trait EventsModule:
         swap_no_fee_and_forward_event: &SwapNoFeeAndForwardEvent<Self::Api>,
     );
 
+    #[event("flash_swap")]
+    fn flash_swap_event(
+        &self,
+        #[indexed] token_out: &TokenIdentifier,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] epoch: u64,
+        flash_swap_event: &FlashSwapEvent<Self::Api>,
+    );
+
     #[event("add_liquidity")]
     fn add_liquidity_event(
         &self,
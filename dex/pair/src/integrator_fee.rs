@@ -0,0 +1,164 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use multiversx_sc::storage::StorageKey;
+
+use crate::{config::MAX_PERCENTAGE, contexts::swap::SwapContext};
+
+pub static INTEGRATOR_FEE_SHARE_STORAGE_KEY: &[u8] = b"integratorFeeSharePercentage";
+
+#[derive(TypeAbi, TopEncode)]
+pub struct IntegratorFeeEvent<M: ManagedTypeApi> {
+    integrator: ManagedAddress<M>,
+    token_id: TokenIdentifier<M>,
+    volume: BigUint<M>,
+    fee_amount: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait IntegratorFeeModule:
+    crate::config::ConfigModule
+    + token_send::TokenSendModule
+    + permissions_module::PermissionsModule
+    + pausable::PausableModule
+{
+    /// Sends the caller all the fees credited to them as integrator.
+    #[endpoint(claimIntegratorFees)]
+    fn claim_integrator_fees(&self) -> ManagedVec<EsdtTokenPayment> {
+        let caller = self.blockchain().get_caller();
+        let mut claimable_fees = self.integrator_claimable_fees(&caller);
+
+        let mut payments = ManagedVec::new();
+        for (token_id, amount) in claimable_fees.iter() {
+            payments.push(EsdtTokenPayment::new(token_id, 0, amount));
+        }
+        claimable_fees.clear();
+
+        self.send_multiple_tokens_if_not_zero(&caller, &payments);
+
+        payments
+    }
+
+    /// Credits the integrator with their share of the swap's special fee, if they are approved in the router.
+    /// Returns the credited amount, which is no longer distributed to the other fee destinations.
+    fn credit_integrator_fee(
+        &self,
+        opt_integrator: OptionalValue<ManagedAddress>,
+        swap_context: &SwapContext<Self::Api>,
+    ) -> BigUint {
+        let integrator = match opt_integrator {
+            OptionalValue::Some(integrator) => integrator,
+            OptionalValue::None => return BigUint::zero(),
+        };
+
+        let fee_share_percentage = self
+            .get_integrator_fee_share_percentage_mapper(&integrator)
+            .get();
+        if fee_share_percentage == 0 {
+            return BigUint::zero();
+        }
+
+        let token_id = &swap_context.input_token_id;
+        let volume = &swap_context.final_input_amount;
+        let fee_amount = &swap_context.fee_amount * fee_share_percentage / MAX_PERCENTAGE;
+
+        self.integrator_volume(&integrator, token_id)
+            .update(|total| *total += volume);
+        if fee_amount > 0 {
+            self.integrator_total_fees(&integrator, token_id)
+                .update(|total| *total += &fee_amount);
+
+            let mut claimable_fees = self.integrator_claimable_fees(&integrator);
+            let claimable_amount = claimable_fees.get(token_id).unwrap_or_default();
+            claimable_fees.insert(token_id.clone(), claimable_amount + &fee_amount);
+        }
+
+        let caller = self.blockchain().get_caller();
+        let epoch = self.blockchain().get_block_epoch();
+        self.integrator_fee_event(
+            &integrator,
+            &caller,
+            epoch,
+            &IntegratorFeeEvent {
+                integrator: integrator.clone(),
+                token_id: token_id.clone(),
+                volume: volume.clone(),
+                fee_amount: fee_amount.clone(),
+            },
+        );
+
+        fee_amount
+    }
+
+    /// The integrator registry is kept by the router
+    fn get_integrator_fee_share_percentage_mapper(
+        &self,
+        integrator: &ManagedAddress,
+    ) -> SingleValueMapper<u64, ManagedAddress> {
+        let mut storage_key = StorageKey::new(INTEGRATOR_FEE_SHARE_STORAGE_KEY);
+        storage_key.append_item(integrator);
+
+        SingleValueMapper::<_, _, ManagedAddress>::new_from_address(
+            self.router_address().get(),
+            storage_key,
+        )
+    }
+
+    #[view(getIntegratorClaimableFees)]
+    fn get_integrator_claimable_fees(
+        &self,
+        integrator: ManagedAddress,
+    ) -> MultiValueEncoded<EsdtTokenPayment> {
+        let mut result = MultiValueEncoded::new();
+        for (token_id, amount) in self.integrator_claimable_fees(&integrator).iter() {
+            result.push(EsdtTokenPayment::new(token_id, 0, amount));
+        }
+
+        result
+    }
+
+    /// Returns (token, swap volume, total credited fees) for each of the pair's tokens
+    #[view(getIntegratorStats)]
+    fn get_integrator_stats(
+        &self,
+        integrator: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue3<TokenIdentifier, BigUint, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for token_id in [self.first_token_id().get(), self.second_token_id().get()] {
+            let volume = self.integrator_volume(&integrator, &token_id).get();
+            let total_fees = self.integrator_total_fees(&integrator, &token_id).get();
+            result.push((token_id, volume, total_fees).into());
+        }
+
+        result
+    }
+
+    #[event("integratorFee")]
+    fn integrator_fee_event(
+        &self,
+        #[indexed] integrator: &ManagedAddress,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] epoch: u64,
+        integrator_fee_event: &IntegratorFeeEvent<Self::Api>,
+    );
+
+    #[storage_mapper("integratorClaimableFees")]
+    fn integrator_claimable_fees(
+        &self,
+        integrator: &ManagedAddress,
+    ) -> MapMapper<TokenIdentifier, BigUint>;
+
+    #[storage_mapper("integratorVolume")]
+    fn integrator_volume(
+        &self,
+        integrator: &ManagedAddress,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("integratorTotalFees")]
+    fn integrator_total_fees(
+        &self,
+        integrator: &ManagedAddress,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+}
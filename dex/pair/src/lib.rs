@@ -9,6 +9,7 @@ mod contexts;
 pub mod errors;
 mod events;
 pub mod fee;
+pub mod integrator_fee;
 mod liquidity_pool;
 pub mod locking_wrapper;
 pub mod pair_actions;
@@ -30,6 +31,7 @@ use permissions_module::Permissions;
 pub trait Pair<ContractReader>:
     amm::AmmModule
     + fee::FeeModule
+    + integrator_fee::IntegratorFeeModule
     + liquidity_pool::LiquidityPoolModule
     + config::ConfigModule
     + token_send::TokenSendModule
@@ -45,6 +47,7 @@ pub trait Pair<ContractReader>:
     + pair_actions::add_liq::AddLiquidityModule
     + pair_actions::remove_liq::RemoveLiquidityModule
     + pair_actions::swap::SwapModule
+    + pair_actions::flash_swap::FlashSwapModule
     + pair_actions::views::ViewsModule
     + pair_actions::common_methods::CommonMethodsModule
     + utils::UtilsModule
@@ -1,7 +1,7 @@
 use crate::{
-    contexts::add_liquidity::AddLiquidityContext, StorageCache, ERROR_BAD_PAYMENT_TOKENS,
-    ERROR_INITIAL_LIQUIDITY_NOT_ADDED, ERROR_INVALID_ARGS, ERROR_K_INVARIANT_FAILED,
-    ERROR_LP_TOKEN_NOT_ISSUED, ERROR_NOT_ACTIVE,
+    contexts::add_liquidity::AddLiquidityContext, StorageCache, ERROR_ADD_LIQUIDITY_DISABLED,
+    ERROR_BAD_PAYMENT_TOKENS, ERROR_INITIAL_LIQUIDITY_NOT_ADDED, ERROR_INVALID_ARGS,
+    ERROR_K_INVARIANT_FAILED, ERROR_LP_TOKEN_NOT_ISSUED, ERROR_NOT_ACTIVE,
 };
 
 use super::common_result_types::AddLiquidityResultType;
@@ -53,6 +53,10 @@ pub trait AddLiquidityModule:
             self.is_state_active(storage_cache.contract_state),
             ERROR_NOT_ACTIVE
         );
+        require!(
+            !self.add_liquidity_disabled().get(),
+            ERROR_ADD_LIQUIDITY_DISABLED
+        );
         require!(
             storage_cache.lp_token_id.is_valid_esdt_identifier(),
             ERROR_LP_TOKEN_NOT_ISSUED
@@ -0,0 +1,154 @@
+use crate::{
+    config::MAX_PERCENTAGE, contexts::base::SwapTokensOrder, StorageCache,
+    ERROR_FLASH_SWAP_CALLER_NOT_SC, ERROR_INVALID_ARGS, ERROR_K_INVARIANT_FAILED,
+    ERROR_NOT_ENOUGH_RESERVE, ERROR_SWAP_NOT_ENABLED, ERROR_UNKNOWN_TOKEN,
+};
+
+use pausable::State;
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait FlashSwapModule:
+    crate::liquidity_pool::LiquidityPoolModule
+    + crate::amm::AmmModule
+    + crate::locking_wrapper::LockingWrapperModule
+    + crate::events::EventsModule
+    + crate::safe_price::SafePriceModule
+    + crate::fee::FeeModule
+    + crate::config::ConfigModule
+    + token_send::TokenSendModule
+    + permissions_module::PermissionsModule
+    + pausable::PausableModule
+    + super::common_methods::CommonMethodsModule
+    + utils::UtilsModule
+{
+    /// Sends amount_out of token_out to the calling contract, and then calls
+    /// callback_endpoint on it with the given arguments.
+    /// Before the callback returns, the caller must send the tokens back to the pair,
+    /// in either of the pool tokens, so that the k invariant holds after deducting
+    /// the swap fee from the repaid amounts.
+    /// The special fee is taken from the repaid amounts, as for a regular swap.
+    /// The pair is not usable while the callback is executed.
+    #[endpoint(flashSwap)]
+    fn flash_swap(
+        &self,
+        token_out: TokenIdentifier,
+        amount_out: BigUint,
+        callback_endpoint: ManagedBuffer,
+        callback_args: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        require!(amount_out > 0, ERROR_INVALID_ARGS);
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.blockchain().is_smart_contract(&caller),
+            ERROR_FLASH_SWAP_CALLER_NOT_SC
+        );
+
+        let mut storage_cache = StorageCache::new(self);
+        require!(
+            self.can_swap(storage_cache.contract_state),
+            ERROR_SWAP_NOT_ENABLED
+        );
+
+        let token_in = if token_out == storage_cache.first_token_id {
+            storage_cache.second_token_id.clone()
+        } else if token_out == storage_cache.second_token_id {
+            storage_cache.first_token_id.clone()
+        } else {
+            sc_panic!(ERROR_UNKNOWN_TOKEN);
+        };
+        let swap_tokens_order = storage_cache.get_swap_tokens_order(&token_in, &token_out);
+
+        let reserve_out = storage_cache.get_reserve_out(swap_tokens_order);
+        require!(*reserve_out > amount_out, ERROR_NOT_ENOUGH_RESERVE);
+
+        self.update_safe_price(
+            &storage_cache.first_token_reserve,
+            &storage_cache.second_token_reserve,
+            &storage_cache.lp_token_supply,
+        );
+
+        let initial_k = self.calculate_k_constant(
+            &storage_cache.first_token_reserve,
+            &storage_cache.second_token_reserve,
+        );
+
+        let balance_in_before = self.get_own_token_balance(&token_in);
+        let balance_out_before = self.get_own_token_balance(&token_out);
+
+        // no other pair action may be performed until the tokens are repaid
+        self.state().set(State::Inactive);
+        let mut contract_call = self
+            .send()
+            .contract_call::<()>(caller, callback_endpoint)
+            .with_esdt_transfer(EsdtTokenPayment::new(
+                token_out.clone(),
+                0,
+                amount_out.clone(),
+            ));
+        for arg in callback_args {
+            contract_call.push_raw_argument(arg);
+        }
+        let _: IgnoreValue = contract_call.execute_on_dest_context();
+        self.state().set(storage_cache.contract_state);
+
+        let balance_in_after = self.get_own_token_balance(&token_in);
+        let balance_out_after = self.get_own_token_balance(&token_out);
+        require!(
+            balance_in_after >= balance_in_before
+                && &balance_out_after + &amount_out >= balance_out_before,
+            ERROR_K_INVARIANT_FAILED
+        );
+
+        let repaid_in = balance_in_after - balance_in_before;
+        let repaid_out = balance_out_after + &amount_out - balance_out_before;
+
+        *storage_cache.get_mut_reserve_in(swap_tokens_order) += &repaid_in;
+        *storage_cache.get_mut_reserve_out(swap_tokens_order) += &repaid_out;
+        *storage_cache.get_mut_reserve_out(swap_tokens_order) -= &amount_out;
+
+        let total_fee_percent = self.total_fee_percent().get();
+        let adjusted_reserve_in = storage_cache.get_reserve_in(swap_tokens_order) * MAX_PERCENTAGE
+            - &repaid_in * total_fee_percent;
+        let adjusted_reserve_out = storage_cache.get_reserve_out(swap_tokens_order)
+            * MAX_PERCENTAGE
+            - &repaid_out * total_fee_percent;
+        let adjusted_k = self.calculate_k_constant(&adjusted_reserve_in, &adjusted_reserve_out);
+        require!(
+            adjusted_k >= initial_k * MAX_PERCENTAGE * MAX_PERCENTAGE,
+            ERROR_K_INVARIANT_FAILED
+        );
+
+        let mut fee_in = BigUint::zero();
+        let mut fee_out = BigUint::zero();
+        if self.is_fee_enabled() {
+            fee_in = self.get_special_fee_from_input(&repaid_in);
+            fee_out = self.get_special_fee_from_input(&repaid_out);
+
+            *storage_cache.get_mut_reserve_in(swap_tokens_order) -= &fee_in;
+            *storage_cache.get_mut_reserve_out(swap_tokens_order) -= &fee_out;
+        }
+
+        self.send_fee(&mut storage_cache, swap_tokens_order, &token_in, &fee_in);
+        let reverse_order = match swap_tokens_order {
+            SwapTokensOrder::PoolOrder => SwapTokensOrder::ReverseOrder,
+            SwapTokensOrder::ReverseOrder => SwapTokensOrder::PoolOrder,
+        };
+        self.send_fee(&mut storage_cache, reverse_order, &token_out, &fee_out);
+
+        self.emit_flash_swap_event(
+            &storage_cache,
+            swap_tokens_order,
+            amount_out,
+            repaid_in,
+            repaid_out,
+        );
+    }
+
+    fn get_own_token_balance(&self, token_id: &TokenIdentifier) -> BigUint {
+        self.blockchain()
+            .get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(token_id.clone()), 0)
+    }
+}
@@ -1,6 +1,7 @@
 pub mod add_liq;
 pub mod common_methods;
 pub mod common_result_types;
+pub mod flash_swap;
 pub mod initial_liq;
 pub mod remove_liq;
 pub mod swap;
@@ -24,6 +24,7 @@ pub trait SwapModule:
     + crate::events::EventsModule
     + crate::safe_price::SafePriceModule
     + crate::fee::FeeModule
+    + crate::integrator_fee::IntegratorFeeModule
     + crate::config::ConfigModule
     + token_send::TokenSendModule
     + permissions_module::PermissionsModule
@@ -90,12 +91,14 @@ pub trait SwapModule:
         self.emit_swap_no_fee_and_forward_event(swap_context, destination_address);
     }
 
+    /// The optional integrator is credited with a share of the special fee, if approved in the router.
     #[payable("*")]
     #[endpoint(swapTokensFixedInput)]
     fn swap_tokens_fixed_input(
         &self,
         token_out: TokenIdentifier,
         amount_out_min: BigUint,
+        opt_integrator: OptionalValue<ManagedAddress>,
     ) -> SwapTokensFixedInputResultType<Self::Api> {
         require!(amount_out_min > 0, ERROR_INVALID_ARGS);
 
@@ -138,12 +141,14 @@ pub trait SwapModule:
         );
         require!(initial_k <= new_k, ERROR_K_INVARIANT_FAILED);
 
-        if swap_context.fee_amount > 0 {
+        let integrator_fee_amount = self.credit_integrator_fee(opt_integrator, &swap_context);
+        let remaining_fee_amount = &swap_context.fee_amount - &integrator_fee_amount;
+        if remaining_fee_amount > 0 {
             self.send_fee(
                 &mut storage_cache,
                 swap_context.swap_tokens_order,
                 &swap_context.input_token_id,
-                &swap_context.fee_amount,
+                &remaining_fee_amount,
             );
         }
 
@@ -162,12 +167,14 @@ pub trait SwapModule:
         self.build_swap_fixed_input_results(output_payments)
     }
 
+    /// The optional integrator is credited with a share of the special fee, if approved in the router.
     #[payable("*")]
     #[endpoint(swapTokensFixedOutput)]
     fn swap_tokens_fixed_output(
         &self,
         token_out: TokenIdentifier,
         amount_out: BigUint,
+        opt_integrator: OptionalValue<ManagedAddress>,
     ) -> SwapTokensFixedOutputResultType<Self::Api> {
         require!(amount_out > 0, ERROR_INVALID_ARGS);
 
@@ -210,12 +217,14 @@ pub trait SwapModule:
         );
         require!(initial_k <= new_k, ERROR_K_INVARIANT_FAILED);
 
-        if swap_context.fee_amount > 0 {
+        let integrator_fee_amount = self.credit_integrator_fee(opt_integrator, &swap_context);
+        let remaining_fee_amount = &swap_context.fee_amount - &integrator_fee_amount;
+        if remaining_fee_amount > 0 {
             self.send_fee(
                 &mut storage_cache,
                 swap_context.swap_tokens_order,
                 &swap_context.input_token_id,
-                &swap_context.fee_amount,
+                &remaining_fee_amount,
             );
         }
 
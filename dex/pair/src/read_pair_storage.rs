@@ -10,6 +10,7 @@ pub static SECOND_TOKEN_ID_STORAGE_KEY: &[u8] = b"second_token_id";
 pub static SAFE_PRICE_CURRENT_INDEX_STORAGE_KEY: &[u8] = b"safe_price_current_index";
 pub static PRICE_OBSERVATIONS_STORAGE_KEY: &[u8] = b"price_observations";
 pub static PAIR_RESERVE_BASE_STORAGE_KEY: &[u8] = b"reserve";
+pub static TOTAL_FEE_PERCENT_STORAGE_KEY: &[u8] = b"total_fee_percent";
 
 #[multiversx_sc::module]
 pub trait ReadPairStorageModule {
@@ -73,4 +74,14 @@ pub trait ReadPairStorageModule {
 
         SingleValueMapper::<_, _, ManagedAddress>::new_from_address(pair_address, storage_key)
     }
+
+    fn get_total_fee_percent_mapper(
+        &self,
+        pair_address: ManagedAddress,
+    ) -> SingleValueMapper<u64, ManagedAddress> {
+        SingleValueMapper::<_, _, ManagedAddress>::new_from_address(
+            pair_address,
+            StorageKey::new(TOTAL_FEE_PERCENT_STORAGE_KEY),
+        )
+    }
 }
@@ -0,0 +1,38 @@
+#![allow(dead_code)]
+
+multiversx_sc::imports!();
+
+use pair::pair_actions::swap::ProxyTrait as _;
+
+#[multiversx_sc::contract]
+pub trait FlashSwapCalleeMock {
+    #[init]
+    fn init(&self) {}
+
+    #[payable("*")]
+    #[endpoint(repayFlashSwap)]
+    fn repay_flash_swap(&self, repay_token_id: TokenIdentifier, repay_amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.send()
+            .direct_esdt(&caller, &repay_token_id, 0, &repay_amount);
+    }
+
+    #[payable("*")]
+    #[endpoint(swapDuringFlashSwap)]
+    fn swap_during_flash_swap(&self, token_out: TokenIdentifier) {
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().single_esdt();
+        let _: IgnoreValue = self
+            .pair_proxy(caller)
+            .swap_tokens_fixed_input(
+                token_out,
+                BigUint::from(1u32),
+                OptionalValue::<ManagedAddress>::None,
+            )
+            .with_esdt_transfer(payment)
+            .execute_on_dest_context();
+    }
+
+    #[proxy]
+    fn pair_proxy(&self, sc_address: ManagedAddress) -> pair::Proxy<Self::Api>;
+}
@@ -0,0 +1,253 @@
+#![allow(deprecated)]
+
+mod flash_swap_callee_mock;
+#[allow(dead_code)]
+mod pair_setup;
+
+use fees_collector::{config::ConfigModule, FeesCollector};
+use multiversx_sc::types::{ManagedBuffer, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_buffer, managed_token_id, rust_biguint,
+    whitebox_legacy::TxResult,
+};
+use pair::{
+    config::{ConfigModule as PairConfigModule, MAX_PERCENTAGE},
+    fee::FeeModule,
+    pair_actions::flash_swap::FlashSwapModule,
+};
+use pair_setup::*;
+
+const FLASH_SWAP_AMOUNT: u64 = 10_000;
+
+fn call_flash_swap<PairObjBuilder>(
+    pair_setup: &mut PairSetup<PairObjBuilder>,
+    callee_address: &multiversx_sc::types::Address,
+    callback_endpoint: &[u8],
+    callback_args: Vec<Vec<u8>>,
+) -> TxResult
+where
+    PairObjBuilder: 'static + Copy + Fn() -> pair::ContractObj<multiversx_sc_scenario::DebugApi>,
+{
+    pair_setup.b_mock.execute_tx(
+        callee_address,
+        &pair_setup.pair_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let mut args = MultiValueEncoded::new();
+            for arg in callback_args {
+                args.push(ManagedBuffer::from(arg.as_slice()));
+            }
+
+            sc.flash_swap(
+                managed_token_id!(MEX_TOKEN_ID),
+                managed_biguint!(FLASH_SWAP_AMOUNT),
+                managed_buffer!(callback_endpoint),
+                args,
+            );
+        },
+    )
+}
+
+fn repay_args(token_id: &[u8], amount: u64) -> Vec<Vec<u8>> {
+    vec![token_id.to_vec(), rust_biguint!(amount).to_bytes_be()]
+}
+
+#[test]
+fn flash_swap_repaid_in_same_token_test() {
+    let mut pair_setup = PairSetup::new(pair::contract_obj);
+    let fees_collector_wrapper = pair_setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        fees_collector::contract_obj,
+        "fees collector path",
+    );
+    let callee_wrapper = pair_setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        flash_swap_callee_mock::contract_obj,
+        "flash swap callee path",
+    );
+    let callee_addr = callee_wrapper.address_ref().clone();
+    pair_setup
+        .b_mock
+        .set_esdt_balance(&callee_addr, MEX_TOKEN_ID, &rust_biguint!(1_000));
+
+    let pair_addr = pair_setup.pair_wrapper.address_ref().clone();
+    pair_setup
+        .b_mock
+        .execute_tx(
+            &pair_setup.owner_address,
+            &fees_collector_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init(
+                    managed_token_id!(LOCKED_TOKEN_ID),
+                    managed_address!(&pair_addr),
+                );
+                let _ = sc.known_contracts().insert(managed_address!(&pair_addr));
+
+                let mut tokens = MultiValueEncoded::new();
+                tokens.push(managed_token_id!(WEGLD_TOKEN_ID));
+                tokens.push(managed_token_id!(MEX_TOKEN_ID));
+
+                sc.add_known_tokens(tokens);
+            },
+        )
+        .assert_ok();
+
+    pair_setup
+        .b_mock
+        .execute_tx(
+            &pair_setup.owner_address,
+            &pair_setup.pair_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.setup_fees_collector(
+                    managed_address!(fees_collector_wrapper.address_ref()),
+                    MAX_PERCENTAGE / 2,
+                );
+            },
+        )
+        .assert_ok();
+
+    pair_setup.add_liquidity(
+        1_001_000, 1_000_000, 1_001_000, 1_000_000, 1_000_000, 1_001_000, 1_001_000,
+    );
+
+    // 10_000 * 100_000 / 99_700 rounded up is 10_031
+    call_flash_swap(
+        &mut pair_setup,
+        &callee_addr,
+        b"repayFlashSwap",
+        repay_args(MEX_TOKEN_ID, 10_030),
+    )
+    .assert_user_error("K invariant failed");
+
+    call_flash_swap(
+        &mut pair_setup,
+        &callee_addr,
+        b"repayFlashSwap",
+        repay_args(MEX_TOKEN_ID, 10_031),
+    )
+    .assert_ok();
+
+    // special fee is 10_031 * 50 / 100_000 = 5, half of which goes to the fees collector
+    pair_setup.b_mock.check_esdt_balance(
+        fees_collector_wrapper.address_ref(),
+        MEX_TOKEN_ID,
+        &rust_biguint!(2),
+    );
+    pair_setup
+        .b_mock
+        .check_esdt_balance(&callee_addr, MEX_TOKEN_ID, &rust_biguint!(1_000 - 31));
+
+    pair_setup
+        .b_mock
+        .execute_query(&pair_setup.pair_wrapper, |sc| {
+            assert_eq!(
+                sc.pair_reserve(&managed_token_id!(WEGLD_TOKEN_ID)).get(),
+                managed_biguint!(1_001_000)
+            );
+            assert_eq!(
+                sc.pair_reserve(&managed_token_id!(MEX_TOKEN_ID)).get(),
+                managed_biguint!(1_001_000 + 31 - 5)
+            );
+            assert!(sc.is_fee_enabled());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn flash_swap_repaid_in_other_token_test() {
+    let mut pair_setup = PairSetup::new(pair::contract_obj);
+    let callee_wrapper = pair_setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        flash_swap_callee_mock::contract_obj,
+        "flash swap callee path",
+    );
+    let callee_addr = callee_wrapper.address_ref().clone();
+    pair_setup
+        .b_mock
+        .set_esdt_balance(&callee_addr, WEGLD_TOKEN_ID, &rust_biguint!(20_000));
+
+    pair_setup.add_liquidity(
+        1_001_000, 1_000_000, 1_001_000, 1_000_000, 1_000_000, 1_001_000, 1_001_000,
+    );
+
+    // equivalent to a fixed output swap, which would require 10_132 WEGLD
+    call_flash_swap(
+        &mut pair_setup,
+        &callee_addr,
+        b"repayFlashSwap",
+        repay_args(WEGLD_TOKEN_ID, 10_131),
+    )
+    .assert_user_error("K invariant failed");
+
+    call_flash_swap(
+        &mut pair_setup,
+        &callee_addr,
+        b"repayFlashSwap",
+        repay_args(WEGLD_TOKEN_ID, 10_132),
+    )
+    .assert_ok();
+
+    pair_setup.b_mock.check_esdt_balance(
+        &callee_addr,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(20_000 - 10_132),
+    );
+    pair_setup.b_mock.check_esdt_balance(
+        &callee_addr,
+        MEX_TOKEN_ID,
+        &rust_biguint!(FLASH_SWAP_AMOUNT),
+    );
+
+    pair_setup
+        .b_mock
+        .execute_query(&pair_setup.pair_wrapper, |sc| {
+            assert_eq!(
+                sc.pair_reserve(&managed_token_id!(WEGLD_TOKEN_ID)).get(),
+                managed_biguint!(1_001_000 + 10_132)
+            );
+            assert_eq!(
+                sc.pair_reserve(&managed_token_id!(MEX_TOKEN_ID)).get(),
+                managed_biguint!(1_001_000 - FLASH_SWAP_AMOUNT)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn flash_swap_no_reentrancy_test() {
+    let mut pair_setup = PairSetup::new(pair::contract_obj);
+    let callee_wrapper = pair_setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        flash_swap_callee_mock::contract_obj,
+        "flash swap callee path",
+    );
+    let callee_addr = callee_wrapper.address_ref().clone();
+
+    pair_setup.add_liquidity(
+        1_001_000, 1_000_000, 1_001_000, 1_000_000, 1_000_000, 1_001_000, 1_001_000,
+    );
+
+    call_flash_swap(
+        &mut pair_setup,
+        &callee_addr,
+        b"swapDuringFlashSwap",
+        vec![WEGLD_TOKEN_ID.to_vec()],
+    )
+    .assert_user_error("Swap is not enabled");
+
+    // only contracts may do flash swaps
+    let user_addr = pair_setup.user_address.clone();
+    call_flash_swap(
+        &mut pair_setup,
+        &user_addr,
+        b"repayFlashSwap",
+        repay_args(MEX_TOKEN_ID, 10_031),
+    )
+    .assert_user_error("Flash swaps may only be done by contracts");
+}
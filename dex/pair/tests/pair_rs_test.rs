@@ -1203,6 +1203,7 @@ fn test_locked_asset() {
                 let ret = sc.swap_tokens_fixed_input(
                     managed_token_id!(WEGLD_TOKEN_ID),
                     managed_biguint!(10),
+                    OptionalValue::None,
                 );
 
                 assert_eq!(ret.token_identifier, managed_token_id!(LOCKED_TOKEN_ID));
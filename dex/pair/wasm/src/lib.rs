@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           54
+// Endpoints:                           60
 // Async Callback (empty):               1
-// Total number of exported functions:  57
+// Total number of exported functions:  63
 
 #![no_std]
 
@@ -33,7 +33,11 @@ multiversx_sc_wasm_adapter::endpoints! {
         getWhitelistedManagedAddresses => get_whitelisted_managed_addresses
         getFeesCollectorAddress => fees_collector_address
         getFeesCollectorCutPercentage => fees_collector_cut_percentage
+        claimIntegratorFees => claim_integrator_fees
+        getIntegratorClaimableFees => get_integrator_claimable_fees
+        getIntegratorStats => get_integrator_stats
         setStateActiveNoSwaps => set_state_active_no_swaps
+        setAddLiquidityDisabled => set_add_liquidity_disabled
         setFeePercents => set_fee_percent
         getLpTokenIdentifier => get_lp_token_identifier
         getTotalFeePercent => total_fee_percent
@@ -43,6 +47,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         getSecondTokenId => second_token_id
         getTotalSupply => lp_token_supply
         getInitialLiquidtyAdder => initial_liquidity_adder
+        isAddLiquidityDisabled => add_liquidity_disabled
         getReserve => pair_reserve
         getSafePriceCurrentIndex => safe_price_current_index
         updateAndGetTokensForGivenPositionWithSafePrice => update_and_get_tokens_for_given_position_with_safe_price
@@ -69,6 +74,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         swapNoFeeAndForward => swap_no_fee
         swapTokensFixedInput => swap_tokens_fixed_input
         swapTokensFixedOutput => swap_tokens_fixed_output
+        flashSwap => flash_swap
         getTokensForGivenPosition => get_tokens_for_given_position
         getReservesAndTotalSupply => get_reserves_and_total_supply
         getAmountOut => get_amount_out_view
@@ -0,0 +1,218 @@
+multiversx_sc::imports!();
+
+use crate::{
+    config::{self, MAX_PERCENTAGE},
+    events,
+};
+use pair::{read_pair_storage, safe_price_view::ProxyTrait as _};
+use router::{
+    factory::ProxyTrait as _,
+    multi_pair_swap::{ProxyTrait as _, SWAP_TOKENS_FIXED_INPUT_FUNC_NAME},
+    zap::ProxyTrait as _,
+};
+
+#[multiversx_sc::module]
+pub trait BuybackModule:
+    config::ConfigModule + events::EventsModule + read_pair_storage::ReadPairStorageModule
+{
+    /// Uses the accumulated fees of the given token, up to its max buyback amount,
+    /// to buy governance tokens through the router. Fees in the governance token are used directly.
+    /// burn_percentage of the bought tokens are burned, and the rest is added as liquidity in the POL pair.
+    /// Buybacks are at least buyback_cooldown_blocks apart.
+    #[endpoint]
+    fn buyback(&self, fee_token_id: TokenIdentifier) {
+        self.require_caller_keeper();
+
+        let current_block = self.blockchain().get_block_nonce();
+        let last_buyback_block_mapper = self.last_buyback_block();
+        require!(
+            last_buyback_block_mapper.is_empty()
+                || last_buyback_block_mapper.get() + self.buyback_cooldown_blocks().get()
+                    <= current_block,
+            "Buyback cooldown not passed"
+        );
+
+        let max_amount = self.max_buyback_amount(&fee_token_id).get();
+        require!(max_amount > 0, "Buyback not enabled for token");
+
+        let accumulated_fees = self.accumulated_fees(&fee_token_id).get();
+        require!(accumulated_fees > 0, "No fees to buy back");
+
+        let fee_amount = core::cmp::min(accumulated_fees, max_amount);
+        self.accumulated_fees(&fee_token_id)
+            .update(|amount| *amount -= &fee_amount);
+
+        let governance_token_id = self.governance_token_id().get();
+        let bought_amount = if fee_token_id == governance_token_id {
+            fee_amount.clone()
+        } else {
+            self.buy_governance_tokens(fee_token_id.clone(), fee_amount.clone())
+        };
+
+        let burn_amount = &bought_amount * self.burn_percentage().get() / MAX_PERCENTAGE;
+        let pol_amount = &bought_amount - &burn_amount;
+        if burn_amount > 0 {
+            self.send()
+                .esdt_local_burn(&governance_token_id, 0, &burn_amount);
+            self.total_burned().update(|total| *total += &burn_amount);
+        }
+
+        let mut pol_lp_amount = BigUint::zero();
+        if pol_amount > 0 {
+            pol_lp_amount = self.add_protocol_owned_liquidity(pol_amount.clone());
+            self.total_pol_governance_tokens()
+                .update(|total| *total += &pol_amount);
+            self.total_pol_lp_tokens()
+                .update(|total| *total += &pol_lp_amount);
+        }
+
+        self.total_bought_back()
+            .update(|total| *total += &bought_amount);
+        last_buyback_block_mapper.set(current_block);
+
+        self.emit_buyback_event(
+            EsdtTokenPayment::new(fee_token_id, 0, fee_amount),
+            bought_amount,
+            burn_amount,
+            pol_amount,
+            pol_lp_amount,
+        );
+    }
+
+    fn accumulate_fees(&self, token_id: &TokenIdentifier, amount: &BigUint) {
+        self.accumulated_fees(token_id)
+            .update(|accumulated| *accumulated += amount);
+        let _ = self.fee_tokens().insert(token_id.clone());
+    }
+
+    /// Swaps through the router, with the min amount out given by the safe price of the pair
+    fn buy_governance_tokens(&self, fee_token_id: TokenIdentifier, amount: BigUint) -> BigUint {
+        let router_address = self.router_address().get();
+        let governance_token_id = self.governance_token_id().get();
+        let pair_address: ManagedAddress = self
+            .router_proxy(router_address.clone())
+            .get_pair(fee_token_id.clone(), governance_token_id.clone())
+            .execute_on_dest_context();
+        require!(!pair_address.is_zero(), "No pair for fee token");
+
+        let payment = EsdtTokenPayment::new(fee_token_id, 0, amount);
+        let safe_price_payment: EsdtTokenPayment = self
+            .pair_proxy(self.safe_price_view_address().get())
+            .get_safe_price_by_default_offset(pair_address.clone(), payment.clone())
+            .execute_on_dest_context();
+        let min_amount_out = self.apply_max_price_deviation(&safe_price_payment.amount);
+
+        let mut swap_operations = MultiValueEncoded::new();
+        swap_operations.push(MultiValue4::from((
+            pair_address,
+            ManagedBuffer::from(SWAP_TOKENS_FIXED_INPUT_FUNC_NAME),
+            governance_token_id,
+            min_amount_out,
+        )));
+        let output_payments: ManagedVec<EsdtTokenPayment> = self
+            .router_proxy(router_address)
+            .multi_pair_swap(swap_operations)
+            .with_esdt_transfer(payment)
+            .execute_on_dest_context();
+
+        output_payments.get(output_payments.len() - 1).amount
+    }
+
+    /// Zaps the governance tokens into the POL pair through the router.
+    /// The value of the received LP tokens, at the safe price, is checked against the added amount.
+    /// The dust left from adding the liquidity is bought back again later.
+    /// Returns the amount of LP tokens received.
+    fn add_protocol_owned_liquidity(&self, amount: BigUint) -> BigUint {
+        let pol_pair_mapper = self.pol_pair_address();
+        require!(!pol_pair_mapper.is_empty(), "POL pair not set");
+
+        let pol_pair_address = pol_pair_mapper.get();
+        let governance_token_id = self.governance_token_id().get();
+        let zap_in_result: ManagedVec<EsdtTokenPayment> = self
+            .router_proxy(self.router_address().get())
+            .zap_in(
+                pol_pair_address.clone(),
+                BigUint::from(1u32),
+                MultiValueEncoded::new(),
+            )
+            .with_esdt_transfer(EsdtTokenPayment::new(
+                governance_token_id.clone(),
+                0,
+                amount.clone(),
+            ))
+            .execute_on_dest_context();
+
+        let lp_payment = zap_in_result.get(0);
+        let mut added_amount = amount;
+        for dust_payment in zap_in_result.slice(1, zap_in_result.len()).unwrap().iter() {
+            if dust_payment.amount == 0 {
+                continue;
+            }
+
+            if dust_payment.token_identifier == governance_token_id {
+                added_amount -= &dust_payment.amount;
+            }
+            self.accumulate_fees(&dust_payment.token_identifier, &dust_payment.amount);
+        }
+
+        let lp_safe_price: MultiValue2<EsdtTokenPayment, EsdtTokenPayment> = self
+            .pair_proxy(self.safe_price_view_address().get())
+            .get_lp_tokens_safe_price_by_default_offset(pol_pair_address, lp_payment.amount.clone())
+            .execute_on_dest_context();
+        let (first_token_payment, second_token_payment) = lp_safe_price.into_tuple();
+        let governance_token_payment =
+            if first_token_payment.token_identifier == governance_token_id {
+                first_token_payment
+            } else {
+                second_token_payment
+            };
+
+        // the liquidity is balanced at the safe price, so it is worth twice its governance tokens
+        let lp_value = governance_token_payment.amount * 2u32;
+        require!(
+            lp_value >= self.apply_max_price_deviation(&added_amount),
+            "POL price deviation too high"
+        );
+
+        lp_payment.amount
+    }
+
+    fn apply_max_price_deviation(&self, amount: &BigUint) -> BigUint {
+        let max_price_deviation_percentage = self.max_price_deviation_percentage().get();
+        amount * (MAX_PERCENTAGE - max_price_deviation_percentage) / MAX_PERCENTAGE
+    }
+
+    #[proxy]
+    fn router_proxy(&self, sc_address: ManagedAddress) -> router::Proxy<Self::Api>;
+
+    #[proxy]
+    fn pair_proxy(&self, sc_address: ManagedAddress) -> pair::Proxy<Self::Api>;
+
+    #[view(getAccumulatedFees)]
+    #[storage_mapper("accumulatedFees")]
+    fn accumulated_fees(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getFeeTokens)]
+    #[storage_mapper("feeTokens")]
+    fn fee_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
+    #[view(getLastBuybackBlock)]
+    #[storage_mapper("lastBuybackBlock")]
+    fn last_buyback_block(&self) -> SingleValueMapper<u64>;
+
+    #[view(getTotalBoughtBack)]
+    #[storage_mapper("totalBoughtBack")]
+    fn total_bought_back(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalBurned)]
+    #[storage_mapper("totalBurned")]
+    fn total_burned(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalPolGovernanceTokens)]
+    #[storage_mapper("totalPolGovernanceTokens")]
+    fn total_pol_governance_tokens(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalPolLpTokens)]
+    #[storage_mapper("totalPolLpTokens")]
+    fn total_pol_lp_tokens(&self) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,149 @@
+multiversx_sc::imports!();
+
+use pair::read_pair_storage;
+
+pub const MAX_PERCENTAGE: u64 = 10_000;
+
+#[multiversx_sc::module]
+pub trait ConfigModule: read_pair_storage::ReadPairStorageModule {
+    #[only_owner]
+    #[endpoint(setRouterAddress)]
+    fn set_router_address(&self, router_address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&router_address),
+            "Invalid router address"
+        );
+
+        self.router_address().set(router_address);
+    }
+
+    /// Usually the safe-price-view contract of the pairs, used for the TWAP slippage checks
+    #[only_owner]
+    #[endpoint(setSafePriceViewAddress)]
+    fn set_safe_price_view_address(&self, safe_price_view_address: ManagedAddress) {
+        require!(
+            self.blockchain()
+                .is_smart_contract(&safe_price_view_address),
+            "Invalid safe price view address"
+        );
+
+        self.safe_price_view_address().set(safe_price_view_address);
+    }
+
+    /// The pair in which the protocol-owned liquidity is added. One of its tokens must be the governance token.
+    #[only_owner]
+    #[endpoint(setPolPairAddress)]
+    fn set_pol_pair_address(&self, pol_pair_address: ManagedAddress) {
+        let governance_token_id = self.governance_token_id().get();
+        let first_token_id = self
+            .get_first_token_id_mapper(pol_pair_address.clone())
+            .get();
+        let second_token_id = self
+            .get_second_token_id_mapper(pol_pair_address.clone())
+            .get();
+        require!(
+            first_token_id == governance_token_id || second_token_id == governance_token_id,
+            "Invalid POL pair"
+        );
+
+        self.pol_pair_address().set(pol_pair_address);
+    }
+
+    /// The part of the bought governance tokens that is burned. The rest is added as liquidity.
+    #[only_owner]
+    #[endpoint(setBurnPercentage)]
+    fn set_burn_percentage(&self, burn_percentage: u64) {
+        require!(burn_percentage <= MAX_PERCENTAGE, "Invalid burn percentage");
+
+        self.burn_percentage().set(burn_percentage);
+    }
+
+    /// How much lower than the safe price a swap result may be
+    #[only_owner]
+    #[endpoint(setMaxPriceDeviationPercentage)]
+    fn set_max_price_deviation_percentage(&self, max_price_deviation_percentage: u64) {
+        require!(
+            max_price_deviation_percentage < MAX_PERCENTAGE,
+            "Invalid max price deviation percentage"
+        );
+
+        self.max_price_deviation_percentage()
+            .set(max_price_deviation_percentage);
+    }
+
+    #[only_owner]
+    #[endpoint(setBuybackCooldownBlocks)]
+    fn set_buyback_cooldown_blocks(&self, buyback_cooldown_blocks: u64) {
+        self.buyback_cooldown_blocks().set(buyback_cooldown_blocks);
+    }
+
+    /// Rate limit for the amount of fee tokens used by a single buyback.
+    /// Fee tokens without a limit are not bought back.
+    #[only_owner]
+    #[endpoint(setMaxBuybackAmount)]
+    fn set_max_buyback_amount(&self, token_id: TokenIdentifier, max_amount: BigUint) {
+        self.max_buyback_amount(&token_id).set(max_amount);
+    }
+
+    #[only_owner]
+    #[endpoint(addKeepers)]
+    fn add_keepers(&self, keepers: MultiValueEncoded<ManagedAddress>) {
+        let mut mapper = self.keepers();
+        for keeper in keepers {
+            let _ = mapper.insert(keeper);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeKeepers)]
+    fn remove_keepers(&self, keepers: MultiValueEncoded<ManagedAddress>) {
+        let mut mapper = self.keepers();
+        for keeper in keepers {
+            let _ = mapper.swap_remove(&keeper);
+        }
+    }
+
+    fn require_caller_keeper(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.keepers().contains(&caller) || caller == self.blockchain().get_owner_address(),
+            "Only keepers can buy back"
+        );
+    }
+
+    #[view(getRouterAddress)]
+    #[storage_mapper("routerAddress")]
+    fn router_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getSafePriceViewAddress)]
+    #[storage_mapper("safePriceViewAddress")]
+    fn safe_price_view_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getPolPairAddress)]
+    #[storage_mapper("polPairAddress")]
+    fn pol_pair_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getGovernanceTokenId)]
+    #[storage_mapper("governanceTokenId")]
+    fn governance_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getBurnPercentage)]
+    #[storage_mapper("burnPercentage")]
+    fn burn_percentage(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMaxPriceDeviationPercentage)]
+    #[storage_mapper("maxPriceDeviationPercentage")]
+    fn max_price_deviation_percentage(&self) -> SingleValueMapper<u64>;
+
+    #[view(getBuybackCooldownBlocks)]
+    #[storage_mapper("buybackCooldownBlocks")]
+    fn buyback_cooldown_blocks(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMaxBuybackAmount)]
+    #[storage_mapper("maxBuybackAmount")]
+    fn max_buyback_amount(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getKeepers)]
+    #[storage_mapper("keepers")]
+    fn keepers(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
@@ -0,0 +1,61 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(TypeAbi, TopEncode)]
+pub struct BuybackEvent<M: ManagedTypeApi> {
+    keeper: ManagedAddress<M>,
+    fee_payment: EsdtTokenPayment<M>,
+    bought_amount: BigUint<M>,
+    burned_amount: BigUint<M>,
+    pol_amount: BigUint<M>,
+    pol_lp_amount: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_deposit_fees_event(&self, caller: ManagedAddress, payment: EsdtTokenPayment) {
+        let epoch = self.blockchain().get_block_epoch();
+        self.deposit_fees_event(&caller, &payment.token_identifier, epoch, &payment.amount);
+    }
+
+    fn emit_buyback_event(
+        &self,
+        fee_payment: EsdtTokenPayment,
+        bought_amount: BigUint,
+        burned_amount: BigUint,
+        pol_amount: BigUint,
+        pol_lp_amount: BigUint,
+    ) {
+        let epoch = self.blockchain().get_block_epoch();
+        let keeper = self.blockchain().get_caller();
+        self.buyback_event(
+            &fee_payment.token_identifier.clone(),
+            epoch,
+            &BuybackEvent {
+                keeper,
+                fee_payment,
+                bought_amount,
+                burned_amount,
+                pol_amount,
+                pol_lp_amount,
+            },
+        );
+    }
+
+    #[event("depositFees")]
+    fn deposit_fees_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] epoch: u64,
+        amount: &BigUint,
+    );
+
+    #[event("buyback")]
+    fn buyback_event(
+        &self,
+        #[indexed] fee_token_id: &TokenIdentifier,
+        #[indexed] epoch: u64,
+        buyback_event: &BuybackEvent<Self::Api>,
+    );
+}
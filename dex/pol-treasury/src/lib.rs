@@ -0,0 +1,61 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod buyback;
+pub mod config;
+pub mod events;
+
+/// Gathers swap fees and, on a keeper-called schedule, uses them to buy the governance token
+/// through the router. Part of the bought tokens is burned, and the rest is added
+/// as protocol-owned liquidity.
+#[multiversx_sc::contract]
+pub trait PolTreasury:
+    config::ConfigModule
+    + buyback::BuybackModule
+    + events::EventsModule
+    + pair::read_pair_storage::ReadPairStorageModule
+{
+    #[init]
+    fn init(
+        &self,
+        router_address: ManagedAddress,
+        safe_price_view_address: ManagedAddress,
+        governance_token_id: TokenIdentifier,
+        burn_percentage: u64,
+        max_price_deviation_percentage: u64,
+        buyback_cooldown_blocks: u64,
+    ) {
+        require!(
+            governance_token_id.is_valid_esdt_identifier(),
+            "Invalid governance token"
+        );
+
+        self.governance_token_id().set(governance_token_id);
+        self.set_router_address(router_address);
+        self.set_safe_price_view_address(safe_price_view_address);
+        self.set_burn_percentage(burn_percentage);
+        self.set_max_price_deviation_percentage(max_price_deviation_percentage);
+        self.set_buyback_cooldown_blocks(buyback_cooldown_blocks);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Pairs deposit their fees through this endpoint, so the treasury can be set as a pair's fees collector.
+    /// Anyone else may also deposit tokens to be bought back.
+    #[payable("*")]
+    #[endpoint(depositSwapFees)]
+    fn deposit_swap_fees(&self) {
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_nonce == 0 && payment.amount > 0,
+            "Invalid payment"
+        );
+
+        self.accumulate_fees(&payment.token_identifier, &payment.amount);
+
+        let caller = self.blockchain().get_caller();
+        self.emit_deposit_fees_event(caller, payment);
+    }
+}
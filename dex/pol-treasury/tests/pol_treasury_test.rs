@@ -0,0 +1,294 @@
+#![allow(deprecated)]
+
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    types::{Address, EsdtLocalRole, ManagedAddress, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint,
+    whitebox_legacy::{BlockchainStateWrapper, TxTokenTransfer},
+    DebugApi,
+};
+use pair::{
+    config::ConfigModule as PairConfigModule,
+    pair_actions::{initial_liq::InitialLiquidityModule, swap::SwapModule},
+    Pair,
+};
+use pausable::{PausableModule, State};
+use pol_treasury::{buyback::BuybackModule, config::ConfigModule, PolTreasury};
+use router::{config::ConfigModule as RouterConfigModule, factory::PairTokens, Router};
+
+const WEGLD_TOKEN_ID: &[u8] = b"WEGLD-123456";
+const MEX_TOKEN_ID: &[u8] = b"MEX-123456";
+const LP_TOKEN_ID: &[u8] = b"LPTOK-123456";
+const POOL_RESERVE: u64 = 1_000_000_000_000;
+const FEES_AMOUNT: u64 = 1_500;
+const MAX_BUYBACK_AMOUNT: u64 = 1_000;
+const BURN_PERCENTAGE: u64 = 5_000; // 50%
+const MAX_PRICE_DEVIATION_PERCENTAGE: u64 = 500; // 5%
+const BUYBACK_COOLDOWN_BLOCKS: u64 = 10;
+
+#[test]
+fn pol_treasury_buyback_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let keeper = b_mock.create_user_account(&rust_zero);
+    let depositor = b_mock.create_user_account(&rust_zero);
+
+    let router_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        router::contract_obj,
+        "router.wasm",
+    );
+    let pair_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(router_wrapper.address_ref()),
+        pair::contract_obj,
+        "pair.wasm",
+    );
+    let treasury_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        pol_treasury::contract_obj,
+        "pol-treasury.wasm",
+    );
+    let pair_address = pair_wrapper.address_ref().clone();
+
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.init(OptionalValue::None);
+            sc.pair_map().insert(
+                PairTokens {
+                    first_token_id: managed_token_id!(WEGLD_TOKEN_ID),
+                    second_token_id: managed_token_id!(MEX_TOKEN_ID),
+                },
+                managed_address!(&pair_address),
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &pair_wrapper, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(WEGLD_TOKEN_ID),
+                managed_token_id!(MEX_TOKEN_ID),
+                managed_address!(router_wrapper.address_ref()),
+                managed_address!(&owner),
+                0,
+                0,
+                ManagedAddress::<DebugApi>::zero(),
+                MultiValueEncoded::<DebugApi, ManagedAddress<DebugApi>>::new(),
+            );
+            sc.lp_token_identifier()
+                .set(&managed_token_id!(LP_TOKEN_ID));
+        })
+        .assert_ok();
+    b_mock.set_esdt_local_roles(
+        &pair_address,
+        LP_TOKEN_ID,
+        &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+    );
+
+    b_mock.set_esdt_balance(&owner, WEGLD_TOKEN_ID, &rust_biguint!(POOL_RESERVE));
+    b_mock.set_esdt_balance(&owner, MEX_TOKEN_ID, &rust_biguint!(POOL_RESERVE));
+    let payments = vec![
+        TxTokenTransfer {
+            token_identifier: WEGLD_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: rust_biguint!(POOL_RESERVE),
+        },
+        TxTokenTransfer {
+            token_identifier: MEX_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: rust_biguint!(POOL_RESERVE),
+        },
+    ];
+    b_mock
+        .execute_esdt_multi_transfer(&owner, &pair_wrapper, &payments, |sc| {
+            let _ = sc.add_initial_liquidity();
+            sc.state().set(State::Active);
+        })
+        .assert_ok();
+
+    // record the first price observation
+    b_mock.set_block_round(1);
+    b_mock.set_esdt_balance(&owner, WEGLD_TOKEN_ID, &rust_biguint!(1_000));
+    b_mock
+        .execute_esdt_transfer(
+            &owner,
+            &pair_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let _ = sc.swap_tokens_fixed_input(
+                    managed_token_id!(MEX_TOKEN_ID),
+                    managed_biguint!(1),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    // the pair contract also holds the safe price views
+    b_mock
+        .execute_tx(&owner, &treasury_wrapper, &rust_zero, |sc| {
+            sc.init(
+                managed_address!(router_wrapper.address_ref()),
+                managed_address!(&pair_address),
+                managed_token_id!(MEX_TOKEN_ID),
+                BURN_PERCENTAGE,
+                MAX_PRICE_DEVIATION_PERCENTAGE,
+                BUYBACK_COOLDOWN_BLOCKS,
+            );
+            sc.set_max_buyback_amount(
+                managed_token_id!(WEGLD_TOKEN_ID),
+                managed_biguint!(MAX_BUYBACK_AMOUNT),
+            );
+
+            let mut keepers = MultiValueEncoded::new();
+            keepers.push(managed_address!(&keeper));
+            sc.add_keepers(keepers);
+        })
+        .assert_ok();
+    b_mock.set_esdt_local_roles(
+        treasury_wrapper.address_ref(),
+        MEX_TOKEN_ID,
+        &[EsdtLocalRole::Burn],
+    );
+
+    b_mock.set_esdt_balance(&depositor, WEGLD_TOKEN_ID, &rust_biguint!(FEES_AMOUNT));
+    b_mock
+        .execute_esdt_transfer(
+            &depositor,
+            &treasury_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(FEES_AMOUNT),
+            |sc| {
+                sc.deposit_swap_fees();
+            },
+        )
+        .assert_ok();
+
+    b_mock.set_block_round(10);
+    b_mock.set_block_nonce(10);
+
+    b_mock
+        .execute_tx(&depositor, &treasury_wrapper, &rust_zero, |sc| {
+            sc.buyback(managed_token_id!(WEGLD_TOKEN_ID));
+        })
+        .assert_user_error("Only keepers can buy back");
+
+    b_mock
+        .execute_tx(&keeper, &treasury_wrapper, &rust_zero, |sc| {
+            sc.buyback(managed_token_id!(MEX_TOKEN_ID));
+        })
+        .assert_user_error("Buyback not enabled for token");
+
+    // burning everything doesn't require the POL pair
+    b_mock
+        .execute_tx(&keeper, &treasury_wrapper, &rust_zero, |sc| {
+            sc.buyback(managed_token_id!(WEGLD_TOKEN_ID));
+        })
+        .assert_user_error("POL pair not set");
+
+    b_mock
+        .execute_tx(&owner, &treasury_wrapper, &rust_zero, |sc| {
+            sc.set_pol_pair_address(managed_address!(&pair_address));
+        })
+        .assert_ok();
+
+    // the spot price is moved away from the safe price
+    let manipulation_amount = POOL_RESERVE / 10;
+    b_mock.set_esdt_balance(&owner, WEGLD_TOKEN_ID, &rust_biguint!(manipulation_amount));
+    b_mock
+        .execute_esdt_transfer(
+            &owner,
+            &pair_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(manipulation_amount),
+            |sc| {
+                let _ = sc.swap_tokens_fixed_input(
+                    managed_token_id!(MEX_TOKEN_ID),
+                    managed_biguint!(1),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+    b_mock
+        .execute_tx(&keeper, &treasury_wrapper, &rust_zero, |sc| {
+            sc.buyback(managed_token_id!(WEGLD_TOKEN_ID));
+        })
+        .assert_user_error("Slippage exceeded");
+
+    // and moved back
+    let mex_balance = b_mock.get_esdt_balance(&owner, MEX_TOKEN_ID, 0);
+    b_mock
+        .execute_esdt_transfer(&owner, &pair_wrapper, MEX_TOKEN_ID, 0, &mex_balance, |sc| {
+            let _ = sc.swap_tokens_fixed_input(
+                managed_token_id!(WEGLD_TOKEN_ID),
+                managed_biguint!(1),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&keeper, &treasury_wrapper, &rust_zero, |sc| {
+            sc.buyback(managed_token_id!(WEGLD_TOKEN_ID));
+
+            // 999 MEX bought for 1000 WEGLD, half of it burned
+            assert_eq!(
+                sc.accumulated_fees(&managed_token_id!(WEGLD_TOKEN_ID))
+                    .get(),
+                managed_biguint!(FEES_AMOUNT - MAX_BUYBACK_AMOUNT)
+            );
+            assert_eq!(sc.total_bought_back().get(), managed_biguint!(999));
+            assert_eq!(sc.total_burned().get(), managed_biguint!(499));
+            assert_eq!(
+                sc.total_pol_governance_tokens().get(),
+                managed_biguint!(500)
+            );
+            assert!(sc.total_pol_lp_tokens().get() > 0);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&keeper, &treasury_wrapper, &rust_zero, |sc| {
+            sc.buyback(managed_token_id!(WEGLD_TOKEN_ID));
+        })
+        .assert_user_error("Buyback cooldown not passed");
+
+    b_mock.set_block_round(20);
+    b_mock.set_block_nonce(20);
+    b_mock
+        .execute_tx(&keeper, &treasury_wrapper, &rust_zero, |sc| {
+            sc.buyback(managed_token_id!(WEGLD_TOKEN_ID));
+
+            assert_eq!(
+                sc.accumulated_fees(&managed_token_id!(WEGLD_TOKEN_ID))
+                    .get(),
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+
+    let treasury_address: Address = treasury_wrapper.address_ref().clone();
+    b_mock
+        .execute_query(&treasury_wrapper, |sc| {
+            let total_pol_lp_tokens = sc.total_pol_lp_tokens().get();
+            assert_eq!(
+                sc.total_pol_governance_tokens().get(),
+                managed_biguint!(750)
+            );
+            assert_eq!(sc.total_burned().get(), managed_biguint!(748));
+            assert!(total_pol_lp_tokens > 0);
+        })
+        .assert_ok();
+    b_mock.check_esdt_balance(&treasury_address, WEGLD_TOKEN_ID, &rust_zero);
+}
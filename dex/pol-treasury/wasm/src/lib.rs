@@ -0,0 +1,53 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           27
+// Async Callback (empty):               1
+// Total number of exported functions:  30
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    pol_treasury
+    (
+        init => init
+        upgrade => upgrade
+        depositSwapFees => deposit_swap_fees
+        setRouterAddress => set_router_address
+        setSafePriceViewAddress => set_safe_price_view_address
+        setPolPairAddress => set_pol_pair_address
+        setBurnPercentage => set_burn_percentage
+        setMaxPriceDeviationPercentage => set_max_price_deviation_percentage
+        setBuybackCooldownBlocks => set_buyback_cooldown_blocks
+        setMaxBuybackAmount => set_max_buyback_amount
+        addKeepers => add_keepers
+        removeKeepers => remove_keepers
+        getRouterAddress => router_address
+        getSafePriceViewAddress => safe_price_view_address
+        getPolPairAddress => pol_pair_address
+        getGovernanceTokenId => governance_token_id
+        getBurnPercentage => burn_percentage
+        getMaxPriceDeviationPercentage => max_price_deviation_percentage
+        getBuybackCooldownBlocks => buyback_cooldown_blocks
+        getMaxBuybackAmount => max_buyback_amount
+        getKeepers => keepers
+        buyback => buyback
+        getAccumulatedFees => accumulated_fees
+        getFeeTokens => fee_tokens
+        getLastBuybackBlock => last_buyback_block
+        getTotalBoughtBack => total_bought_back
+        getTotalBurned => total_burned
+        getTotalPolGovernanceTokens => total_pol_governance_tokens
+        getTotalPolLpTokens => total_pol_lp_tokens
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
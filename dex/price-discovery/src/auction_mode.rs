@@ -0,0 +1,270 @@
+multiversx_sc::imports!();
+
+use crate::{common_storage::AuctionMode, phase::Phase};
+
+static NOT_IDLE_ERR_MSG: &[u8] = b"May only change auction settings before start";
+
+#[multiversx_sc::module]
+pub trait AuctionModeModule:
+    crate::common_storage::CommonStorageModule + crate::events::EventsModule + crate::phase::PhaseModule
+{
+    /// Switches the contract to a descending-price Dutch auction.
+    /// The price starts at start_price and decreases linearly until end_block,
+    /// where it reaches the min launched token price, which acts as the reserve price.
+    /// The auction closes early once the deposited accepted tokens
+    /// cover all the launched tokens at the current price.
+    #[only_owner]
+    #[endpoint(setDutchAuctionMode)]
+    fn set_dutch_auction_mode(&self, start_price: BigUint) {
+        self.require_idle_phase();
+
+        let reserve_price = self.min_launched_token_price().get();
+        require!(
+            start_price > reserve_price,
+            "Start price must be higher than reserve price"
+        );
+
+        self.dutch_start_price().set(&start_price);
+        self.auction_mode().set(AuctionMode::Dutch);
+    }
+
+    /// Switches the contract to a sealed batch auction.
+    /// All accepted tokens deposited until end_block clear at a single uniform price,
+    /// which is never lower than the min launched token price (i.e. the reserve price).
+    #[only_owner]
+    #[endpoint(setBatchAuctionMode)]
+    fn set_batch_auction_mode(&self) {
+        self.require_idle_phase();
+
+        self.dutch_start_price().clear();
+        self.auction_mode().set(AuctionMode::Batch);
+    }
+
+    /// Max amount of accepted tokens a single user may deposit in auction modes.
+    /// Zero means no cap.
+    #[only_owner]
+    #[endpoint(setMaxUserDeposit)]
+    fn set_max_user_deposit(&self, max_user_deposit: BigUint) {
+        self.max_user_deposit().set(&max_user_deposit);
+    }
+
+    /// In auction modes, if the whitelist is not empty,
+    /// only whitelisted addresses may deposit accepted tokens.
+    #[only_owner]
+    #[endpoint(addToWhitelist)]
+    fn add_to_whitelist(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        let mut whitelist = self.whitelist();
+        for address in addresses {
+            let _ = whitelist.insert(address);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeFromWhitelist)]
+    fn remove_from_whitelist(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        let mut whitelist = self.whitelist();
+        for address in addresses {
+            let _ = whitelist.swap_remove(&address);
+        }
+    }
+
+    /// Launched tokens that were not bought because the clearing price
+    /// was set by the reserve price are returned to the owner.
+    #[only_owner]
+    #[endpoint(claimUnsoldLaunchedTokens)]
+    fn claim_unsold_launched_tokens(&self) -> BigUint {
+        let phase = self.get_current_phase();
+        self.require_redeem_allowed(&phase);
+        require!(
+            self.auction_mode().get() != AuctionMode::Deposit,
+            "Only available in auction modes"
+        );
+        require!(
+            !self.unsold_launched_tokens_claimed().get(),
+            "Unsold tokens already claimed"
+        );
+
+        let unsold_amount = self.get_unsold_launched_tokens();
+        self.unsold_launched_tokens_claimed().set(true);
+
+        if unsold_amount > 0 {
+            let caller = self.blockchain().get_caller();
+            let launched_token_id = self.launched_token_id().get();
+            self.send()
+                .direct_esdt(&caller, &launched_token_id, 0, &unsold_amount);
+        }
+
+        unsold_amount
+    }
+
+    fn require_idle_phase(&self) {
+        let phase = self.get_current_phase();
+        require!(phase == Phase::Idle, NOT_IDLE_ERR_MSG);
+    }
+
+    /// In auction modes, only the owner provides launched tokens.
+    /// Accepted token deposits are checked against the whitelist and the per-user cap.
+    /// Returns the part of the deposit that is taken, the rest is to be refunded.
+    fn take_auction_deposit(
+        &self,
+        caller: &ManagedAddress,
+        is_launched_token: bool,
+        amount: &BigUint,
+    ) -> BigUint {
+        if is_launched_token {
+            let owner = self.blockchain().get_owner_address();
+            require!(caller == &owner, "Only owner may deposit launched tokens");
+
+            return amount.clone();
+        }
+
+        require!(
+            self.launched_token_balance().get() > 0,
+            "No launched tokens available"
+        );
+
+        let whitelist = self.whitelist();
+        require!(
+            whitelist.is_empty() || whitelist.contains(caller),
+            "Not whitelisted"
+        );
+
+        let taken_amount = self.take_dutch_deposit(amount);
+        let max_user_deposit = self.max_user_deposit().get();
+        self.user_deposit(caller).update(|user_deposit| {
+            *user_deposit += &taken_amount;
+            require!(
+                max_user_deposit == 0 || *user_deposit <= max_user_deposit,
+                "User deposit cap exceeded"
+            );
+        });
+
+        taken_amount
+    }
+
+    /// Closes the Dutch auction once the accepted tokens cover
+    /// all launched tokens at the current descending price.
+    /// The deposit that sells out is capped at the value of the remaining launched tokens,
+    /// so every depositor buys at the sold out price.
+    fn take_dutch_deposit(&self, amount: &BigUint) -> BigUint {
+        if self.auction_mode().get() != AuctionMode::Dutch {
+            return amount.clone();
+        }
+
+        let dutch_price = self.get_current_dutch_price();
+        let price_precision = self.price_precision().get();
+        let sold_out_amount = &dutch_price * &self.launched_token_balance().get() / price_precision;
+        let accepted_token_balance = self.accepted_token_balance().get();
+
+        // the price may have dropped below the deposits since the last one
+        require!(accepted_token_balance < sold_out_amount, "Auction sold out");
+
+        let remaining_amount = sold_out_amount - accepted_token_balance;
+        if amount < &remaining_amount {
+            return amount.clone();
+        }
+
+        self.dutch_sold_out_price().set(&dutch_price);
+
+        remaining_amount
+    }
+
+    /// The price at which accepted token depositors buy launched tokens in auction modes.
+    /// If the deposit ratio is above the floor price, all launched tokens are sold at that ratio.
+    /// Otherwise, tokens are sold at the floor price, and the rest remains unsold.
+    #[view(getClearingPrice)]
+    fn get_clearing_price(&self) -> BigUint {
+        let launched_token_balance = self.launched_token_balance().get();
+        require!(launched_token_balance > 0, "No launched tokens available");
+
+        let price_precision = self.price_precision().get();
+        let ratio_price =
+            self.accepted_token_balance().get() * price_precision / launched_token_balance;
+        let floor_price = self.get_floor_price();
+
+        core::cmp::max(ratio_price, floor_price)
+    }
+
+    #[view(getCurrentDutchPrice)]
+    fn get_current_dutch_price(&self) -> BigUint {
+        let reserve_price = self.min_launched_token_price().get();
+        if self.auction_mode().get() != AuctionMode::Dutch {
+            return reserve_price;
+        }
+
+        let start_price = self.dutch_start_price().get();
+        let current_block = self.blockchain().get_block_nonce();
+        let start_block = self.start_block().get();
+        let end_block = self.end_block().get();
+        if current_block <= start_block {
+            return start_price;
+        }
+        if current_block >= end_block {
+            return reserve_price;
+        }
+
+        let price_diff = &start_price - &reserve_price;
+        let price_decrease = price_diff * (current_block - start_block) / (end_block - start_block);
+
+        start_price - price_decrease
+    }
+
+    #[view(getUnsoldLaunchedTokens)]
+    fn get_unsold_launched_tokens(&self) -> BigUint {
+        let launched_token_balance = self.launched_token_balance().get();
+        if launched_token_balance == 0 {
+            return BigUint::zero();
+        }
+
+        let floor_price = self.get_floor_price();
+        let price_precision = self.price_precision().get();
+        let accepted_value = self.accepted_token_balance().get() * price_precision;
+        if accepted_value >= &floor_price * &launched_token_balance {
+            return BigUint::zero();
+        }
+
+        let sold_amount = accepted_value / floor_price;
+
+        launched_token_balance - sold_amount
+    }
+
+    fn compute_auction_bought_launched_tokens(&self, accepted_token_amount: &BigUint) -> BigUint {
+        let launched_token_balance = self.launched_token_balance().get();
+        let accepted_token_balance = self.accepted_token_balance().get();
+        let floor_price = self.get_floor_price();
+        let price_precision = self.price_precision().get();
+        if &accepted_token_balance * price_precision >= &floor_price * &launched_token_balance {
+            return launched_token_balance * accepted_token_amount / accepted_token_balance;
+        }
+
+        accepted_token_amount * price_precision / floor_price
+    }
+
+    fn get_floor_price(&self) -> BigUint {
+        let sold_out_mapper = self.dutch_sold_out_price();
+        if !sold_out_mapper.is_empty() {
+            return sold_out_mapper.get();
+        }
+
+        self.min_launched_token_price().get()
+    }
+
+    #[view(getDutchStartPrice)]
+    #[storage_mapper("dutchStartPrice")]
+    fn dutch_start_price(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getMaxUserDeposit)]
+    #[storage_mapper("maxUserDeposit")]
+    fn max_user_deposit(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getUserDeposit)]
+    #[storage_mapper("userDeposit")]
+    fn user_deposit(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getWhitelist)]
+    #[storage_mapper("whitelist")]
+    fn whitelist(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("unsoldLaunchedTokensClaimed")]
+    fn unsold_launched_tokens_claimed(&self) -> SingleValueMapper<bool>;
+}
@@ -1,7 +1,15 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
 
 pub const MAX_PERCENTAGE: u64 = 10_000_000_000_000; // 100%
 
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Eq, Clone, Copy)]
+pub enum AuctionMode {
+    Deposit,
+    Dutch,
+    Batch,
+}
+
 #[multiversx_sc::module]
 pub trait CommonStorageModule {
     #[view(getLaunchedTokenId)]
@@ -27,4 +35,20 @@ pub trait CommonStorageModule {
     #[view(getEndBlock)]
     #[storage_mapper("endBlock")]
     fn end_block(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMinLaunchedTokenPrice)]
+    #[storage_mapper("minLaunchedTokenPrice")]
+    fn min_launched_token_price(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getPricePrecision)]
+    #[storage_mapper("pricePrecision")]
+    fn price_precision(&self) -> SingleValueMapper<u64>;
+
+    #[view(getAuctionMode)]
+    #[storage_mapper("auctionMode")]
+    fn auction_mode(&self) -> SingleValueMapper<AuctionMode>;
+
+    #[view(getDutchSoldOutPrice)]
+    #[storage_mapper("dutchSoldOutPrice")]
+    fn dutch_sold_out_price(&self) -> SingleValueMapper<BigUint>;
 }
@@ -0,0 +1,51 @@
+multiversx_sc::imports!();
+
+use energy_tiers::EnergyTier;
+
+/// At the start of the launch, only users with enough energy may deposit accepted tokens.
+/// The tiers are set through `setEnergyTiers`, and the energy factory through `setEnergyFactoryAddress`.
+#[multiversx_sc::module]
+pub trait EarlyAccessModule:
+    crate::auction_mode::AuctionModeModule
+    + crate::common_storage::CommonStorageModule
+    + crate::events::EventsModule
+    + crate::phase::PhaseModule
+    + energy_tiers::EnergyTiersModule
+    + energy_query::EnergyQueryModule
+{
+    /// For the first `duration_blocks` blocks after start_block,
+    /// only users with at least `min_tier` may deposit accepted tokens.
+    /// A duration of 0 disables the early access.
+    #[only_owner]
+    #[endpoint(setEarlyAccess)]
+    fn set_early_access(&self, duration_blocks: u64, min_tier: EnergyTier) {
+        self.require_idle_phase();
+
+        let no_limit_phase_duration = self.no_limit_phase_duration_blocks().get();
+        require!(
+            duration_blocks <= no_limit_phase_duration,
+            "Early access longer than the no limit phase"
+        );
+
+        self.early_access_duration_blocks().set(duration_blocks);
+        self.early_access_min_tier().set(min_tier);
+    }
+
+    fn require_early_access_allowed(&self, caller: &ManagedAddress) {
+        let early_access_end = self.start_block().get() + self.early_access_duration_blocks().get();
+        if self.blockchain().get_block_nonce() >= early_access_end {
+            return;
+        }
+
+        let min_tier = self.early_access_min_tier().get();
+        self.require_energy_tier(caller, min_tier);
+    }
+
+    #[view(getEarlyAccessDurationBlocks)]
+    #[storage_mapper("earlyAccessDurationBlocks")]
+    fn early_access_duration_blocks(&self) -> SingleValueMapper<u64>;
+
+    #[view(getEarlyAccessMinTier)]
+    #[storage_mapper("earlyAccessMinTier")]
+    fn early_access_min_tier(&self) -> SingleValueMapper<EnergyTier>;
+}
@@ -38,6 +38,17 @@ pub struct RedeemEvent<M: ManagedTypeApi> {
     bought_token_amount: BigUint<M>,
 }
 
+#[derive(TypeAbi, TopEncode)]
+pub struct BootstrapPoolEvent<M: ManagedTypeApi> {
+    pool_address: ManagedAddress<M>,
+    launched_token_amount: BigUint<M>,
+    accepted_token_amount: BigUint<M>,
+    price: BigUint<M>,
+    locked_lp_token_id: EgldOrEsdtTokenIdentifier<M>,
+    locked_lp_token_nonce: u64,
+    locked_lp_token_amount: BigUint<M>,
+}
+
 #[multiversx_sc::module]
 pub trait EventsModule: crate::common_storage::CommonStorageModule {
     fn emit_deposit_event(
@@ -142,6 +153,36 @@ pub trait EventsModule: crate::common_storage::CommonStorageModule {
         )
     }
 
+    fn emit_bootstrap_pool_event(
+        &self,
+        pool_address: ManagedAddress,
+        launched_token_amount: BigUint,
+        accepted_token_amount: BigUint,
+        price: BigUint,
+        locked_lp_payment: EgldOrEsdtTokenPayment,
+    ) {
+        let caller = self.blockchain().get_caller();
+        let block = self.blockchain().get_block_nonce();
+        let epoch = self.blockchain().get_block_epoch();
+        let timestamp = self.blockchain().get_block_timestamp();
+
+        self.bootstrap_pool_event(
+            &caller,
+            block,
+            epoch,
+            timestamp,
+            &BootstrapPoolEvent {
+                pool_address,
+                launched_token_amount,
+                accepted_token_amount,
+                price,
+                locked_lp_token_id: locked_lp_payment.token_identifier,
+                locked_lp_token_nonce: locked_lp_payment.token_nonce,
+                locked_lp_token_amount: locked_lp_payment.amount,
+            },
+        )
+    }
+
     #[event("depositEvent")]
     fn deposit_event(
         &self,
@@ -171,4 +212,14 @@ pub trait EventsModule: crate::common_storage::CommonStorageModule {
         #[indexed] timestamp: u64,
         redeem_event: &RedeemEvent<Self::Api>,
     );
+
+    #[event("bootstrapPoolEvent")]
+    fn bootstrap_pool_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: u64,
+        #[indexed] epoch: u64,
+        #[indexed] timestamp: u64,
+        bootstrap_pool_event: &BootstrapPoolEvent<Self::Api>,
+    );
 }
@@ -3,13 +3,16 @@
 multiversx_sc::imports!();
 
 use crate::{
-    common_storage::MAX_PERCENTAGE,
+    common_storage::{AuctionMode, MAX_PERCENTAGE},
     redeem_token::{ACCEPTED_TOKEN_REDEEM_NONCE, LAUNCHED_TOKEN_REDEEM_NONCE},
 };
 
+pub mod auction_mode;
 pub mod common_storage;
+pub mod early_access;
 pub mod events;
 pub mod phase;
+pub mod pool_bootstrap;
 pub mod redeem_token;
 
 static INVALID_PAYMENT_ERR_MSG: &[u8] = b"Invalid payment token";
@@ -18,10 +21,15 @@ const MAX_TOKEN_DECIMALS: u32 = 18;
 
 #[multiversx_sc::contract]
 pub trait PriceDiscovery:
-    common_storage::CommonStorageModule
+    auction_mode::AuctionModeModule
+    + common_storage::CommonStorageModule
+    + early_access::EarlyAccessModule
+    + energy_query::EnergyQueryModule
+    + energy_tiers::EnergyTiersModule
     + events::EventsModule
     + locking_module::locking_module::LockingModule
     + phase::PhaseModule
+    + pool_bootstrap::PoolBootstrapModule
     + redeem_token::RedeemTokenModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
@@ -117,7 +125,10 @@ pub trait PriceDiscovery:
     fn upgrade(&self) {}
 
     /// Users can deposit either launched_token or accepted_token.
-    /// They will receive an SFT that can be used to withdraw said tokens
+    /// They will receive an SFT that can be used to withdraw said tokens.
+    /// In auction modes, only the owner may deposit launched tokens,
+    /// and the SFTs can only be used to redeem.
+    /// The part of the deposit that exceeds the sold out amount of a Dutch auction is refunded.
     #[payable("*")]
     #[endpoint]
     fn deposit(&self) -> EsdtTokenPayment<Self::Api> {
@@ -135,25 +146,50 @@ pub trait PriceDiscovery:
             sc_panic!(INVALID_PAYMENT_ERR_MSG);
         };
 
-        self.increase_balance(balance_mapper, &payment_amount);
+        let caller = self.blockchain().get_caller();
+        if redeem_token_nonce == ACCEPTED_TOKEN_REDEEM_NONCE {
+            self.require_early_access_allowed(&caller);
+        }
+
+        let auction_mode = self.auction_mode().get();
+        let deposit_amount = if auction_mode != AuctionMode::Deposit {
+            self.take_auction_deposit(
+                &caller,
+                redeem_token_nonce == LAUNCHED_TOKEN_REDEEM_NONCE,
+                &payment_amount,
+            )
+        } else {
+            payment_amount.clone()
+        };
+
+        self.increase_balance(balance_mapper, &deposit_amount);
 
         let current_price = self.calculate_price();
-        let min_price = self.min_launched_token_price().get();
-        require!(
-            current_price == 0 || current_price >= min_price || payment_token == accepted_token_id,
-            BELOW_MIN_PRICE_ERR_MSG
-        );
+        if auction_mode == AuctionMode::Deposit {
+            let min_price = self.min_launched_token_price().get();
+            require!(
+                current_price == 0
+                    || current_price >= min_price
+                    || payment_token == accepted_token_id,
+                BELOW_MIN_PRICE_ERR_MSG
+            );
+        }
 
-        let caller = self.blockchain().get_caller();
         let payment_result =
-            self.mint_and_send_redeem_token(&caller, redeem_token_nonce, payment_amount.clone());
+            self.mint_and_send_redeem_token(&caller, redeem_token_nonce, deposit_amount.clone());
+
+        let refund_amount = &payment_amount - &deposit_amount;
+        if refund_amount > 0 {
+            self.send()
+                .direct(&caller, &payment_token, 0, &refund_amount);
+        }
 
         self.emit_deposit_event(
             payment_token,
-            payment_amount.clone(),
+            deposit_amount.clone(),
             payment_result.token_identifier.clone(),
             redeem_token_nonce,
-            payment_amount,
+            deposit_amount,
             current_price,
             phase,
         );
@@ -223,6 +259,8 @@ pub trait PriceDiscovery:
     /// Users that deposited launched tokens will receive Locked accepted tokens.
     /// The users can unlock said tokens at the configured unlock_epoch,
     /// through the SC at locking_sc_address
+    ///
+    /// In auction modes, accepted token depositors buy at the clearing price instead.
     #[payable("*")]
     #[endpoint]
     fn redeem(&self) -> EgldOrEsdtTokenPayment<Self::Api> {
@@ -257,6 +295,91 @@ pub trait PriceDiscovery:
         bought_tokens
     }
 
+    /// After all phases have ended, the owner can seed the pool with the raised accepted tokens,
+    /// at the discovered price (the clearing price in auction modes).
+    ///
+    /// Expected payments: launched token redeem SFTs, and the launched tokens to be added to the pool.
+    /// The accepted tokens the SFTs can be redeemed for are added to the pool,
+    /// along with the matching amount of launched tokens. The rest of the launched tokens are returned.
+    ///
+    /// The received LP tokens are locked until lp_unlock_epoch and used to enable swaps through the router.
+    /// Returns the locked LP tokens and the launched tokens refund.
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(bootstrapPool)]
+    fn bootstrap_pool(&self) -> MultiValue2<EgldOrEsdtTokenPayment, EsdtTokenPayment> {
+        let phase = self.get_current_phase();
+        self.require_redeem_allowed(&phase);
+        require!(!self.pool_address().is_empty(), "Pool not created");
+        require!(!self.pool_bootstrapped().get(), "Pool already bootstrapped");
+
+        let [redeem_payment, launched_payment] = self.call_value().multi_esdt();
+        let redeem_token_id = self.redeem_token().get_token_id();
+        require!(
+            redeem_payment.token_identifier == redeem_token_id
+                && redeem_payment.token_nonce == LAUNCHED_TOKEN_REDEEM_NONCE,
+            INVALID_PAYMENT_ERR_MSG
+        );
+        require!(
+            launched_payment.token_identifier == self.launched_token_id().get(),
+            INVALID_PAYMENT_ERR_MSG
+        );
+
+        let accepted_tokens =
+            self.compute_bought_tokens(redeem_payment.token_nonce, &redeem_payment.amount);
+        require!(accepted_tokens.amount > 0, "No accepted tokens raised");
+
+        let price = if self.auction_mode().get() == AuctionMode::Deposit {
+            self.calculate_price()
+        } else {
+            self.get_clearing_price()
+        };
+        require!(price > 0, "Invalid price");
+
+        let price_precision = self.price_precision().get();
+        let launched_token_amount = &accepted_tokens.amount * price_precision / &price;
+        require!(
+            launched_token_amount > 0 && launched_token_amount <= launched_payment.amount,
+            "Not enough launched tokens"
+        );
+
+        self.burn_redeem_token_without_supply_decrease(
+            redeem_payment.token_nonce,
+            &redeem_payment.amount,
+        );
+        self.pool_bootstrapped().set(true);
+
+        let locked_lp_payment = self.add_initial_liquidity_and_lock(
+            launched_token_amount.clone(),
+            accepted_tokens.amount.clone(),
+        );
+
+        let caller = self.blockchain().get_caller();
+        let launched_refund = EsdtTokenPayment::new(
+            launched_payment.token_identifier,
+            0,
+            &launched_payment.amount - &launched_token_amount,
+        );
+        self.send().direct(
+            &caller,
+            &locked_lp_payment.token_identifier,
+            locked_lp_payment.token_nonce,
+            &locked_lp_payment.amount,
+        );
+        self.send()
+            .direct_non_zero_esdt_payment(&caller, &launched_refund);
+
+        self.emit_bootstrap_pool_event(
+            self.pool_address().get(),
+            launched_token_amount,
+            accepted_tokens.amount,
+            price,
+            locked_lp_payment.clone(),
+        );
+
+        (locked_lp_payment, launched_refund).into()
+    }
+
     // private
 
     fn compute_bought_tokens(
@@ -264,6 +387,15 @@ pub trait PriceDiscovery:
         redeem_token_nonce: u64,
         redeem_token_amount: &BigUint,
     ) -> EgldOrEsdtTokenPayment<Self::Api> {
+        if redeem_token_nonce == ACCEPTED_TOKEN_REDEEM_NONCE
+            && self.auction_mode().get() != AuctionMode::Deposit
+        {
+            let launched_token_id = EgldOrEsdtTokenIdentifier::esdt(self.launched_token_id().get());
+            let reward_amount = self.compute_auction_bought_launched_tokens(redeem_token_amount);
+
+            return EgldOrEsdtTokenPayment::new(launched_token_id, 0, reward_amount);
+        }
+
         let redeem_token_supply = self
             .redeem_token_total_circulating_supply(redeem_token_nonce)
             .get();
@@ -303,12 +435,4 @@ pub trait PriceDiscovery:
     fn decrease_balance(&self, mapper: SingleValueMapper<BigUint>, amount: &BigUint) {
         mapper.update(|b| *b -= amount);
     }
-
-    #[view(getMinLaunchedTokenPrice)]
-    #[storage_mapper("minLaunchedTokenPrice")]
-    fn min_launched_token_price(&self) -> SingleValueMapper<BigUint>;
-
-    #[view(getPricePrecision)]
-    #[storage_mapper("pricePrecision")]
-    fn price_precision(&self) -> SingleValueMapper<u64>;
 }
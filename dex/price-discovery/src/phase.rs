@@ -1,6 +1,8 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use crate::common_storage::AuctionMode;
+
 #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, PartialEq)]
 pub enum Phase<M: ManagedTypeApi> {
     Idle,
@@ -32,6 +34,11 @@ pub trait PhaseModule:
             return Phase::Idle;
         }
 
+        // a sold out Dutch auction goes straight to redeem
+        if !self.dutch_sold_out_price().is_empty() {
+            return Phase::Redeem;
+        }
+
         let no_limit_phase_duration_blocks = self.no_limit_phase_duration_blocks().get();
         let no_limit_phase_end = start_block + no_limit_phase_duration_blocks;
         if current_block < no_limit_phase_end {
@@ -76,18 +83,28 @@ pub trait PhaseModule:
 
     fn require_deposit_allowed(&self, phase: &Phase<Self::Api>) {
         match phase {
-            Phase::Idle
-            | Phase::OnlyWithdrawFixedPenalty {
-                penalty_percentage: _,
-            }
-            | Phase::Redeem => {
+            Phase::Idle | Phase::Redeem => {
                 sc_panic!("Deposit not allowed in this phase")
             }
+            Phase::OnlyWithdrawFixedPenalty {
+                penalty_percentage: _,
+            } => {
+                // auctions accept deposits until end_block
+                require!(
+                    self.auction_mode().get() != AuctionMode::Deposit,
+                    "Deposit not allowed in this phase"
+                );
+            }
             _ => {}
         };
     }
 
     fn require_withdraw_allowed(&self, phase: &Phase<Self::Api>) {
+        require!(
+            self.auction_mode().get() == AuctionMode::Deposit,
+            "Withdraw not allowed in auction mode"
+        );
+
         match phase {
             Phase::Idle | Phase::Redeem => {
                 sc_panic!("Withdraw not allowed in this phase")
@@ -0,0 +1,160 @@
+multiversx_sc::imports!();
+
+use pair::{config::ProxyTrait as _, pair_actions::initial_liq::ProxyTrait as _};
+use router::{enable_swap_by_user::ProxyTrait as _, factory::ProxyTrait as _, ProxyTrait as _};
+use simple_lock::ProxyTrait as _;
+
+#[multiversx_sc::module]
+pub trait PoolBootstrapModule:
+    crate::common_storage::CommonStorageModule + locking_module::locking_module::LockingModule
+{
+    #[only_owner]
+    #[endpoint(setRouterAddress)]
+    fn set_router_address(&self, router_address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&router_address),
+            "Invalid SC Address"
+        );
+
+        self.router_address().set(&router_address);
+    }
+
+    /// The LP tokens received when bootstrapping the pool are locked until this epoch
+    #[only_owner]
+    #[endpoint(setLpUnlockEpoch)]
+    fn set_lp_unlock_epoch(&self, lp_unlock_epoch: u64) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            lp_unlock_epoch > current_epoch,
+            "Unlock epoch cannot be in the past"
+        );
+
+        self.lp_unlock_epoch().set(lp_unlock_epoch);
+    }
+
+    /// Creates the launched token/accepted token pair through the router,
+    /// with this contract as the initial liquidity adder.
+    /// If the pair already exists, it is reused instead.
+    /// The pair's LP token must be issued through the router before calling bootstrapPool.
+    #[only_owner]
+    #[endpoint(createPool)]
+    fn create_pool(&self) -> ManagedAddress {
+        require!(!self.router_address().is_empty(), "Router address not set");
+        require!(self.pool_address().is_empty(), "Pool already set");
+
+        let accepted_token_id = self.accepted_token_id().get();
+        require!(
+            accepted_token_id.is_esdt(),
+            "Accepted token must be an ESDT"
+        );
+
+        let launched_token_id = self.launched_token_id().get();
+        let accepted_token_id = accepted_token_id.unwrap_esdt();
+        let router_address = self.router_address().get();
+        let mut pool_address: ManagedAddress = self
+            .router_proxy(router_address.clone())
+            .get_pair(launched_token_id.clone(), accepted_token_id.clone())
+            .execute_on_dest_context();
+        if pool_address.is_zero() {
+            let own_sc_address = self.blockchain().get_sc_address();
+            pool_address = self
+                .router_proxy(router_address)
+                .create_pair_endpoint(
+                    launched_token_id,
+                    accepted_token_id,
+                    own_sc_address,
+                    OptionalValue::<MultiValue2<u64, u64>>::None,
+                    MultiValueEncoded::<Self::Api, ManagedAddress>::new(),
+                )
+                .execute_on_dest_context();
+        }
+
+        self.pool_address().set(&pool_address);
+
+        pool_address
+    }
+
+    /// Adds the initial liquidity to the pool, locks the received LP tokens
+    /// through the locking SC, and uses them to enable swaps through the router.
+    /// Returns the locked LP tokens.
+    fn add_initial_liquidity_and_lock(
+        &self,
+        launched_token_amount: BigUint,
+        accepted_token_amount: BigUint,
+    ) -> EgldOrEsdtTokenPayment {
+        require!(!self.router_address().is_empty(), "Router address not set");
+        require!(
+            !self.lp_unlock_epoch().is_empty(),
+            "LP unlock epoch not set"
+        );
+
+        let pool_address = self.pool_address().get();
+        let launched_token_id = self.launched_token_id().get();
+        let accepted_token_id = self.accepted_token_id().get().unwrap_esdt();
+        let first_token_id: TokenIdentifier = self
+            .pool_proxy(pool_address.clone())
+            .first_token_id()
+            .execute_on_dest_context();
+
+        let launched_payment = EsdtTokenPayment::new(launched_token_id, 0, launched_token_amount);
+        let accepted_payment = EsdtTokenPayment::new(accepted_token_id, 0, accepted_token_amount);
+        let mut payments = ManagedVec::new();
+        if first_token_id == launched_payment.token_identifier {
+            payments.push(launched_payment);
+            payments.push(accepted_payment);
+        } else {
+            payments.push(accepted_payment);
+            payments.push(launched_payment);
+        }
+
+        let add_liq_result: MultiValue3<EsdtTokenPayment, EsdtTokenPayment, EsdtTokenPayment> =
+            self.pool_proxy(pool_address.clone())
+                .add_initial_liquidity()
+                .with_multi_token_transfer(payments)
+                .execute_on_dest_context();
+        let (lp_payment, _, _) = add_liq_result.into_tuple();
+
+        let lp_unlock_epoch = self.lp_unlock_epoch().get();
+        let locked_lp_payment: EgldOrEsdtTokenPayment = self
+            .get_locking_sc_proxy_instance()
+            .lock_tokens_endpoint(lp_unlock_epoch, OptionalValue::<ManagedAddress>::None)
+            .with_esdt_transfer(lp_payment)
+            .execute_on_dest_context();
+
+        // the router sends the locked LP tokens back after enabling swaps
+        let router_address = self.router_address().get();
+        let _: IgnoreValue = self
+            .router_proxy(router_address)
+            .set_swap_enabled_by_user(pool_address)
+            .with_esdt_transfer(EsdtTokenPayment::new(
+                locked_lp_payment.token_identifier.clone().unwrap_esdt(),
+                locked_lp_payment.token_nonce,
+                locked_lp_payment.amount.clone(),
+            ))
+            .execute_on_dest_context();
+
+        locked_lp_payment
+    }
+
+    #[proxy]
+    fn router_proxy(&self, sc_address: ManagedAddress) -> router::Proxy<Self::Api>;
+
+    #[proxy]
+    fn pool_proxy(&self, sc_address: ManagedAddress) -> pair::Proxy<Self::Api>;
+
+    #[view(getRouterAddress)]
+    #[storage_mapper("routerAddress")]
+    fn router_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getPoolAddress)]
+    #[storage_mapper("poolAddress")]
+    fn pool_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getLpUnlockEpoch)]
+    #[storage_mapper("lpUnlockEpoch")]
+    fn lp_unlock_epoch(&self) -> SingleValueMapper<u64>;
+
+    #[view(isPoolBootstrapped)]
+    #[storage_mapper("poolBootstrapped")]
+    fn pool_bootstrapped(&self) -> SingleValueMapper<bool>;
+}
@@ -0,0 +1,396 @@
+#![allow(deprecated)]
+
+use multiversx_sc::{codec::Empty, types::MultiValueEncoded};
+use multiversx_sc_scenario::{managed_address, managed_biguint, managed_token_id_wrapped};
+use multiversx_sc_scenario::{rust_biguint, DebugApi};
+use price_discovery::auction_mode::AuctionModeModule;
+use price_discovery::common_storage::*;
+use price_discovery::phase::{Phase, PhaseModule};
+use price_discovery::redeem_token::*;
+use price_discovery::PriceDiscovery;
+
+mod tests_common;
+use simple_lock::locked_token::LockedTokenAttributes;
+use tests_common::*;
+
+const MIN_PRICE_PRECISION: u64 = 1_000_000_000_000_000_000;
+
+#[test]
+fn batch_auction_clears_at_reserve_price() {
+    let mut pd_setup = init(price_discovery::contract_obj);
+
+    let owner_address = pd_setup.owner_address.clone();
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                // each launched token = 0.5 accepted token
+                sc.min_launched_token_price()
+                    .set(&managed_biguint!(MIN_PRICE_PRECISION / 2));
+                sc.set_batch_auction_mode();
+            },
+        )
+        .assert_ok();
+
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK);
+    call_deposit_initial_tokens(&mut pd_setup, &rust_biguint!(5_000_000_000));
+
+    let first_user_address = pd_setup.first_user_address.clone();
+    let second_user_address = pd_setup.second_user_address.clone();
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(1_000_000_000),
+    )
+    .assert_ok();
+
+    // deposits are still allowed in the last phase
+    pd_setup.blockchain_wrapper.set_block_nonce(END_BLOCK - 1);
+    call_deposit(
+        &mut pd_setup,
+        &second_user_address,
+        &rust_biguint!(500_000_000),
+    )
+    .assert_ok();
+
+    // bids are binding
+    call_withdraw(&mut pd_setup, &first_user_address, &rust_biguint!(1))
+        .assert_user_error("Withdraw not allowed in auction mode");
+
+    pd_setup.blockchain_wrapper.set_block_nonce(END_BLOCK);
+
+    pd_setup
+        .blockchain_wrapper
+        .execute_query(&pd_setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.get_clearing_price(),
+                managed_biguint!(MIN_PRICE_PRECISION / 2)
+            );
+            assert_eq!(
+                sc.get_unsold_launched_tokens(),
+                managed_biguint!(2_000_000_000)
+            );
+        })
+        .assert_ok();
+
+    call_redeem(
+        &mut pd_setup,
+        &first_user_address,
+        ACCEPTED_TOKEN_REDEEM_NONCE,
+        &rust_biguint!(1_000_000_000),
+    )
+    .assert_ok();
+    call_redeem(
+        &mut pd_setup,
+        &second_user_address,
+        ACCEPTED_TOKEN_REDEEM_NONCE,
+        &rust_biguint!(500_000_000),
+    )
+    .assert_ok();
+
+    DebugApi::dummy();
+    let launched_locked_attributes = LockedTokenAttributes::<DebugApi> {
+        original_token_id: managed_token_id_wrapped!(LAUNCHED_TOKEN_ID),
+        original_token_nonce: 0,
+        unlock_epoch: UNLOCK_EPOCH,
+    };
+    pd_setup.blockchain_wrapper.check_nft_balance(
+        &first_user_address,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(2_000_000_000),
+        Some(&launched_locked_attributes),
+    );
+    pd_setup.blockchain_wrapper.check_nft_balance(
+        &second_user_address,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(1_000_000_000),
+        Some(&launched_locked_attributes),
+    );
+    pd_setup.blockchain_wrapper.check_esdt_balance(
+        &pd_setup.locking_sc_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(3_000_000_000),
+    );
+
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let unsold_amount = sc.claim_unsold_launched_tokens();
+                assert_eq!(unsold_amount, managed_biguint!(2_000_000_000));
+            },
+        )
+        .assert_ok();
+
+    pd_setup.blockchain_wrapper.check_esdt_balance(
+        &owner_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(2_000_000_000),
+    );
+
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.claim_unsold_launched_tokens();
+            },
+        )
+        .assert_user_error("Unsold tokens already claimed");
+}
+
+#[test]
+fn batch_auction_whitelist_and_user_cap() {
+    let mut pd_setup = init(price_discovery::contract_obj);
+
+    let owner_address = pd_setup.owner_address.clone();
+    let first_user_address = pd_setup.first_user_address.clone();
+    let second_user_address = pd_setup.second_user_address.clone();
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_batch_auction_mode();
+                sc.set_max_user_deposit(managed_biguint!(600_000_000));
+
+                let mut addresses = MultiValueEncoded::new();
+                addresses.push(managed_address!(&first_user_address));
+                sc.add_to_whitelist(addresses);
+            },
+        )
+        .assert_ok();
+
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK);
+
+    // no launched tokens yet
+    call_deposit(&mut pd_setup, &first_user_address, &rust_biguint!(1))
+        .assert_user_error("No launched tokens available");
+
+    // only owner provides launched tokens
+    pd_setup.blockchain_wrapper.set_esdt_balance(
+        &first_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(1_000),
+    );
+    pd_setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &first_user_address,
+            &pd_setup.pd_wrapper,
+            LAUNCHED_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.deposit();
+            },
+        )
+        .assert_user_error("Only owner may deposit launched tokens");
+
+    call_deposit_initial_tokens(&mut pd_setup, &rust_biguint!(5_000_000_000));
+
+    call_deposit(
+        &mut pd_setup,
+        &second_user_address,
+        &rust_biguint!(100_000_000),
+    )
+    .assert_user_error("Not whitelisted");
+
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(500_000_000),
+    )
+    .assert_ok();
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(200_000_000),
+    )
+    .assert_user_error("User deposit cap exceeded");
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(100_000_000),
+    )
+    .assert_ok();
+
+    pd_setup
+        .blockchain_wrapper
+        .execute_query(&pd_setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.user_deposit(&managed_address!(&first_user_address))
+                    .get(),
+                managed_biguint!(600_000_000)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn dutch_auction_sold_out_early() {
+    let mut pd_setup = init(price_discovery::contract_obj);
+
+    let owner_address = pd_setup.owner_address.clone();
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                // start at 1 launched token = 1 accepted token, reserve price is 0
+                sc.set_dutch_auction_mode(managed_biguint!(MIN_PRICE_PRECISION));
+            },
+        )
+        .assert_ok();
+
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK);
+    call_deposit_initial_tokens(&mut pd_setup, &rust_biguint!(1_000_000_000));
+
+    let first_user_address = pd_setup.first_user_address.clone();
+    let second_user_address = pd_setup.second_user_address.clone();
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(500_000_000),
+    )
+    .assert_ok();
+
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK + 7);
+
+    let expected_dutch_price =
+        MIN_PRICE_PRECISION - MIN_PRICE_PRECISION * 7 / (END_BLOCK - START_BLOCK);
+    pd_setup
+        .blockchain_wrapper
+        .execute_query(&pd_setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.get_current_dutch_price(),
+                managed_biguint!(expected_dutch_price)
+            );
+        })
+        .assert_ok();
+
+    call_deposit(
+        &mut pd_setup,
+        &second_user_address,
+        &rust_biguint!(100_000_000),
+    )
+    .assert_ok();
+
+    // the sell out deposit is capped at the value of the remaining launched tokens
+    let sold_out_amount =
+        1_000_000_000u128 * expected_dutch_price as u128 / MIN_PRICE_PRECISION as u128;
+    let second_user_deposit = sold_out_amount as u64 - 500_000_000;
+    pd_setup.blockchain_wrapper.check_esdt_balance(
+        &second_user_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(1_000_000_000 - second_user_deposit),
+    );
+    pd_setup.blockchain_wrapper.check_nft_balance::<Empty>(
+        &second_user_address,
+        REDEEM_TOKEN_ID,
+        ACCEPTED_TOKEN_REDEEM_NONCE,
+        &rust_biguint!(second_user_deposit),
+        None,
+    );
+
+    // sold out, the auction moves to redeem before end block
+    pd_setup
+        .blockchain_wrapper
+        .execute_query(&pd_setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.dutch_sold_out_price().get(),
+                managed_biguint!(expected_dutch_price)
+            );
+            assert_eq!(
+                sc.get_clearing_price(),
+                managed_biguint!(expected_dutch_price)
+            );
+            assert!(sc.get_current_phase() == Phase::Redeem);
+        })
+        .assert_ok();
+
+    call_deposit(&mut pd_setup, &first_user_address, &rust_biguint!(1))
+        .assert_user_error("Deposit not allowed in this phase");
+
+    call_redeem(
+        &mut pd_setup,
+        &first_user_address,
+        ACCEPTED_TOKEN_REDEEM_NONCE,
+        &rust_biguint!(500_000_000),
+    )
+    .assert_ok();
+    call_redeem(
+        &mut pd_setup,
+        &second_user_address,
+        ACCEPTED_TOKEN_REDEEM_NONCE,
+        &rust_biguint!(second_user_deposit),
+    )
+    .assert_ok();
+
+    // every buyer pays the sold out price
+    DebugApi::dummy();
+    let launched_locked_attributes = LockedTokenAttributes::<DebugApi> {
+        original_token_id: managed_token_id_wrapped!(LAUNCHED_TOKEN_ID),
+        original_token_nonce: 0,
+        unlock_epoch: UNLOCK_EPOCH,
+    };
+    let first_user_bought =
+        500_000_000u128 * MIN_PRICE_PRECISION as u128 / expected_dutch_price as u128;
+    let second_user_bought =
+        second_user_deposit as u128 * MIN_PRICE_PRECISION as u128 / expected_dutch_price as u128;
+    pd_setup.blockchain_wrapper.check_nft_balance(
+        &first_user_address,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(first_user_bought as u64),
+        Some(&launched_locked_attributes),
+    );
+    pd_setup.blockchain_wrapper.check_nft_balance(
+        &second_user_address,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(second_user_bought as u64),
+        Some(&launched_locked_attributes),
+    );
+
+    // only the rounding dust is left unsold
+    pd_setup
+        .blockchain_wrapper
+        .execute_query(&pd_setup.pd_wrapper, |sc| {
+            assert!(sc.get_unsold_launched_tokens() <= managed_biguint!(1));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn auction_settings_only_before_start() {
+    let mut pd_setup = init(price_discovery::contract_obj);
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK);
+
+    let owner_address = pd_setup.owner_address.clone();
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_batch_auction_mode();
+            },
+        )
+        .assert_user_error("May only change auction settings before start");
+}
@@ -0,0 +1,239 @@
+#![allow(deprecated)]
+
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{EsdtLocalRole, ManagedAddress, ManagedVec, MultiValueEncoded};
+use multiversx_sc_scenario::whitebox_legacy::{ContractObjWrapper, TxTokenTransfer};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, managed_token_id_wrapped, rust_biguint,
+    DebugApi,
+};
+use pair::{config::ConfigModule as PairConfigModule, Pair};
+use pausable::{PausableModule, State};
+use price_discovery::pool_bootstrap::PoolBootstrapModule;
+use price_discovery::redeem_token::*;
+use price_discovery::PriceDiscovery;
+use router::{
+    config::ConfigModule, enable_swap_by_user::EnableSwapByUserModule, factory::PairTokens, Router,
+};
+
+mod tests_common;
+use simple_lock::locked_token::LockedTokenAttributes;
+use tests_common::*;
+
+const LP_TOKEN_ID: &[u8] = b"LPTOKEN-123456";
+const LP_UNLOCK_EPOCH: u64 = 200;
+const MIN_LOCKED_LP_TOKEN_VALUE: u64 = 1_000;
+const MIN_LOCKED_PERIOD_EPOCHS: u64 = 100;
+
+fn setup_router_and_pair<PriceDiscObjBuilder, RouterObjBuilder, PairObjBuilder>(
+    pd_setup: &mut PriceDiscSetup<PriceDiscObjBuilder>,
+    router_builder: RouterObjBuilder,
+    pair_builder: PairObjBuilder,
+) -> (
+    ContractObjWrapper<router::ContractObj<DebugApi>, RouterObjBuilder>,
+    ContractObjWrapper<pair::ContractObj<DebugApi>, PairObjBuilder>,
+)
+where
+    PriceDiscObjBuilder: 'static + Copy + Fn() -> price_discovery::ContractObj<DebugApi>,
+    RouterObjBuilder: 'static + Copy + Fn() -> router::ContractObj<DebugApi>,
+    PairObjBuilder: 'static + Copy + Fn() -> pair::ContractObj<DebugApi>,
+{
+    let rust_zero = rust_biguint!(0u64);
+    let owner_address = pd_setup.owner_address.clone();
+    let pd_address = pd_setup.pd_wrapper.address_ref().clone();
+    let b_mock = &mut pd_setup.blockchain_wrapper;
+
+    let router_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner_address),
+        router_builder,
+        "router.wasm",
+    );
+    let pair_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(router_wrapper.address_ref()),
+        pair_builder,
+        "pair.wasm",
+    );
+
+    b_mock
+        .execute_tx(&owner_address, &router_wrapper, &rust_zero, |sc| {
+            sc.init(OptionalValue::None);
+
+            sc.pair_map().insert(
+                PairTokens {
+                    first_token_id: managed_token_id!(LAUNCHED_TOKEN_ID),
+                    second_token_id: managed_token_id!(ACCEPTED_TOKEN_ID),
+                },
+                managed_address!(pair_wrapper.address_ref()),
+            );
+
+            sc.add_common_tokens_for_user_pairs(MultiValueEncoded::from(ManagedVec::from(vec![
+                managed_token_id!(ACCEPTED_TOKEN_ID),
+            ])));
+            sc.config_enable_by_user_parameters(
+                managed_token_id!(ACCEPTED_TOKEN_ID),
+                managed_token_id!(LOCKED_TOKEN_ID),
+                managed_biguint!(MIN_LOCKED_LP_TOKEN_VALUE),
+                MIN_LOCKED_PERIOD_EPOCHS,
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner_address, &pair_wrapper, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(LAUNCHED_TOKEN_ID),
+                managed_token_id!(ACCEPTED_TOKEN_ID),
+                managed_address!(router_wrapper.address_ref()),
+                managed_address!(&owner_address),
+                0,
+                0,
+                managed_address!(&pd_address),
+                MultiValueEncoded::<DebugApi, ManagedAddress<DebugApi>>::new(),
+            );
+
+            sc.lp_token_identifier()
+                .set(&managed_token_id!(LP_TOKEN_ID));
+        })
+        .assert_ok();
+
+    b_mock.set_esdt_local_roles(
+        pair_wrapper.address_ref(),
+        LP_TOKEN_ID,
+        &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+    );
+
+    (router_wrapper, pair_wrapper)
+}
+
+#[test]
+fn bootstrap_pool_ok() {
+    let mut pd_setup = init(price_discovery::contract_obj);
+    let (router_wrapper, pair_wrapper) =
+        setup_router_and_pair(&mut pd_setup, router::contract_obj, pair::contract_obj);
+    let router_address = router_wrapper.address_ref().clone();
+    let pair_address = pair_wrapper.address_ref().clone();
+
+    let owner_address = pd_setup.owner_address.clone();
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_router_address(managed_address!(&router_address));
+                sc.set_lp_unlock_epoch(LP_UNLOCK_EPOCH);
+
+                let pool_address = sc.create_pool();
+                assert_eq!(pool_address, managed_address!(&pair_address));
+            },
+        )
+        .assert_ok();
+
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK);
+    call_deposit_initial_tokens(&mut pd_setup, &rust_biguint!(5_000_000_000));
+
+    let first_user_address = pd_setup.first_user_address.clone();
+    let second_user_address = pd_setup.second_user_address.clone();
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(1_000_000_000),
+    )
+    .assert_ok();
+    call_deposit(
+        &mut pd_setup,
+        &second_user_address,
+        &rust_biguint!(500_000_000),
+    )
+    .assert_ok();
+
+    // owner sends more launched tokens than needed
+    pd_setup.blockchain_wrapper.set_esdt_balance(
+        &owner_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(6_000_000_000),
+    );
+    let payments = vec![
+        TxTokenTransfer {
+            token_identifier: REDEEM_TOKEN_ID.to_vec(),
+            nonce: LAUNCHED_TOKEN_REDEEM_NONCE,
+            value: rust_biguint!(5_000_000_000),
+        },
+        TxTokenTransfer {
+            token_identifier: LAUNCHED_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: rust_biguint!(6_000_000_000),
+        },
+    ];
+
+    pd_setup.blockchain_wrapper.set_block_nonce(END_BLOCK - 1);
+    pd_setup
+        .blockchain_wrapper
+        .execute_esdt_multi_transfer(&owner_address, &pd_setup.pd_wrapper, &payments, |sc| {
+            let _ = sc.bootstrap_pool();
+        })
+        .assert_user_error("Redeem not allowed in this phase");
+
+    pd_setup.blockchain_wrapper.set_block_nonce(END_BLOCK);
+    pd_setup
+        .blockchain_wrapper
+        .execute_esdt_multi_transfer(&owner_address, &pd_setup.pd_wrapper, &payments, |sc| {
+            let (locked_lp_payment, launched_refund) = sc.bootstrap_pool().into_tuple();
+            assert_eq!(
+                locked_lp_payment.token_identifier,
+                managed_token_id_wrapped!(LOCKED_TOKEN_ID)
+            );
+            assert_eq!(locked_lp_payment.amount, managed_biguint!(1_499_999_000));
+            assert_eq!(launched_refund.amount, managed_biguint!(1_000_000_000));
+        })
+        .assert_ok();
+
+    // pool seeded at the discovered price, with swaps enabled
+    let b_mock = &mut pd_setup.blockchain_wrapper;
+    b_mock.check_esdt_balance(
+        &pair_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(5_000_000_000),
+    );
+    b_mock.check_esdt_balance(
+        &pair_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(1_500_000_000),
+    );
+    b_mock.check_esdt_balance(
+        &owner_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(1_000_000_000),
+    );
+
+    b_mock
+        .execute_query(&pair_wrapper, |sc| {
+            assert_eq!(sc.state().get(), State::Active);
+        })
+        .assert_ok();
+
+    DebugApi::dummy();
+    b_mock.check_nft_balance(
+        &owner_address,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(1_499_999_000),
+        Some(&LockedTokenAttributes::<DebugApi> {
+            original_token_id: managed_token_id_wrapped!(LP_TOKEN_ID),
+            original_token_nonce: 0,
+            unlock_epoch: LP_UNLOCK_EPOCH,
+        }),
+    );
+
+    // users still redeem as usual
+    call_redeem(
+        &mut pd_setup,
+        &first_user_address,
+        ACCEPTED_TOKEN_REDEEM_NONCE,
+        &rust_biguint!(1_000_000_000),
+    )
+    .assert_ok();
+}
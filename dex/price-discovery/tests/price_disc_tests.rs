@@ -1,9 +1,14 @@
 #![allow(deprecated)]
 
+use energy_factory_mock::EnergyFactoryMock;
+use energy_query::EnergyQueryModule;
+use energy_tiers::EnergyTiersModule;
 use multiversx_sc::codec::Empty;
-use multiversx_sc_scenario::{managed_biguint, managed_token_id_wrapped};
+use multiversx_sc::types::MultiValueEncoded;
+use multiversx_sc_scenario::{managed_address, managed_biguint, managed_token_id_wrapped};
 use multiversx_sc_scenario::{rust_biguint, DebugApi};
 use price_discovery::common_storage::*;
+use price_discovery::early_access::EarlyAccessModule;
 use price_discovery::redeem_token::*;
 use price_discovery::PriceDiscovery;
 
@@ -429,3 +434,79 @@ fn redeem_too_early() {
     )
     .assert_user_error("Redeem not allowed in this phase");
 }
+
+#[test]
+fn early_access_by_energy_tier_test() {
+    let mut pd_setup = init(price_discovery::contract_obj);
+    let owner_address = pd_setup.owner_address.clone();
+    let first_user_address = pd_setup.first_user_address.clone();
+    let second_user_address = pd_setup.second_user_address.clone();
+
+    let energy_factory_wrapper = pd_setup.blockchain_wrapper.create_sc_account(
+        &rust_biguint!(0),
+        Some(&owner_address),
+        energy_factory_mock::contract_obj,
+        "energy factory mock",
+    );
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &energy_factory_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_user_energy(
+                    managed_address!(&first_user_address),
+                    managed_biguint!(1_000),
+                    managed_biguint!(0),
+                );
+            },
+        )
+        .assert_ok();
+
+    // only tier 1 users may deposit in the first 3 blocks
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_energy_factory_address(managed_address!(
+                    energy_factory_wrapper.address_ref()
+                ));
+
+                let mut thresholds = MultiValueEncoded::new();
+                thresholds.push(managed_biguint!(100));
+                thresholds.push(managed_biguint!(10_000));
+                sc.set_energy_tiers(thresholds);
+
+                sc.set_early_access(3, 1);
+            },
+        )
+        .assert_ok();
+
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK);
+    call_deposit_initial_tokens(&mut pd_setup, &rust_biguint!(5_000_000_000));
+
+    call_deposit(&mut pd_setup, &first_user_address, &rust_biguint!(1_000)).assert_ok();
+    call_deposit(&mut pd_setup, &second_user_address, &rust_biguint!(1_000))
+        .assert_user_error("Energy tier too low");
+
+    pd_setup
+        .blockchain_wrapper
+        .execute_query(&pd_setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.get_user_energy_tier_view(managed_address!(&first_user_address)),
+                1
+            );
+            assert_eq!(
+                sc.get_user_energy_tier_view(managed_address!(&second_user_address)),
+                0
+            );
+        })
+        .assert_ok();
+
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK + 3);
+    call_deposit(&mut pd_setup, &second_user_address, &rust_biguint!(1_000)).assert_ok();
+}
@@ -1,3 +1,4 @@
+#![allow(dead_code)]
 #![allow(deprecated)]
 
 use multiversx_sc::codec::Empty;
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           27
+// Endpoints:                           59
 // Async Callback:                       1
-// Total number of exported functions:  30
+// Total number of exported functions:  62
 
 #![no_std]
 
@@ -23,15 +23,40 @@ multiversx_sc_wasm_adapter::endpoints! {
         deposit => deposit
         withdraw => withdraw
         redeem => redeem
+        bootstrapPool => bootstrap_pool
         getCurrentPrice => calculate_price
-        getMinLaunchedTokenPrice => min_launched_token_price
-        getPricePrecision => price_precision
+        setDutchAuctionMode => set_dutch_auction_mode
+        setBatchAuctionMode => set_batch_auction_mode
+        setMaxUserDeposit => set_max_user_deposit
+        addToWhitelist => add_to_whitelist
+        removeFromWhitelist => remove_from_whitelist
+        claimUnsoldLaunchedTokens => claim_unsold_launched_tokens
+        getClearingPrice => get_clearing_price
+        getCurrentDutchPrice => get_current_dutch_price
+        getUnsoldLaunchedTokens => get_unsold_launched_tokens
+        getDutchStartPrice => dutch_start_price
+        getMaxUserDeposit => max_user_deposit
+        getUserDeposit => user_deposit
+        getWhitelist => whitelist
         getLaunchedTokenId => launched_token_id
         getAcceptedTokenId => accepted_token_id
         getLaunchedTokenBalance => launched_token_balance
         getAcceptedTokenBalance => accepted_token_balance
         getStartBlock => start_block
         getEndBlock => end_block
+        getMinLaunchedTokenPrice => min_launched_token_price
+        getPricePrecision => price_precision
+        getAuctionMode => auction_mode
+        getDutchSoldOutPrice => dutch_sold_out_price
+        setEarlyAccess => set_early_access
+        getEarlyAccessDurationBlocks => early_access_duration_blocks
+        getEarlyAccessMinTier => early_access_min_tier
+        setEnergyFactoryAddress => set_energy_factory_address
+        getEnergyFactoryAddress => energy_factory_address
+        setEnergyTiers => set_energy_tiers
+        refreshEnergyTier => refresh_energy_tier
+        getUserEnergyTier => get_user_energy_tier_view
+        getEnergyTierThresholds => energy_tier_thresholds
         setLockingScAddress => set_locking_sc_address
         setUnlockEpoch => set_unlock_epoch
         getLockingScAddress => locking_sc_address
@@ -43,6 +68,13 @@ multiversx_sc_wasm_adapter::endpoints! {
         getPenaltyMinPercentage => penalty_min_percentage
         getPenaltyMaxPercentage => penalty_max_percentage
         getFixedPenaltyPercentage => fixed_penalty_percentage
+        setRouterAddress => set_router_address
+        setLpUnlockEpoch => set_lp_unlock_epoch
+        createPool => create_pool
+        getRouterAddress => router_address
+        getPoolAddress => pool_address
+        getLpUnlockEpoch => lp_unlock_epoch
+        isPoolBootstrapped => pool_bootstrapped
         issueRedeemToken => issue_redeem_token
         createInitialRedeemTokens => create_initial_redeem_tokens
         getRedeemTokenId => redeem_token
@@ -71,6 +71,29 @@ pub trait FarmDeployModule {
         all_farm_addresses
     }
 
+    /// Paginated version of getAllDeployedFarms, over the deployers.
+    /// The deployers are never removed from the list, so the index is a stable cursor.
+    #[view(getDeployedFarmsPaginated)]
+    fn get_deployed_farms_paginated(
+        &self,
+        start_index: usize,
+        limit: usize,
+    ) -> pagination::PaginatedResult<Self::Api, ManagedAddress> {
+        let deployers_list = self.deployers_list();
+        let page = pagination::get_page(start_index, limit, deployers_list.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in page.indexes {
+            // the set mapper indexes start from 1
+            let deployer = deployers_list.get_by_index(index + 1);
+            for farm_address in &self.deployer_farm_addresses(&deployer).get() {
+                result.push(farm_address);
+            }
+        }
+
+        (page.next_index, result).into()
+    }
+
     #[proxy]
     fn farm_deploy_proxy(&self) -> farm::Proxy<Self::Api>;
 
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            4
+// Endpoints:                            5
 // Async Callback (empty):               1
-// Total number of exported functions:   7
+// Total number of exported functions:   8
 
 #![no_std]
 
@@ -23,6 +23,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         deployFarm => deploy_farm
         callFarmEndpoint => call_farm_endpoint
         getAllDeployedFarms => get_all_deployed_farms
+        getDeployedFarmsPaginated => get_deployed_farms_paginated
         getDeployerFarmAddresses => deployer_farm_addresses
     )
 }
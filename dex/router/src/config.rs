@@ -14,26 +14,57 @@ pub trait ConfigModule: read_pair_storage::ReadPairStorageModule {
         let first_token_id = self.get_first_token_id_mapper(pair_address.clone()).get();
         let second_token_id = self.get_second_token_id_mapper(pair_address.clone()).get();
 
+        let pair_tokens_opt = self.get_pair_tokens_key(&first_token_id, &second_token_id);
+        require!(pair_tokens_opt.is_some(), "Not a pair SC");
+
+        unsafe {
+            let pair_tokens = pair_tokens_opt.unwrap_unchecked();
+            let pair_map_address = self.pair_map().get(&pair_tokens).unwrap_unchecked();
+            require!(
+                &pair_map_address == pair_address
+                    || self
+                        .pair_fee_tiers(&pair_tokens)
+                        .values()
+                        .any(|address| &address == pair_address),
+                "Not a pair SC"
+            );
+        }
+    }
+
+    /// Pools created before fee tiers were introduced fall back to their current total fee percent.
+    fn get_pair_fee_tier(&self, pair_address: &ManagedAddress) -> u64 {
+        let fee_tier_mapper = self.pair_fee_tier(pair_address);
+        if !fee_tier_mapper.is_empty() {
+            return fee_tier_mapper.get();
+        }
+
+        self.get_total_fee_percent_mapper(pair_address.clone())
+            .get()
+    }
+
+    /// Returns the tokens in the order under which the pair was registered, if any.
+    fn get_pair_tokens_key(
+        &self,
+        first_token_id: &TokenIdentifier,
+        second_token_id: &TokenIdentifier,
+    ) -> Option<PairTokens<Self::Api>> {
         let pair_tokens = PairTokens {
             first_token_id: first_token_id.clone(),
             second_token_id: second_token_id.clone(),
         };
-
-        let mut pair_map_address_opt = self.pair_map().get(&pair_tokens);
-        if pair_map_address_opt.is_none() {
-            let reverse_pair_tokens = PairTokens {
-                first_token_id: second_token_id.clone(),
-                second_token_id: first_token_id.clone(),
-            };
-            pair_map_address_opt = self.pair_map().get(&reverse_pair_tokens);
+        if self.pair_map().contains_key(&pair_tokens) {
+            return Some(pair_tokens);
         }
 
-        require!(pair_map_address_opt.is_some(), "Not a pair SC");
-
-        unsafe {
-            let pair_map_address = pair_map_address_opt.unwrap_unchecked();
-            require!(&pair_map_address == pair_address, "Not a pair SC");
+        let reverse_pair_tokens = PairTokens {
+            first_token_id: second_token_id.clone(),
+            second_token_id: first_token_id.clone(),
+        };
+        if self.pair_map().contains_key(&reverse_pair_tokens) {
+            return Some(reverse_pair_tokens);
         }
+
+        None
     }
 
     #[view(getPairCreationEnabled)]
@@ -63,6 +94,23 @@ pub trait ConfigModule: read_pair_storage::ReadPairStorageModule {
     #[storage_mapper("pair_map")]
     fn pair_map(&self) -> MapMapper<PairTokens<Self::Api>, ManagedAddress>;
 
+    /// The keys of pair_map, which allow iterating from a given token pair.
+    #[storage_mapper("pair_map")]
+    fn pair_map_keys(&self) -> SetMapper<PairTokens<Self::Api>>;
+
+    /// Pools of the same tokens with other fees, besides the one in pair_map, keyed by their total fee percent.
+    #[storage_mapper("pairFeeTiers")]
+    fn pair_fee_tiers(&self, pair_tokens: &PairTokens<Self::Api>)
+        -> MapMapper<u64, ManagedAddress>;
+
+    /// The total fee percent a pool was created with, which keeps identifying its fee tier
+    /// even if the fees of the pool are changed afterwards.
+    #[storage_mapper("pairFeeTier")]
+    fn pair_fee_tier(&self, pair_address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("feeTiers")]
+    fn fee_tiers(&self) -> MapMapper<u64, u64>;
+
     #[view(getPairTemplateAddress)]
     #[storage_mapper("pair_template_address")]
     fn pair_template_address(&self) -> SingleValueMapper<ManagedAddress>;
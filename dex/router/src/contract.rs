@@ -6,9 +6,13 @@ multiversx_sc::derive_imports!();
 
 pub mod config;
 pub mod enable_swap_by_user;
-mod events;
+pub mod events;
 pub mod factory;
+pub mod fee_tier;
+pub mod integrator;
 pub mod multi_pair_swap;
+pub mod pair_lifecycle;
+pub mod zap;
 
 use factory::PairTokens;
 use pair::config::ProxyTrait as _;
@@ -21,7 +25,7 @@ const LP_TOKEN_INITIAL_SUPPLY: u64 = 1000;
 
 const DEFAULT_TOTAL_FEE_PERCENT: u64 = 300;
 const DEFAULT_SPECIAL_FEE_PERCENT: u64 = 50;
-const MAX_TOTAL_FEE_PERCENT: u64 = 100_000;
+pub const MAX_TOTAL_FEE_PERCENT: u64 = 100_000;
 const USER_DEFINED_TOTAL_FEE_PERCENT: u64 = 1_000;
 
 #[multiversx_sc::contract]
@@ -33,6 +37,10 @@ pub trait Router:
     + multi_pair_swap::MultiPairSwap
     + token_send::TokenSendModule
     + enable_swap_by_user::EnableSwapByUserModule
+    + zap::ZapModule
+    + pair_lifecycle::PairLifecycleModule
+    + fee_tier::FeeTierModule
+    + integrator::IntegratorModule
 {
     #[init]
     fn init(&self, pair_template_address_opt: OptionalValue<ManagedAddress>) {
@@ -139,6 +147,13 @@ pub trait Router:
             &initial_liquidity_adder,
             admins,
         );
+        self.pair_map().insert(
+            PairTokens {
+                first_token_id: first_token_id.clone(),
+                second_token_id: second_token_id.clone(),
+            },
+            address.clone(),
+        );
 
         self.emit_create_pair_event(
             caller,
@@ -279,6 +294,10 @@ pub trait Router:
         );
         let mut pair_address = self.get_pair(first_token_id.clone(), second_token_id.clone());
         require!(!pair_address.is_zero(), "Pair does not exists");
+        let pair_tokens = self
+            .get_pair_tokens_key(&first_token_id, &second_token_id)
+            .unwrap();
+        self.remove_all_pair_fee_tiers(&pair_tokens);
 
         pair_address = self
             .pair_map()
@@ -298,6 +317,8 @@ pub trait Router:
                 .unwrap_or_else(ManagedAddress::zero);
         }
 
+        self.clear_removed_pair_state(&pair_address);
+
         pair_address
     }
 
@@ -1,6 +1,8 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use crate::pair_lifecycle::PairLifecycleState;
+
 #[derive(TypeAbi, TopEncode)]
 pub struct CreatePairEvent<M: ManagedTypeApi> {
     caller: ManagedAddress<M>,
@@ -22,6 +24,22 @@ pub struct UserPairSwapEnabledEvent<M: ManagedTypeApi> {
     pair_address: ManagedAddress<M>,
 }
 
+#[derive(TypeAbi, TopEncode)]
+pub struct PairLifecycleStateEvent<M: ManagedTypeApi> {
+    caller: ManagedAddress<M>,
+    pair_address: ManagedAddress<M>,
+    successor_pair_address: ManagedAddress<M>,
+    state: PairLifecycleState,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct MigrateLiquidityEvent<M: ManagedTypeApi> {
+    caller: ManagedAddress<M>,
+    pair_address: ManagedAddress<M>,
+    successor_pair_address: ManagedAddress<M>,
+    output_payments: ManagedVec<M, EsdtTokenPayment<M>>,
+}
+
 #[multiversx_sc::module]
 pub trait EventsModule {
     fn emit_create_pair_event(
@@ -75,6 +93,48 @@ pub trait EventsModule {
         )
     }
 
+    fn emit_pair_lifecycle_state_event(
+        &self,
+        caller: ManagedAddress,
+        pair_address: ManagedAddress,
+        successor_pair_address: ManagedAddress,
+        state: PairLifecycleState,
+    ) {
+        let epoch = self.blockchain().get_block_epoch();
+        self.pair_lifecycle_state_event(
+            pair_address.clone(),
+            caller.clone(),
+            epoch,
+            PairLifecycleStateEvent {
+                caller,
+                pair_address,
+                successor_pair_address,
+                state,
+            },
+        )
+    }
+
+    fn emit_migrate_liquidity_event(
+        &self,
+        caller: ManagedAddress,
+        pair_address: ManagedAddress,
+        successor_pair_address: ManagedAddress,
+        output_payments: ManagedVec<EsdtTokenPayment>,
+    ) {
+        let epoch = self.blockchain().get_block_epoch();
+        self.migrate_liquidity_event(
+            pair_address.clone(),
+            caller.clone(),
+            epoch,
+            MigrateLiquidityEvent {
+                caller,
+                pair_address,
+                successor_pair_address,
+                output_payments,
+            },
+        )
+    }
+
     #[event("create_pair")]
     fn create_pair_event(
         self,
@@ -94,4 +154,22 @@ pub trait EventsModule {
         #[indexed] epoch: u64,
         swap_enabled_event: UserPairSwapEnabledEvent<Self::Api>,
     );
+
+    #[event("pairLifecycleState")]
+    fn pair_lifecycle_state_event(
+        &self,
+        #[indexed] pair_address: ManagedAddress,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] epoch: u64,
+        pair_lifecycle_state_event: PairLifecycleStateEvent<Self::Api>,
+    );
+
+    #[event("migrateLiquidity")]
+    fn migrate_liquidity_event(
+        &self,
+        #[indexed] pair_address: ManagedAddress,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] epoch: u64,
+        migrate_liquidity_event: MigrateLiquidityEvent<Self::Api>,
+    );
 }
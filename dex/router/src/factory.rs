@@ -6,7 +6,7 @@ use pair::read_pair_storage;
 
 const TEMPORARY_OWNER_PERIOD_BLOCKS: u64 = 50;
 
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, TypeAbi)]
 pub struct PairTokens<M: ManagedTypeApi> {
     pub first_token_id: TokenIdentifier<M>,
     pub second_token_id: TokenIdentifier<M>,
@@ -65,13 +65,7 @@ pub trait FactoryModule: config::ConfigModule + read_pair_storage::ReadPairStora
                 CodeMetadata::UPGRADEABLE | CodeMetadata::READABLE | CodeMetadata::PAYABLE_BY_SC,
             );
 
-        self.pair_map().insert(
-            PairTokens {
-                first_token_id: first_token_id.clone(),
-                second_token_id: second_token_id.clone(),
-            },
-            new_address.clone(),
-        );
+        self.pair_fee_tier(&new_address).set(total_fee_percent);
         self.pair_temporary_owner().insert(
             new_address.clone(),
             (
@@ -97,8 +91,11 @@ pub trait FactoryModule: config::ConfigModule + read_pair_storage::ReadPairStora
     #[view(getAllPairsManagedAddresses)]
     fn get_all_pairs_addresses(&self) -> MultiValueEncoded<ManagedAddress> {
         let mut result = MultiValueEncoded::new();
-        for pair in self.pair_map().values() {
+        for (pair_tokens, pair) in self.pair_map().iter() {
             result.push(pair);
+            for fee_tier_pair in self.pair_fee_tiers(&pair_tokens).values() {
+                result.push(fee_tier_pair);
+            }
         }
         result
     }
@@ -116,6 +113,14 @@ pub trait FactoryModule: config::ConfigModule + read_pair_storage::ReadPairStora
     fn get_all_pair_contract_metadata(&self) -> MultiValueEncoded<PairContractMetadata<Self::Api>> {
         let mut result = MultiValueEncoded::new();
         for (k, v) in self.pair_map().iter() {
+            for fee_tier_pair in self.pair_fee_tiers(&k).values() {
+                result.push(PairContractMetadata {
+                    first_token_id: k.first_token_id.clone(),
+                    second_token_id: k.second_token_id.clone(),
+                    address: fee_tier_pair,
+                });
+            }
+
             let pair_metadata = PairContractMetadata {
                 first_token_id: k.first_token_id,
                 second_token_id: k.second_token_id,
@@ -126,6 +131,81 @@ pub trait FactoryModule: config::ConfigModule + read_pair_storage::ReadPairStora
         result
     }
 
+    /// Same as getAllPairsManagedAddresses, but only for up to `limit` token pairs,
+    /// starting from the given token pairs, or from the first ones if not given.
+    /// Also returns the token pairs to continue from, which are missing once the end was reached.
+    #[view(getPairsManagedAddressesPaginated)]
+    fn get_pairs_addresses_paginated(
+        &self,
+        limit: usize,
+        opt_start_pair: OptionalValue<PairTokens<Self::Api>>,
+    ) -> pagination::KeyPaginatedResult<Self::Api, PairTokens<Self::Api>, ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+        let opt_next_pair =
+            self.for_each_pair_in_page(limit, opt_start_pair, |pair_tokens, pair| {
+                result.push(pair);
+                for fee_tier_pair in self.pair_fee_tiers(&pair_tokens).values() {
+                    result.push(fee_tier_pair);
+                }
+            });
+
+        (opt_next_pair, result).into()
+    }
+
+    /// Same as getAllPairContractMetadata, but paginated like getPairsManagedAddressesPaginated.
+    #[view(getPairContractMetadataPaginated)]
+    fn get_pair_contract_metadata_paginated(
+        &self,
+        limit: usize,
+        opt_start_pair: OptionalValue<PairTokens<Self::Api>>,
+    ) -> pagination::KeyPaginatedResult<
+        Self::Api,
+        PairTokens<Self::Api>,
+        PairContractMetadata<Self::Api>,
+    > {
+        let mut result = MultiValueEncoded::new();
+        let opt_next_pair =
+            self.for_each_pair_in_page(limit, opt_start_pair, |pair_tokens, pair| {
+                for fee_tier_pair in self.pair_fee_tiers(&pair_tokens).values() {
+                    result.push(PairContractMetadata {
+                        first_token_id: pair_tokens.first_token_id.clone(),
+                        second_token_id: pair_tokens.second_token_id.clone(),
+                        address: fee_tier_pair,
+                    });
+                }
+
+                result.push(PairContractMetadata {
+                    first_token_id: pair_tokens.first_token_id,
+                    second_token_id: pair_tokens.second_token_id,
+                    address: pair,
+                });
+            });
+
+        (opt_next_pair, result).into()
+    }
+
+    /// Walks the pairs from the given token pairs instead of the head of the map,
+    /// so the cost of a page doesn't depend on its position.
+    fn for_each_pair_in_page<F: FnMut(PairTokens<Self::Api>, ManagedAddress)>(
+        &self,
+        limit: usize,
+        opt_start_pair: OptionalValue<PairTokens<Self::Api>>,
+        mut f: F,
+    ) -> Option<PairTokens<Self::Api>> {
+        let pair_map = self.pair_map();
+        pagination::for_each_in_key_page(
+            &self.pair_map_keys(),
+            limit,
+            opt_start_pair,
+            |pair_tokens| {
+                let pair = pair_map
+                    .get(&pair_tokens)
+                    .unwrap_or_else(ManagedAddress::zero);
+                f(pair_tokens, pair);
+            },
+        )
+    }
+
     #[view(getPair)]
     fn get_pair(
         &self,
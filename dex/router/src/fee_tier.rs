@@ -0,0 +1,222 @@
+multiversx_sc::imports!();
+
+use crate::{config, events, factory, multi_pair_swap, pair_lifecycle, MAX_TOTAL_FEE_PERCENT};
+use pair::read_pair_storage;
+
+#[multiversx_sc::module]
+pub trait FeeTierModule:
+    config::ConfigModule
+    + read_pair_storage::ReadPairStorageModule
+    + factory::FactoryModule
+    + events::EventsModule
+    + multi_pair_swap::MultiPairSwap
+    + token_send::TokenSendModule
+    + pair_lifecycle::PairLifecycleModule
+{
+    /// Fee tiers in which new pools can be created for an existing token pair,
+    /// e.g. a low fee for stable pairs and a high one for volatile tokens.
+    #[only_owner]
+    #[endpoint(addFeeTier)]
+    fn add_fee_tier(&self, total_fee_percent: u64, special_fee_percent: u64) {
+        require!(
+            total_fee_percent >= special_fee_percent && total_fee_percent < MAX_TOTAL_FEE_PERCENT,
+            "Bad percents"
+        );
+
+        let _ = self
+            .fee_tiers()
+            .insert(total_fee_percent, special_fee_percent);
+    }
+
+    /// Existing pools in the tier are not affected.
+    #[only_owner]
+    #[endpoint(removeFeeTier)]
+    fn remove_fee_tier(&self, total_fee_percent: u64) {
+        let removed = self.fee_tiers().remove(&total_fee_percent);
+        require!(removed.is_some(), "Unknown fee tier");
+    }
+
+    /// Creates a new pool for the given tokens, with the fees of the given tier.
+    /// If no pool exists yet for the tokens, it becomes the one returned by getPair.
+    #[allow_multiple_var_args]
+    #[endpoint(createPairWithFeeTier)]
+    fn create_pair_with_fee_tier(
+        &self,
+        first_token_id: TokenIdentifier,
+        second_token_id: TokenIdentifier,
+        initial_liquidity_adder: ManagedAddress,
+        total_fee_percent: u64,
+        mut admins: MultiValueEncoded<ManagedAddress>,
+    ) -> ManagedAddress {
+        require!(self.is_active(), "Not active");
+        let owner = self.owner().get();
+        let caller = self.blockchain().get_caller();
+
+        if caller != owner {
+            require!(
+                self.pair_creation_enabled().get(),
+                "Pair creation is disabled"
+            );
+        }
+
+        require!(first_token_id != second_token_id, "Identical tokens");
+        require!(
+            first_token_id.is_valid_esdt_identifier(),
+            "First Token ID is not a valid esdt token ID"
+        );
+        require!(
+            second_token_id.is_valid_esdt_identifier(),
+            "Second Token ID is not a valid esdt token ID"
+        );
+
+        let special_fee_percent = self
+            .fee_tiers()
+            .get(&total_fee_percent)
+            .unwrap_or_else(|| sc_panic!("Unknown fee tier"));
+        let pair_address = self.get_pair_by_fee_tier(
+            first_token_id.clone(),
+            second_token_id.clone(),
+            total_fee_percent,
+        );
+        require!(pair_address.is_zero(), "Pair already exists");
+
+        admins.push(caller.clone());
+
+        let address = self.create_pair(
+            &first_token_id,
+            &second_token_id,
+            &owner,
+            total_fee_percent,
+            special_fee_percent,
+            &initial_liquidity_adder,
+            admins,
+        );
+        match self.get_pair_tokens_key(&first_token_id, &second_token_id) {
+            Some(pair_tokens) => {
+                self.pair_fee_tiers(&pair_tokens)
+                    .insert(total_fee_percent, address.clone());
+            }
+            None => {
+                self.pair_map().insert(
+                    factory::PairTokens {
+                        first_token_id: first_token_id.clone(),
+                        second_token_id: second_token_id.clone(),
+                    },
+                    address.clone(),
+                );
+            }
+        }
+
+        self.emit_create_pair_event(
+            caller,
+            first_token_id,
+            second_token_id,
+            total_fee_percent,
+            special_fee_percent,
+            address.clone(),
+        );
+        address
+    }
+
+    /// Removes a pool from a fee tier. The pool returned by getPair is removed through removePair.
+    #[only_owner]
+    #[endpoint(removePairFeeTier)]
+    fn remove_pair_fee_tier(
+        &self,
+        first_token_id: TokenIdentifier,
+        second_token_id: TokenIdentifier,
+        total_fee_percent: u64,
+    ) -> ManagedAddress {
+        require!(self.is_active(), "Not active");
+
+        let pair_tokens = self
+            .get_pair_tokens_key(&first_token_id, &second_token_id)
+            .unwrap_or_else(|| sc_panic!("Pair does not exists"));
+        let pair_address = self
+            .pair_fee_tiers(&pair_tokens)
+            .remove(&total_fee_percent)
+            .unwrap_or_else(|| sc_panic!("Pair does not exists"));
+        self.clear_removed_pair_state(&pair_address);
+
+        pair_address
+    }
+
+    /// Returns the pool of the given tokens with the given total fee percent, or the zero address.
+    #[view(getPairByFeeTier)]
+    fn get_pair_by_fee_tier(
+        &self,
+        first_token_id: TokenIdentifier,
+        second_token_id: TokenIdentifier,
+        total_fee_percent: u64,
+    ) -> ManagedAddress {
+        let pair_tokens = match self.get_pair_tokens_key(&first_token_id, &second_token_id) {
+            Some(pair_tokens) => pair_tokens,
+            None => return ManagedAddress::zero(),
+        };
+
+        let pair_address = self
+            .pair_map()
+            .get(&pair_tokens)
+            .unwrap_or_else(ManagedAddress::zero);
+        if self.get_pair_fee_tier(&pair_address) == total_fee_percent {
+            return pair_address;
+        }
+
+        self.pair_fee_tiers(&pair_tokens)
+            .get(&total_fee_percent)
+            .unwrap_or_else(ManagedAddress::zero)
+    }
+
+    /// Lists all the pools of the given tokens, as (total fee percent, address) pairs.
+    /// The first one is the pool returned by getPair.
+    #[view(getPairFeeTiers)]
+    fn get_pair_fee_tiers(
+        &self,
+        first_token_id: TokenIdentifier,
+        second_token_id: TokenIdentifier,
+    ) -> MultiValueEncoded<MultiValue2<u64, ManagedAddress>> {
+        let mut result = MultiValueEncoded::new();
+        let pair_tokens = match self.get_pair_tokens_key(&first_token_id, &second_token_id) {
+            Some(pair_tokens) => pair_tokens,
+            None => return result,
+        };
+
+        let pair_address = self
+            .pair_map()
+            .get(&pair_tokens)
+            .unwrap_or_else(ManagedAddress::zero);
+        let fee_tier = self.get_pair_fee_tier(&pair_address);
+        result.push((fee_tier, pair_address).into());
+
+        for (fee_tier, fee_tier_pair) in self.pair_fee_tiers(&pair_tokens).iter() {
+            result.push((fee_tier, fee_tier_pair).into());
+        }
+
+        result
+    }
+
+    /// Removes the fee tier pools of the given tokens, besides the one returned by getPair.
+    fn remove_all_pair_fee_tiers(&self, pair_tokens: &factory::PairTokens<Self::Api>) {
+        let mut pair_fee_tiers_mapper = self.pair_fee_tiers(pair_tokens);
+        for pair_address in pair_fee_tiers_mapper.values() {
+            self.clear_removed_pair_state(&pair_address);
+        }
+
+        pair_fee_tiers_mapper.clear();
+    }
+
+    fn clear_removed_pair_state(&self, pair_address: &ManagedAddress) {
+        self.pair_fee_tier(pair_address).clear();
+        self.clear_pair_lifecycle_state(pair_address);
+    }
+
+    #[view(getFeeTiers)]
+    fn get_fee_tiers(&self) -> MultiValueEncoded<MultiValue2<u64, u64>> {
+        let mut result = MultiValueEncoded::new();
+        for (total_fee_percent, special_fee_percent) in self.fee_tiers().iter() {
+            result.push((total_fee_percent, special_fee_percent).into());
+        }
+
+        result
+    }
+}
@@ -0,0 +1,54 @@
+multiversx_sc::imports!();
+
+/// Upper limit for the share of a pair's special fee that can go to an integrator
+pub const MAX_INTEGRATOR_FEE_SHARE_PERCENTAGE: u64 = 50_000;
+
+#[multiversx_sc::module]
+pub trait IntegratorModule {
+    /// Approves an integrator, e.g. a front-end or an aggregator, with its own share of the pairs' special fee.
+    /// The fee share percentage uses the pair's MAX_PERCENTAGE and is capped by MAX_INTEGRATOR_FEE_SHARE_PERCENTAGE.
+    /// Pairs read the registry directly from the router's storage.
+    #[only_owner]
+    #[endpoint(setIntegrator)]
+    fn set_integrator(&self, integrator: ManagedAddress, fee_share_percentage: u64) {
+        require!(
+            fee_share_percentage > 0 && fee_share_percentage <= MAX_INTEGRATOR_FEE_SHARE_PERCENTAGE,
+            "Invalid fee share percentage"
+        );
+
+        let _ = self.integrators().insert(integrator.clone());
+        self.integrator_fee_share_percentage(&integrator)
+            .set(fee_share_percentage);
+    }
+
+    /// Fees already credited to the integrator can still be claimed from the pairs.
+    #[only_owner]
+    #[endpoint(removeIntegrator)]
+    fn remove_integrator(&self, integrator: ManagedAddress) {
+        let removed = self.integrators().swap_remove(&integrator);
+        require!(removed, "Unknown integrator");
+
+        self.integrator_fee_share_percentage(&integrator).clear();
+    }
+
+    #[view(getIntegrators)]
+    fn get_integrators(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, u64>> {
+        let mut result = MultiValueEncoded::new();
+        for integrator in self.integrators().iter() {
+            let fee_share_percentage = self.integrator_fee_share_percentage(&integrator).get();
+            result.push((integrator, fee_share_percentage).into());
+        }
+
+        result
+    }
+
+    #[storage_mapper("integrators")]
+    fn integrators(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getIntegratorFeeSharePercentage)]
+    #[storage_mapper("integratorFeeSharePercentage")]
+    fn integrator_fee_share_percentage(
+        &self,
+        integrator: &ManagedAddress,
+    ) -> SingleValueMapper<u64>;
+}
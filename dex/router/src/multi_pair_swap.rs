@@ -23,6 +23,26 @@ pub trait MultiPairSwap:
     fn multi_pair_swap(
         &self,
         swap_operations: MultiValueEncoded<SwapOperationType<Self::Api>>,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        self.multi_pair_swap_common(swap_operations, None)
+    }
+
+    /// Same as multiPairSwap, but each pair credits the integrator with a share of its special fee,
+    /// if the integrator is approved.
+    #[payable("*")]
+    #[endpoint(multiPairSwapWithIntegrator)]
+    fn multi_pair_swap_with_integrator(
+        &self,
+        integrator: ManagedAddress,
+        swap_operations: MultiValueEncoded<SwapOperationType<Self::Api>>,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        self.multi_pair_swap_common(swap_operations, Some(integrator))
+    }
+
+    fn multi_pair_swap_common(
+        &self,
+        swap_operations: MultiValueEncoded<SwapOperationType<Self::Api>>,
+        opt_integrator: Option<ManagedAddress>,
     ) -> ManagedVec<EsdtTokenPayment> {
         require!(self.is_active(), "Not active");
 
@@ -52,6 +72,7 @@ pub trait MultiPairSwap:
                     last_payment.amount,
                     token_wanted,
                     amount_wanted,
+                    opt_integrator.clone(),
                 );
             } else if function == swap_fixed_output_endpoint {
                 let (payment, residuum) = self.actual_swap_fixed_output(
@@ -60,6 +81,7 @@ pub trait MultiPairSwap:
                     last_payment.amount,
                     token_wanted,
                     amount_wanted,
+                    opt_integrator.clone(),
                 );
 
                 last_payment = payment;
@@ -82,9 +104,14 @@ pub trait MultiPairSwap:
         amount_in: BigUint,
         token_out: TokenIdentifier,
         amount_out_min: BigUint,
+        opt_integrator: Option<ManagedAddress>,
     ) -> EsdtTokenPayment<Self::Api> {
         self.pair_contract_proxy(pair_address)
-            .swap_tokens_fixed_input(token_out, amount_out_min)
+            .swap_tokens_fixed_input(
+                token_out,
+                amount_out_min,
+                OptionalValue::from(opt_integrator),
+            )
             .with_esdt_transfer((token_in, 0, amount_in))
             .execute_on_dest_context()
     }
@@ -96,10 +123,15 @@ pub trait MultiPairSwap:
         amount_in_max: BigUint,
         token_out: TokenIdentifier,
         amount_out: BigUint,
+        opt_integrator: Option<ManagedAddress>,
     ) -> (EsdtTokenPayment<Self::Api>, EsdtTokenPayment<Self::Api>) {
         let call_result: MultiValue2<EsdtTokenPayment<Self::Api>, EsdtTokenPayment<Self::Api>> =
             self.pair_contract_proxy(pair_address)
-                .swap_tokens_fixed_output(token_out, amount_out)
+                .swap_tokens_fixed_output(
+                    token_out,
+                    amount_out,
+                    OptionalValue::from(opt_integrator),
+                )
                 .with_esdt_transfer((token_in, 0, amount_in_max))
                 .execute_on_dest_context();
 
@@ -0,0 +1,201 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{config, events, factory, multi_pair_swap};
+use pair::{
+    config::ProxyTrait as _,
+    pair_actions::{add_liq::ProxyTrait as _, remove_liq::ProxyTrait as _},
+    read_pair_storage,
+};
+
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug,
+)]
+pub enum PairLifecycleState {
+    Active,
+    Deprecated,
+    WithdrawOnly,
+}
+
+#[multiversx_sc::module]
+pub trait PairLifecycleModule:
+    config::ConfigModule
+    + read_pair_storage::ReadPairStorageModule
+    + factory::FactoryModule
+    + events::EventsModule
+    + multi_pair_swap::MultiPairSwap
+    + token_send::TokenSendModule
+{
+    /// Marks the pair as deprecated, in favour of the successor pair, which must have the same tokens.
+    /// Adding liquidity to the deprecated pair is disabled, while swaps and removing liquidity still work.
+    /// LP holders can then move their liquidity through migrateLiquidity.
+    #[only_owner]
+    #[endpoint(deprecatePair)]
+    fn deprecate_pair(&self, pair_address: ManagedAddress, successor_pair_address: ManagedAddress) {
+        require!(self.is_active(), "Not active");
+        self.check_is_pair_sc(&pair_address);
+        self.check_is_pair_sc(&successor_pair_address);
+        require!(
+            pair_address != successor_pair_address,
+            "Invalid successor pair"
+        );
+        require!(
+            self.pair_lifecycle_state(&pair_address).get() == PairLifecycleState::Active,
+            "Pair already deprecated"
+        );
+        require!(
+            self.pair_lifecycle_state(&successor_pair_address).get() == PairLifecycleState::Active,
+            "Successor pair is deprecated"
+        );
+
+        let first_token_id = self.get_first_token_id_mapper(pair_address.clone()).get();
+        let second_token_id = self.get_second_token_id_mapper(pair_address.clone()).get();
+        let successor_first_token_id = self
+            .get_first_token_id_mapper(successor_pair_address.clone())
+            .get();
+        let successor_second_token_id = self
+            .get_second_token_id_mapper(successor_pair_address.clone())
+            .get();
+        let same_tokens = (first_token_id == successor_first_token_id
+            && second_token_id == successor_second_token_id)
+            || (first_token_id == successor_second_token_id
+                && second_token_id == successor_first_token_id);
+        require!(same_tokens, "Successor pair has different tokens");
+
+        let _: IgnoreValue = self
+            .pair_contract_proxy(pair_address.clone())
+            .set_add_liquidity_disabled(true)
+            .execute_on_dest_context();
+
+        self.pair_successor(&pair_address)
+            .set(&successor_pair_address);
+        self.set_pair_lifecycle_state(pair_address, PairLifecycleState::Deprecated);
+    }
+
+    /// Final step of retiring a deprecated pair. Swaps are disabled as well,
+    /// so the only remaining action is removing liquidity.
+    #[only_owner]
+    #[endpoint(setPairWithdrawOnly)]
+    fn set_pair_withdraw_only(&self, pair_address: ManagedAddress) {
+        require!(self.is_active(), "Not active");
+        self.check_is_pair_sc(&pair_address);
+        require!(
+            self.pair_lifecycle_state(&pair_address).get() == PairLifecycleState::Deprecated,
+            "Pair not deprecated"
+        );
+
+        let _: IgnoreValue = self
+            .pair_contract_proxy(pair_address.clone())
+            .set_state_active_no_swaps()
+            .execute_on_dest_context();
+
+        self.set_pair_lifecycle_state(pair_address, PairLifecycleState::WithdrawOnly);
+    }
+
+    /// Removes the liquidity of the sent LP tokens from a deprecated pair and adds it to its successor.
+    /// The minimum amounts are for the tokens added to the successor pair, in the successor's order.
+    /// Returns the new LP tokens and the dust left from adding the liquidity.
+    #[payable("*")]
+    #[endpoint(migrateLiquidity)]
+    fn migrate_liquidity(
+        &self,
+        pair_address: ManagedAddress,
+        first_token_amount_min: BigUint,
+        second_token_amount_min: BigUint,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        require!(self.is_active(), "Not active");
+        self.check_is_pair_sc(&pair_address);
+        require!(
+            self.pair_lifecycle_state(&pair_address).get() != PairLifecycleState::Active,
+            "Pair not deprecated"
+        );
+
+        let payment = self.call_value().single_esdt();
+        let lp_token_id: TokenIdentifier = self
+            .pair_contract_proxy(pair_address.clone())
+            .get_lp_token_identifier()
+            .execute_on_dest_context();
+        require!(
+            payment.token_identifier == lp_token_id && payment.token_nonce == 0,
+            "Invalid payment token"
+        );
+
+        let remove_liq_result: MultiValue2<EsdtTokenPayment, EsdtTokenPayment> = self
+            .pair_contract_proxy(pair_address.clone())
+            .remove_liquidity(BigUint::from(1u32), BigUint::from(1u32))
+            .with_esdt_transfer(payment)
+            .execute_on_dest_context();
+        let (first_payment, second_payment) = remove_liq_result.into_tuple();
+
+        let successor_pair_address = self.pair_successor(&pair_address).get();
+        let successor_first_token_id = self
+            .get_first_token_id_mapper(successor_pair_address.clone())
+            .get();
+        let mut add_liq_payments = ManagedVec::new();
+        if first_payment.token_identifier == successor_first_token_id {
+            add_liq_payments.push(first_payment);
+            add_liq_payments.push(second_payment);
+        } else {
+            add_liq_payments.push(second_payment);
+            add_liq_payments.push(first_payment);
+        }
+
+        let add_liq_result: MultiValue3<EsdtTokenPayment, EsdtTokenPayment, EsdtTokenPayment> =
+            self.pair_contract_proxy(successor_pair_address.clone())
+                .add_liquidity(first_token_amount_min, second_token_amount_min)
+                .with_multi_token_transfer(add_liq_payments.clone())
+                .execute_on_dest_context();
+        let (lp_payment, first_used_payment, second_used_payment) = add_liq_result.into_tuple();
+
+        let first_added_payment = add_liq_payments.get(0);
+        let second_added_payment = add_liq_payments.get(1);
+        let mut output_payments = ManagedVec::new();
+        output_payments.push(lp_payment);
+        output_payments.push(EsdtTokenPayment::new(
+            first_added_payment.token_identifier,
+            0,
+            first_added_payment.amount - first_used_payment.amount,
+        ));
+        output_payments.push(EsdtTokenPayment::new(
+            second_added_payment.token_identifier,
+            0,
+            second_added_payment.amount - second_used_payment.amount,
+        ));
+
+        let caller = self.blockchain().get_caller();
+        self.send_multiple_tokens_if_not_zero(&caller, &output_payments);
+
+        self.emit_migrate_liquidity_event(
+            caller,
+            pair_address,
+            successor_pair_address,
+            output_payments.clone(),
+        );
+
+        output_payments
+    }
+
+    fn set_pair_lifecycle_state(&self, pair_address: ManagedAddress, state: PairLifecycleState) {
+        self.pair_lifecycle_state(&pair_address).set(state);
+
+        let caller = self.blockchain().get_caller();
+        let successor_pair_address = self.pair_successor(&pair_address).get();
+        self.emit_pair_lifecycle_state_event(caller, pair_address, successor_pair_address, state);
+    }
+
+    fn clear_pair_lifecycle_state(&self, pair_address: &ManagedAddress) {
+        self.pair_lifecycle_state(pair_address).clear();
+        self.pair_successor(pair_address).clear();
+    }
+
+    #[view(getPairLifecycleState)]
+    #[storage_mapper("pairLifecycleState")]
+    fn pair_lifecycle_state(
+        &self,
+        pair_address: &ManagedAddress,
+    ) -> SingleValueMapper<PairLifecycleState>;
+
+    #[view(getPairSuccessor)]
+    #[storage_mapper("pairSuccessor")]
+    fn pair_successor(&self, pair_address: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+}
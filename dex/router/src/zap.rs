@@ -0,0 +1,278 @@
+multiversx_sc::imports!();
+
+use crate::{config, factory, multi_pair_swap};
+use pair::{
+    config::{ProxyTrait as _, MAX_PERCENTAGE},
+    pair_actions::{add_liq::ProxyTrait as _, remove_liq::ProxyTrait as _},
+    read_pair_storage,
+};
+
+type SwapRouteStepType<M> = MultiValue2<ManagedAddress<M>, TokenIdentifier<M>>;
+
+pub const ENTER_FARM_FUNC_NAME: &[u8] = b"enterFarm";
+
+#[multiversx_sc::module]
+pub trait ZapModule:
+    config::ConfigModule
+    + read_pair_storage::ReadPairStorageModule
+    + factory::FactoryModule
+    + multi_pair_swap::MultiPairSwap
+    + token_send::TokenSendModule
+{
+    /// Adds liquidity to the given pair using a single token.
+    /// If the payment token is not one of the pair's tokens, it is first swapped
+    /// through the given route, as a list of (pair_address, token_wanted) pairs.
+    /// The optimal part of the tokens is then swapped in the pair, and the rest
+    /// is added as liquidity, together with the swapped tokens.
+    /// Returns the LP tokens and the dust left from adding the liquidity.
+    #[payable("*")]
+    #[endpoint(zapIn)]
+    fn zap_in(
+        &self,
+        pair_address: ManagedAddress,
+        min_lp_amount: BigUint,
+        swap_route: MultiValueEncoded<SwapRouteStepType<Self::Api>>,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        let caller = self.blockchain().get_caller();
+        let output_payments = self.zap_in_common(pair_address, min_lp_amount, swap_route);
+        self.send_multiple_tokens_if_not_zero(&caller, &output_payments);
+
+        output_payments
+    }
+
+    /// Same as zapIn, but the LP tokens are used to enter the given farm on behalf of the caller.
+    /// The router must be whitelisted in the farm.
+    /// Returns the farm tokens, the boosted rewards and the dust left from adding the liquidity.
+    #[payable("*")]
+    #[endpoint(zapInAndEnterFarm)]
+    fn zap_in_and_enter_farm(
+        &self,
+        pair_address: ManagedAddress,
+        farm_address: ManagedAddress,
+        min_lp_amount: BigUint,
+        swap_route: MultiValueEncoded<SwapRouteStepType<Self::Api>>,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        require!(
+            self.blockchain().is_smart_contract(&farm_address),
+            "Invalid farm address"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let zap_in_payments = self.zap_in_common(pair_address, min_lp_amount, swap_route);
+        let lp_payment = zap_in_payments.get(0);
+
+        let enter_farm_result: MultiValue2<EsdtTokenPayment, EsdtTokenPayment> = self
+            .send()
+            .contract_call::<()>(farm_address, ENTER_FARM_FUNC_NAME)
+            .argument(&caller)
+            .with_esdt_transfer(lp_payment)
+            .execute_on_dest_context();
+        let (farm_token, boosted_rewards) = enter_farm_result.into_tuple();
+
+        let mut output_payments = ManagedVec::new();
+        output_payments.push(farm_token);
+        output_payments.push(boosted_rewards);
+        output_payments.extend(&zap_in_payments.slice(1, zap_in_payments.len()).unwrap());
+        self.send_multiple_tokens_if_not_zero(&caller, &output_payments);
+
+        output_payments
+    }
+
+    /// Removes liquidity from the given pair and swaps one of the received tokens
+    /// into the other, so that only token_out is received.
+    /// token_out must be one of the pair's tokens. The result can be further
+    /// swapped through the given route, as a list of (pair_address, token_wanted) pairs.
+    /// min_amount_out applies to the final token.
+    #[payable("*")]
+    #[endpoint(zapOut)]
+    fn zap_out(
+        &self,
+        pair_address: ManagedAddress,
+        token_out: TokenIdentifier,
+        min_amount_out: BigUint,
+        swap_route: MultiValueEncoded<SwapRouteStepType<Self::Api>>,
+    ) -> EsdtTokenPayment {
+        require!(self.is_active(), "Not active");
+        self.check_is_pair_sc(&pair_address);
+
+        let payment = self.call_value().single_esdt();
+        let lp_token_id: TokenIdentifier = self
+            .pair_contract_proxy(pair_address.clone())
+            .get_lp_token_identifier()
+            .execute_on_dest_context();
+        require!(
+            payment.token_identifier == lp_token_id && payment.token_nonce == 0,
+            "Invalid payment token"
+        );
+
+        let first_token_id = self.get_first_token_id_mapper(pair_address.clone()).get();
+        let second_token_id = self.get_second_token_id_mapper(pair_address.clone()).get();
+        require!(
+            token_out == first_token_id || token_out == second_token_id,
+            "Invalid token out"
+        );
+
+        let remove_liq_result: MultiValue2<EsdtTokenPayment, EsdtTokenPayment> = self
+            .pair_contract_proxy(pair_address.clone())
+            .remove_liquidity(BigUint::from(1u32), BigUint::from(1u32))
+            .with_esdt_transfer(payment)
+            .execute_on_dest_context();
+        let (first_payment, second_payment) = remove_liq_result.into_tuple();
+
+        let (mut kept_payment, payment_to_swap) = if token_out == first_token_id {
+            (first_payment, second_payment)
+        } else {
+            (second_payment, first_payment)
+        };
+        let swapped_payment = self.actual_swap_fixed_input(
+            pair_address,
+            payment_to_swap.token_identifier,
+            payment_to_swap.amount,
+            token_out,
+            BigUint::from(1u32),
+            None,
+        );
+        kept_payment.amount += swapped_payment.amount;
+
+        let output_payment = self.swap_through_route(kept_payment, swap_route);
+        require!(output_payment.amount >= min_amount_out, "Slippage exceeded");
+
+        let caller = self.blockchain().get_caller();
+        self.send().direct_esdt(
+            &caller,
+            &output_payment.token_identifier,
+            0,
+            &output_payment.amount,
+        );
+
+        output_payment
+    }
+
+    /// The amount of tokens that have to be swapped out of amount_in, so that the swap
+    /// result and the remaining tokens are in the same ratio as the pair reserves after the swap.
+    /// Solves s^2 * (1 - f) + s * r * (2 - f) - a * r = 0, with f being the total fee.
+    #[view(getZapInSwapAmount)]
+    fn get_zap_in_swap_amount(
+        &self,
+        pair_address: ManagedAddress,
+        token_in: TokenIdentifier,
+        amount_in: BigUint,
+    ) -> BigUint {
+        let reserve_in = self
+            .get_pair_reserve_mapper(pair_address.clone(), &token_in)
+            .get();
+        require!(reserve_in > 0, "No liquidity in pair");
+
+        let total_fee_percent: u64 = self
+            .pair_contract_proxy(pair_address)
+            .total_fee_percent()
+            .execute_on_dest_context();
+        let amount_in_percent = MAX_PERCENTAGE - total_fee_percent;
+
+        let reserve_term = &reserve_in * (MAX_PERCENTAGE + amount_in_percent);
+        let discriminant = &reserve_term * &reserve_term
+            + reserve_in * amount_in * (4 * MAX_PERCENTAGE * amount_in_percent);
+
+        (discriminant.sqrt() - reserve_term) / (2 * amount_in_percent)
+    }
+
+    fn zap_in_common(
+        &self,
+        pair_address: ManagedAddress,
+        min_lp_amount: BigUint,
+        swap_route: MultiValueEncoded<SwapRouteStepType<Self::Api>>,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        require!(self.is_active(), "Not active");
+        self.check_is_pair_sc(&pair_address);
+
+        let payment = self.call_value().single_esdt();
+        require!(payment.token_nonce == 0, "Invalid nonce. Should be zero");
+        require!(payment.amount > 0u64, "Invalid amount. Should not be zero");
+
+        let payment = self.swap_through_route(payment, swap_route);
+
+        let first_token_id = self.get_first_token_id_mapper(pair_address.clone()).get();
+        let second_token_id = self.get_second_token_id_mapper(pair_address.clone()).get();
+        let other_token_id = if payment.token_identifier == first_token_id {
+            second_token_id.clone()
+        } else if payment.token_identifier == second_token_id {
+            first_token_id.clone()
+        } else {
+            sc_panic!("Invalid payment token");
+        };
+
+        let swap_amount = self.get_zap_in_swap_amount(
+            pair_address.clone(),
+            payment.token_identifier.clone(),
+            payment.amount.clone(),
+        );
+        require!(
+            swap_amount > 0 && swap_amount < payment.amount,
+            "Invalid amount to swap"
+        );
+
+        let remaining_payment = EsdtTokenPayment::new(
+            payment.token_identifier.clone(),
+            0,
+            &payment.amount - &swap_amount,
+        );
+        let swapped_payment = self.actual_swap_fixed_input(
+            pair_address.clone(),
+            payment.token_identifier,
+            swap_amount,
+            other_token_id,
+            BigUint::from(1u32),
+            None,
+        );
+
+        let mut add_liq_payments = ManagedVec::new();
+        if remaining_payment.token_identifier == first_token_id {
+            add_liq_payments.push(remaining_payment);
+            add_liq_payments.push(swapped_payment);
+        } else {
+            add_liq_payments.push(swapped_payment);
+            add_liq_payments.push(remaining_payment);
+        }
+
+        let add_liq_result: MultiValue3<EsdtTokenPayment, EsdtTokenPayment, EsdtTokenPayment> =
+            self.pair_contract_proxy(pair_address)
+                .add_liquidity(BigUint::from(1u32), BigUint::from(1u32))
+                .with_multi_token_transfer(add_liq_payments.clone())
+                .execute_on_dest_context();
+        let (lp_payment, first_used_payment, second_used_payment) = add_liq_result.into_tuple();
+        require!(lp_payment.amount >= min_lp_amount, "Slippage exceeded");
+
+        let first_dust = add_liq_payments.get(0).amount - first_used_payment.amount;
+        let second_dust = add_liq_payments.get(1).amount - second_used_payment.amount;
+
+        let mut output_payments = ManagedVec::new();
+        output_payments.push(lp_payment);
+        output_payments.push(EsdtTokenPayment::new(first_token_id, 0, first_dust));
+        output_payments.push(EsdtTokenPayment::new(second_token_id, 0, second_dust));
+
+        output_payments
+    }
+
+    fn swap_through_route(
+        &self,
+        payment: EsdtTokenPayment,
+        swap_route: MultiValueEncoded<SwapRouteStepType<Self::Api>>,
+    ) -> EsdtTokenPayment {
+        let mut last_payment = payment;
+        for step in swap_route {
+            let (pair_address, token_wanted) = step.into_tuple();
+            self.check_is_pair_sc(&pair_address);
+
+            last_payment = self.actual_swap_fixed_input(
+                pair_address,
+                last_payment.token_identifier,
+                last_payment.amount,
+                token_wanted,
+                BigUint::from(1u32),
+                None,
+            );
+        }
+
+        last_payment
+    }
+}
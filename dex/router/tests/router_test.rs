@@ -2,27 +2,41 @@
 
 mod router_setup;
 use multiversx_sc::{
-    codec::multi_types::OptionalValue,
+    codec::multi_types::{MultiValue2, MultiValue4, OptionalValue},
     storage::mappers::StorageTokenWrapper,
     types::{
-        Address, EgldOrEsdtTokenIdentifier, EsdtLocalRole, ManagedAddress, ManagedVec,
-        MultiValueEncoded,
+        Address, EgldOrEsdtTokenIdentifier, EsdtLocalRole, EsdtTokenPayment, ManagedAddress,
+        ManagedVec, MultiValueEncoded,
     },
 };
 use pair::{
-    config::ConfigModule as PairConfigModule, pair_actions::initial_liq::InitialLiquidityModule,
+    config::ConfigModule as PairConfigModule,
+    fee::FeeModule,
+    integrator_fee::IntegratorFeeModule,
+    pair_actions::{
+        add_liq::AddLiquidityModule, initial_liq::InitialLiquidityModule, swap::SwapModule,
+    },
     Pair,
 };
 use pausable::{PausableModule, State};
+use permissions_module::{Permissions, PermissionsModule};
 use router::{
-    config::ConfigModule, enable_swap_by_user::EnableSwapByUserModule, factory::PairTokens,
-    multi_pair_swap::SWAP_TOKENS_FIXED_INPUT_FUNC_NAME, Router,
+    config::ConfigModule,
+    enable_swap_by_user::EnableSwapByUserModule,
+    factory::{FactoryModule, PairTokens},
+    fee_tier::FeeTierModule,
+    integrator::IntegratorModule,
+    multi_pair_swap::{MultiPairSwap, SWAP_TOKENS_FIXED_INPUT_FUNC_NAME},
+    pair_lifecycle::{PairLifecycleModule, PairLifecycleState},
+    zap::ZapModule,
+    Router,
 };
 use router_setup::*;
 
 use multiversx_sc_scenario::{
-    managed_address, managed_biguint, managed_token_id, managed_token_id_wrapped, rust_biguint,
-    whitebox_legacy::BlockchainStateWrapper, whitebox_legacy::TxTokenTransfer, DebugApi,
+    managed_address, managed_biguint, managed_buffer, managed_token_id, managed_token_id_wrapped,
+    rust_biguint, whitebox_legacy::BlockchainStateWrapper, whitebox_legacy::TxTokenTransfer,
+    DebugApi,
 };
 use simple_lock::{
     locked_token::{LockedTokenAttributes, LockedTokenModule},
@@ -558,3 +572,962 @@ fn user_enable_pair_swaps_fail_test() {
         }),
     );
 }
+
+const SUCCESSOR_LP_TOKEN_ID: &[u8] = b"LPMEXV2-abcdef";
+
+// LP tokens received when adding liquidity in the setup
+const USER_LP_AMOUNT: u64 = 1_000_000;
+const LP_AMOUNT_FROM_WEGLD: u64 = 48_735;
+const MEX_AMOUNT_FROM_ZAP_OUT: u64 = 90_659;
+
+#[test]
+fn zap_in_and_zap_out_test() {
+    let mut router_setup = RouterSetup::new(router::contract_obj, pair::contract_obj);
+    router_setup.add_liquidity();
+
+    let user_address = router_setup.user_address.clone();
+    let mex_pair_address = router_setup.mex_pair_wrapper.address_ref().clone();
+
+    // zap in with WEGLD, one of the pair's tokens
+    router_setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &user_address,
+            &router_setup.router_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(100_000),
+            |sc| {
+                let output_payments = sc.zap_in(
+                    managed_address!(&mex_pair_address),
+                    managed_biguint!(45_000),
+                    MultiValueEncoded::new(),
+                );
+
+                assert_eq!(output_payments.len(), 3);
+                let lp_payment = output_payments.get(0);
+                assert_eq!(
+                    lp_payment.token_identifier,
+                    managed_token_id!(LPMEX_TOKEN_ID)
+                );
+                assert_eq!(lp_payment.amount, managed_biguint!(LP_AMOUNT_FROM_WEGLD));
+
+                // only dust is left from adding the liquidity
+                assert_eq!(
+                    output_payments.get(1).token_identifier,
+                    managed_token_id!(WEGLD_TOKEN_ID)
+                );
+                assert!(output_payments.get(1).amount <= managed_biguint!(10));
+                assert_eq!(output_payments.get(2).amount, managed_biguint!(0));
+            },
+        )
+        .assert_ok();
+
+    router_setup.blockchain_wrapper.check_esdt_balance(
+        &user_address,
+        LPMEX_TOKEN_ID,
+        &rust_biguint!(USER_LP_AMOUNT + LP_AMOUNT_FROM_WEGLD),
+    );
+    router_setup.blockchain_wrapper.check_esdt_balance(
+        router_setup.router_wrapper.address_ref(),
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+
+    // zap back out to MEX
+    router_setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &user_address,
+            &router_setup.router_wrapper,
+            LPMEX_TOKEN_ID,
+            0,
+            &rust_biguint!(LP_AMOUNT_FROM_WEGLD),
+            |sc| {
+                let output_payment = sc.zap_out(
+                    managed_address!(&mex_pair_address),
+                    managed_token_id!(MEX_TOKEN_ID),
+                    managed_biguint!(1),
+                    MultiValueEncoded::new(),
+                );
+
+                assert_eq!(
+                    output_payment.token_identifier,
+                    managed_token_id!(MEX_TOKEN_ID)
+                );
+                assert_eq!(
+                    output_payment.amount,
+                    managed_biguint!(MEX_AMOUNT_FROM_ZAP_OUT)
+                );
+            },
+        )
+        .assert_ok();
+
+    router_setup.blockchain_wrapper.check_esdt_balance(
+        &user_address,
+        LPMEX_TOKEN_ID,
+        &rust_biguint!(USER_LP_AMOUNT),
+    );
+    router_setup.blockchain_wrapper.check_esdt_balance(
+        &user_address,
+        MEX_TOKEN_ID,
+        &rust_biguint!(5_000_000_000u64 + MEX_AMOUNT_FROM_ZAP_OUT),
+    );
+
+    // slippage
+    router_setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &user_address,
+            &router_setup.router_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(100_000),
+            |sc| {
+                let _ = sc.zap_in(
+                    managed_address!(&mex_pair_address),
+                    managed_biguint!(100_000),
+                    MultiValueEncoded::new(),
+                );
+            },
+        )
+        .assert_user_error("Slippage exceeded");
+}
+
+#[test]
+fn zap_in_through_route_test() {
+    let mut router_setup = RouterSetup::new(router::contract_obj, pair::contract_obj);
+    router_setup.add_liquidity();
+
+    let user_address = router_setup.user_address.clone();
+    let mex_pair_address = router_setup.mex_pair_wrapper.address_ref().clone();
+    let usdc_pair_address = router_setup.usdc_pair_wrapper.address_ref().clone();
+
+    // USDC is swapped to WEGLD first, which is then used for the MEX pair
+    router_setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &user_address,
+            &router_setup.router_wrapper,
+            USDC_TOKEN_ID,
+            0,
+            &rust_biguint!(100_000),
+            |sc| {
+                let mut swap_route = MultiValueEncoded::new();
+                swap_route.push(MultiValue2::from((
+                    managed_address!(&usdc_pair_address),
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                )));
+
+                let output_payments = sc.zap_in(
+                    managed_address!(&mex_pair_address),
+                    managed_biguint!(1),
+                    swap_route,
+                );
+
+                let lp_payment = output_payments.get(0);
+                assert_eq!(
+                    lp_payment.token_identifier,
+                    managed_token_id!(LPMEX_TOKEN_ID)
+                );
+                assert!(lp_payment.amount > managed_biguint!(0));
+            },
+        )
+        .assert_ok();
+
+    router_setup.blockchain_wrapper.check_esdt_balance(
+        &user_address,
+        USDC_TOKEN_ID,
+        &rust_biguint!(5_000_000_000u64 - 100_000),
+    );
+
+    // the payment token must end up as one of the pair's tokens
+    router_setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &user_address,
+            &router_setup.router_wrapper,
+            USDC_TOKEN_ID,
+            0,
+            &rust_biguint!(100_000),
+            |sc| {
+                let _ = sc.zap_in(
+                    managed_address!(&mex_pair_address),
+                    managed_biguint!(1),
+                    MultiValueEncoded::new(),
+                );
+            },
+        )
+        .assert_user_error("Invalid payment token");
+}
+
+#[test]
+fn pair_lifecycle_test() {
+    let mut router_setup = RouterSetup::new(router::contract_obj, pair::contract_obj);
+    router_setup.add_liquidity();
+
+    let rust_zero = rust_biguint!(0u64);
+    let owner_address = router_setup.owner_address.clone();
+    let user_address = router_setup.user_address.clone();
+    let router_address = router_setup.router_wrapper.address_ref().clone();
+    let mex_pair_address = router_setup.mex_pair_wrapper.address_ref().clone();
+    let usdc_pair_address = router_setup.usdc_pair_wrapper.address_ref().clone();
+
+    // successor pair, with the tokens in reverse order
+    let successor_pair_wrapper = router_setup.blockchain_wrapper.create_sc_account(
+        &rust_zero,
+        Some(&owner_address),
+        pair::contract_obj,
+        PAIR_WASM_PATH,
+    );
+    let successor_pair_address = successor_pair_wrapper.address_ref().clone();
+    router_setup
+        .blockchain_wrapper
+        .execute_tx(&owner_address, &successor_pair_wrapper, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(MEX_TOKEN_ID),
+                managed_token_id!(WEGLD_TOKEN_ID),
+                managed_address!(&router_address),
+                managed_address!(&owner_address),
+                100u64,
+                50u64,
+                ManagedAddress::<DebugApi>::zero(),
+                MultiValueEncoded::<DebugApi, ManagedAddress<DebugApi>>::new(),
+            );
+            sc.lp_token_identifier()
+                .set(&managed_token_id!(SUCCESSOR_LP_TOKEN_ID));
+            sc.state().set(State::Active);
+        })
+        .assert_ok();
+    router_setup.blockchain_wrapper.set_esdt_local_roles(
+        &successor_pair_address,
+        SUCCESSOR_LP_TOKEN_ID,
+        &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+    );
+
+    // the router manages the old pair as well
+    router_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &router_setup.mex_pair_wrapper,
+            &rust_zero,
+            |sc| {
+                sc.add_permissions(managed_address!(&router_address), Permissions::OWNER);
+            },
+        )
+        .assert_ok();
+    router_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &router_setup.router_wrapper,
+            &rust_zero,
+            |sc| {
+                sc.pair_map().insert(
+                    PairTokens {
+                        first_token_id: managed_token_id!(MEX_TOKEN_ID),
+                        second_token_id: managed_token_id!(WEGLD_TOKEN_ID),
+                    },
+                    managed_address!(&successor_pair_address),
+                );
+            },
+        )
+        .assert_ok();
+
+    // liquidity can only be migrated from deprecated pairs
+    router_setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &user_address,
+            &router_setup.router_wrapper,
+            LPMEX_TOKEN_ID,
+            0,
+            &rust_biguint!(500_000),
+            |sc| {
+                let _ = sc.migrate_liquidity(
+                    managed_address!(&mex_pair_address),
+                    managed_biguint!(1),
+                    managed_biguint!(1),
+                );
+            },
+        )
+        .assert_user_error("Pair not deprecated");
+
+    router_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &router_setup.router_wrapper,
+            &rust_zero,
+            |sc| {
+                sc.deprecate_pair(
+                    managed_address!(&mex_pair_address),
+                    managed_address!(&usdc_pair_address),
+                );
+            },
+        )
+        .assert_user_error("Successor pair has different tokens");
+
+    router_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &router_setup.router_wrapper,
+            &rust_zero,
+            |sc| {
+                sc.deprecate_pair(
+                    managed_address!(&mex_pair_address),
+                    managed_address!(&successor_pair_address),
+                );
+
+                assert_eq!(
+                    sc.pair_lifecycle_state(&managed_address!(&mex_pair_address))
+                        .get(),
+                    PairLifecycleState::Deprecated
+                );
+                assert_eq!(
+                    sc.pair_lifecycle_state(&managed_address!(&successor_pair_address))
+                        .get(),
+                    PairLifecycleState::Active
+                );
+                assert_eq!(
+                    sc.pair_successor(&managed_address!(&mex_pair_address))
+                        .get(),
+                    managed_address!(&successor_pair_address)
+                );
+            },
+        )
+        .assert_ok();
+
+    // new liquidity is blocked in the deprecated pair
+    let payments = vec![
+        TxTokenTransfer {
+            token_identifier: WEGLD_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: rust_biguint!(ADD_LIQUIDITY_TOKENS),
+        },
+        TxTokenTransfer {
+            token_identifier: MEX_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: rust_biguint!(ADD_LIQUIDITY_TOKENS),
+        },
+    ];
+    router_setup
+        .blockchain_wrapper
+        .execute_esdt_multi_transfer(
+            &user_address,
+            &router_setup.mex_pair_wrapper,
+            &payments,
+            |sc| {
+                sc.add_liquidity(managed_biguint!(1), managed_biguint!(1));
+            },
+        )
+        .assert_user_error("Adding liquidity is disabled");
+
+    // move half of the liquidity to the successor pair
+    router_setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &user_address,
+            &router_setup.router_wrapper,
+            LPMEX_TOKEN_ID,
+            0,
+            &rust_biguint!(500_000),
+            |sc| {
+                let output_payments = sc.migrate_liquidity(
+                    managed_address!(&mex_pair_address),
+                    managed_biguint!(500_000),
+                    managed_biguint!(500_000),
+                );
+
+                assert_eq!(output_payments.len(), 3);
+                let lp_payment = output_payments.get(0);
+                assert_eq!(
+                    lp_payment.token_identifier,
+                    managed_token_id!(SUCCESSOR_LP_TOKEN_ID)
+                );
+                assert_eq!(lp_payment.amount, managed_biguint!(499_000));
+                assert_eq!(
+                    output_payments.get(1).token_identifier,
+                    managed_token_id!(MEX_TOKEN_ID)
+                );
+                assert_eq!(output_payments.get(1).amount, managed_biguint!(0));
+                assert_eq!(output_payments.get(2).amount, managed_biguint!(0));
+            },
+        )
+        .assert_ok();
+
+    router_setup.blockchain_wrapper.check_esdt_balance(
+        &user_address,
+        SUCCESSOR_LP_TOKEN_ID,
+        &rust_biguint!(499_000),
+    );
+    router_setup.blockchain_wrapper.check_esdt_balance(
+        &user_address,
+        LPMEX_TOKEN_ID,
+        &rust_biguint!(USER_LP_AMOUNT - 500_000),
+    );
+    router_setup.blockchain_wrapper.check_esdt_balance(
+        &successor_pair_address,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(500_000),
+    );
+    router_setup.blockchain_wrapper.check_esdt_balance(
+        &successor_pair_address,
+        MEX_TOKEN_ID,
+        &rust_biguint!(500_000),
+    );
+
+    // sunset the old pair
+    router_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &router_setup.router_wrapper,
+            &rust_zero,
+            |sc| {
+                sc.set_pair_withdraw_only(managed_address!(&mex_pair_address));
+
+                assert_eq!(
+                    sc.pair_lifecycle_state(&managed_address!(&mex_pair_address))
+                        .get(),
+                    PairLifecycleState::WithdrawOnly
+                );
+            },
+        )
+        .assert_ok();
+
+    router_setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &user_address,
+            &router_setup.mex_pair_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let _ = sc.swap_tokens_fixed_input(
+                    managed_token_id!(MEX_TOKEN_ID),
+                    managed_biguint!(1),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Swap is not enabled");
+
+    // the remaining liquidity can still be migrated
+    router_setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &user_address,
+            &router_setup.router_wrapper,
+            LPMEX_TOKEN_ID,
+            0,
+            &rust_biguint!(USER_LP_AMOUNT - 500_000),
+            |sc| {
+                let _ = sc.migrate_liquidity(
+                    managed_address!(&mex_pair_address),
+                    managed_biguint!(1),
+                    managed_biguint!(1),
+                );
+            },
+        )
+        .assert_ok();
+
+    router_setup
+        .blockchain_wrapper
+        .check_esdt_balance(&user_address, LPMEX_TOKEN_ID, &rust_zero);
+    router_setup.blockchain_wrapper.check_esdt_balance(
+        &user_address,
+        SUCCESSOR_LP_TOKEN_ID,
+        &rust_biguint!(999_000),
+    );
+}
+
+#[test]
+fn fee_tiers_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let user = b_mock.create_user_account(&rust_zero);
+
+    let router_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        router::contract_obj,
+        ROUTER_WASM_PATH,
+    );
+    let pair_template_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(router_wrapper.address_ref()),
+        pair::contract_obj,
+        PAIR_WASM_PATH,
+    );
+
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.init(OptionalValue::Some(managed_address!(
+                pair_template_wrapper.address_ref()
+            )));
+            sc.set_pair_creation_enabled(true);
+        })
+        .assert_ok();
+
+    // default pool, with 0.3% fee
+    let default_pair_wrapper =
+        b_mock.prepare_deploy_from_sc(router_wrapper.address_ref(), pair::contract_obj);
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_zero, |sc| {
+            let _ = sc.create_pair_endpoint(
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_token_id!(USDC_TOKEN_ID),
+                managed_address!(&user),
+                OptionalValue::None,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_zero, |sc| {
+            let _ = sc.create_pair_with_fee_tier(
+                managed_token_id!(USDC_TOKEN_ID),
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_address!(&user),
+                100,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_user_error("Unknown fee tier");
+
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.add_fee_tier(100, 50);
+            sc.add_fee_tier(300, 50);
+        })
+        .assert_ok();
+
+    // the default pool already has the 0.3% fee
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_zero, |sc| {
+            let _ = sc.create_pair_with_fee_tier(
+                managed_token_id!(USDC_TOKEN_ID),
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_address!(&user),
+                300,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_user_error("Pair already exists");
+
+    // 0.1% fee pool, tokens given in reverse order
+    let low_fee_pair_wrapper =
+        b_mock.prepare_deploy_from_sc(router_wrapper.address_ref(), pair::contract_obj);
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_zero, |sc| {
+            let _ = sc.create_pair_with_fee_tier(
+                managed_token_id!(USDC_TOKEN_ID),
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_address!(&user),
+                100,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(&low_fee_pair_wrapper, |sc| {
+            assert_eq!(sc.total_fee_percent().get(), 100);
+            assert_eq!(sc.special_fee_percent().get(), 50);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(&router_wrapper, |sc| {
+            let default_pair_address = managed_address!(default_pair_wrapper.address_ref());
+            let low_fee_pair_address = managed_address!(low_fee_pair_wrapper.address_ref());
+
+            assert_eq!(
+                sc.get_pair(
+                    managed_token_id!(USDC_TOKEN_ID),
+                    managed_token_id!(CUSTOM_TOKEN_ID)
+                ),
+                default_pair_address
+            );
+            assert_eq!(
+                sc.get_pair_by_fee_tier(
+                    managed_token_id!(USDC_TOKEN_ID),
+                    managed_token_id!(CUSTOM_TOKEN_ID),
+                    100
+                ),
+                low_fee_pair_address
+            );
+            assert_eq!(
+                sc.get_pair_by_fee_tier(
+                    managed_token_id!(CUSTOM_TOKEN_ID),
+                    managed_token_id!(USDC_TOKEN_ID),
+                    300
+                ),
+                default_pair_address
+            );
+            assert!(sc
+                .get_pair_by_fee_tier(
+                    managed_token_id!(CUSTOM_TOKEN_ID),
+                    managed_token_id!(USDC_TOKEN_ID),
+                    1_000
+                )
+                .is_zero());
+
+            let fee_tiers: Vec<_> = sc
+                .get_pair_fee_tiers(
+                    managed_token_id!(USDC_TOKEN_ID),
+                    managed_token_id!(CUSTOM_TOKEN_ID),
+                )
+                .into_iter()
+                .map(|fee_tier| fee_tier.into_tuple())
+                .collect();
+            assert_eq!(
+                fee_tiers,
+                vec![
+                    (300, default_pair_address.clone()),
+                    (100, low_fee_pair_address.clone())
+                ]
+            );
+
+            // both pools can be used for routing
+            sc.check_is_pair_sc(&default_pair_address);
+            sc.check_is_pair_sc(&low_fee_pair_address);
+            assert_eq!(sc.get_all_pairs_addresses().len(), 2);
+        })
+        .assert_ok();
+
+    // changing the fees of a pool doesn't move it to another tier
+    b_mock
+        .execute_tx(&owner, &default_pair_wrapper, &rust_zero, |sc| {
+            sc.set_fee_percent(100, 50);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(&router_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pair_by_fee_tier(
+                    managed_token_id!(USDC_TOKEN_ID),
+                    managed_token_id!(CUSTOM_TOKEN_ID),
+                    300
+                ),
+                managed_address!(default_pair_wrapper.address_ref())
+            );
+            assert_eq!(
+                sc.get_pair_by_fee_tier(
+                    managed_token_id!(USDC_TOKEN_ID),
+                    managed_token_id!(CUSTOM_TOKEN_ID),
+                    100
+                ),
+                managed_address!(low_fee_pair_wrapper.address_ref())
+            );
+        })
+        .assert_ok();
+
+    // 1% fee pool
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.add_fee_tier(1_000, 50);
+        })
+        .assert_ok();
+
+    let high_fee_pair_wrapper =
+        b_mock.prepare_deploy_from_sc(router_wrapper.address_ref(), pair::contract_obj);
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_zero, |sc| {
+            let _ = sc.create_pair_with_fee_tier(
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_token_id!(USDC_TOKEN_ID),
+                managed_address!(&user),
+                1_000,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            let high_fee_pair_address = managed_address!(high_fee_pair_wrapper.address_ref());
+            let removed_pair_address = sc.remove_pair_fee_tier(
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_token_id!(USDC_TOKEN_ID),
+                1_000,
+            );
+            assert_eq!(removed_pair_address, high_fee_pair_address);
+            assert!(sc.pair_fee_tier(&high_fee_pair_address).is_empty());
+            assert_eq!(sc.get_all_pairs_addresses().len(), 2);
+
+            sc.pair_lifecycle_state(&managed_address!(low_fee_pair_wrapper.address_ref()))
+                .set(PairLifecycleState::Deprecated);
+        })
+        .assert_ok();
+
+    // removing the default pool removes the other tier pools as well
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            let removed_pair_address = sc.remove_pair(
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_token_id!(USDC_TOKEN_ID),
+            );
+            assert_eq!(
+                removed_pair_address,
+                managed_address!(default_pair_wrapper.address_ref())
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(&router_wrapper, |sc| {
+            assert!(sc
+                .get_pair_by_fee_tier(
+                    managed_token_id!(USDC_TOKEN_ID),
+                    managed_token_id!(CUSTOM_TOKEN_ID),
+                    100
+                )
+                .is_zero());
+            assert_eq!(sc.get_all_pairs_addresses().len(), 0);
+
+            for pair_address in [
+                managed_address!(default_pair_wrapper.address_ref()),
+                managed_address!(low_fee_pair_wrapper.address_ref()),
+            ] {
+                assert!(sc.pair_fee_tier(&pair_address).is_empty());
+                assert!(sc.pair_lifecycle_state(&pair_address).is_empty());
+            }
+        })
+        .assert_ok();
+}
+
+#[test]
+fn integrator_fee_test() {
+    let mut router_setup = RouterSetup::new(router::contract_obj, pair::contract_obj);
+    router_setup.add_liquidity();
+
+    let rust_zero = rust_biguint!(0u64);
+    let owner_address = router_setup.owner_address.clone();
+    let user_address = router_setup.user_address.clone();
+    let integrator_address = router_setup
+        .blockchain_wrapper
+        .create_user_account(&rust_zero);
+    let router_address = router_setup.router_wrapper.address_ref().clone();
+    let mex_pair_address = router_setup.mex_pair_wrapper.address_ref().clone();
+
+    // 50% of the special fee goes to the integrator
+    router_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &router_setup.router_wrapper,
+            &rust_zero,
+            |sc| {
+                sc.set_integrator(managed_address!(&integrator_address), 50_000);
+            },
+        )
+        .assert_ok();
+
+    // the pair reads the integrator registry of its router, and burns the rest of the special fee
+    router_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &router_setup.mex_pair_wrapper,
+            &rust_zero,
+            |sc| {
+                sc.router_address().set(managed_address!(&router_address));
+                sc.set_fee_on(
+                    true,
+                    managed_address!(&owner_address),
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                );
+            },
+        )
+        .assert_ok();
+    router_setup.blockchain_wrapper.set_esdt_local_roles(
+        &mex_pair_address,
+        WEGLD_TOKEN_ID,
+        &[EsdtLocalRole::Burn],
+    );
+
+    let swap_amount = 100_000u64;
+    let special_fee = swap_amount * 50 / 100_000;
+    let integrator_fee = special_fee / 2;
+    for _ in 0..2 {
+        router_setup
+            .blockchain_wrapper
+            .execute_esdt_transfer(
+                &user_address,
+                &router_setup.router_wrapper,
+                WEGLD_TOKEN_ID,
+                0,
+                &rust_biguint!(swap_amount),
+                |sc| {
+                    let mut swap_operations = MultiValueEncoded::new();
+                    swap_operations.push(MultiValue4::from((
+                        managed_address!(&mex_pair_address),
+                        managed_buffer!(SWAP_TOKENS_FIXED_INPUT_FUNC_NAME),
+                        managed_token_id!(MEX_TOKEN_ID),
+                        managed_biguint!(1),
+                    )));
+                    let _ = sc.multi_pair_swap_with_integrator(
+                        managed_address!(&integrator_address),
+                        swap_operations,
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    // swaps without an approved integrator are not credited
+    router_setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &user_address,
+            &router_setup.mex_pair_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(swap_amount),
+            |sc| {
+                let _ = sc.swap_tokens_fixed_input(
+                    managed_token_id!(MEX_TOKEN_ID),
+                    managed_biguint!(1),
+                    OptionalValue::Some(managed_address!(&user_address)),
+                );
+            },
+        )
+        .assert_ok();
+
+    router_setup
+        .blockchain_wrapper
+        .execute_query(&router_setup.mex_pair_wrapper, |sc| {
+            let claimable_fees: Vec<_> = sc
+                .get_integrator_claimable_fees(managed_address!(&integrator_address))
+                .into_iter()
+                .collect();
+            assert_eq!(
+                claimable_fees,
+                vec![EsdtTokenPayment::new(
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    0,
+                    managed_biguint!(2 * integrator_fee)
+                )]
+            );
+
+            let stats: Vec<_> = sc
+                .get_integrator_stats(managed_address!(&integrator_address))
+                .into_iter()
+                .map(|stats| stats.into_tuple())
+                .collect();
+            assert_eq!(
+                stats,
+                vec![
+                    (
+                        managed_token_id!(WEGLD_TOKEN_ID),
+                        managed_biguint!(2 * swap_amount),
+                        managed_biguint!(2 * integrator_fee)
+                    ),
+                    (
+                        managed_token_id!(MEX_TOKEN_ID),
+                        managed_biguint!(0),
+                        managed_biguint!(0)
+                    ),
+                ]
+            );
+
+            assert_eq!(
+                sc.get_integrator_claimable_fees(managed_address!(&user_address))
+                    .len(),
+                0
+            );
+        })
+        .assert_ok();
+
+    router_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &integrator_address,
+            &router_setup.mex_pair_wrapper,
+            &rust_zero,
+            |sc| {
+                let _ = sc.claim_integrator_fees();
+            },
+        )
+        .assert_ok();
+    router_setup.blockchain_wrapper.check_esdt_balance(
+        &integrator_address,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(2 * integrator_fee),
+    );
+
+    router_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &router_setup.router_wrapper,
+            &rust_zero,
+            |sc| {
+                sc.set_integrator(managed_address!(&integrator_address), 50_001);
+            },
+        )
+        .assert_user_error("Invalid fee share percentage");
+    router_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &router_setup.router_wrapper,
+            &rust_zero,
+            |sc| {
+                sc.remove_integrator(managed_address!(&integrator_address));
+                assert_eq!(sc.get_integrators().len(), 0);
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn paginated_pairs_test() {
+    let mut router_setup = RouterSetup::new(router::contract_obj, pair::contract_obj);
+    let mex_pair_address = router_setup.mex_pair_wrapper.address_ref().clone();
+    let usdc_pair_address = router_setup.usdc_pair_wrapper.address_ref().clone();
+
+    router_setup
+        .blockchain_wrapper
+        .execute_query(&router_setup.router_wrapper, |sc| {
+            let (opt_next_pair, first_page) = sc
+                .get_pairs_addresses_paginated(1, OptionalValue::None)
+                .into_tuple();
+            let next_pair = opt_next_pair.unwrap();
+            assert_eq!(first_page.to_vec().len(), 1);
+            assert_eq!(
+                first_page.to_vec().get(0).clone_value(),
+                managed_address!(&mex_pair_address)
+            );
+
+            let (opt_next_pair, second_page) = sc
+                .get_pairs_addresses_paginated(1, OptionalValue::Some(next_pair.clone()))
+                .into_tuple();
+            assert!(opt_next_pair.is_none());
+            assert_eq!(
+                second_page.to_vec().get(0).clone_value(),
+                managed_address!(&usdc_pair_address)
+            );
+
+            let (opt_next_pair, empty_page) = sc
+                .get_pairs_addresses_paginated(0, OptionalValue::Some(next_pair.clone()))
+                .into_tuple();
+            assert!(opt_next_pair == Some(next_pair));
+            assert!(empty_page.is_empty());
+
+            let (opt_next_pair, all_metadata) = sc
+                .get_pair_contract_metadata_paginated(10, OptionalValue::None)
+                .into_tuple();
+            assert!(opt_next_pair.is_none());
+            assert_eq!(all_metadata.to_vec().len(), 2);
+        })
+        .assert_ok();
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           29
+// Endpoints:                           52
 // Async Callback:                       1
-// Total number of exported functions:  32
+// Total number of exported functions:  55
 
 #![no_std]
 
@@ -41,14 +41,37 @@ multiversx_sc_wasm_adapter::endpoints! {
         getAllPairsManagedAddresses => get_all_pairs_addresses
         getAllPairTokens => get_all_token_pairs
         getAllPairContractMetadata => get_all_pair_contract_metadata
+        getPairsManagedAddressesPaginated => get_pairs_addresses_paginated
+        getPairContractMetadataPaginated => get_pair_contract_metadata_paginated
         getPair => get_pair
         clearPairTemporaryOwnerStorage => clear_pair_temporary_owner_storage
         multiPairSwap => multi_pair_swap
+        multiPairSwapWithIntegrator => multi_pair_swap_with_integrator
         configEnableByUserParameters => config_enable_by_user_parameters
         addCommonTokensForUserPairs => add_common_tokens_for_user_pairs
         removeCommonTokensForUserPairs => remove_common_tokens_for_user_pairs
         setSwapEnabledByUser => set_swap_enabled_by_user
         getEnableSwapByUserConfig => try_get_config
+        zapIn => zap_in
+        zapInAndEnterFarm => zap_in_and_enter_farm
+        zapOut => zap_out
+        getZapInSwapAmount => get_zap_in_swap_amount
+        deprecatePair => deprecate_pair
+        setPairWithdrawOnly => set_pair_withdraw_only
+        migrateLiquidity => migrate_liquidity
+        getPairLifecycleState => pair_lifecycle_state
+        getPairSuccessor => pair_successor
+        addFeeTier => add_fee_tier
+        removeFeeTier => remove_fee_tier
+        createPairWithFeeTier => create_pair_with_fee_tier
+        removePairFeeTier => remove_pair_fee_tier
+        getPairByFeeTier => get_pair_by_fee_tier
+        getPairFeeTiers => get_pair_fee_tiers
+        getFeeTiers => get_fee_tiers
+        setIntegrator => set_integrator
+        removeIntegrator => remove_integrator
+        getIntegrators => get_integrators
+        getIntegratorFeeSharePercentage => integrator_fee_share_percentage
     )
 }
 
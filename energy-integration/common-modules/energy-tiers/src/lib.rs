@@ -0,0 +1,146 @@
+#![no_std]
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use week_timekeeping::{Week, EPOCHS_IN_WEEK};
+
+pub type EnergyTier = usize;
+
+pub const MAX_ENERGY_TIERS: usize = 10;
+static ENERGY_TIER_TOO_LOW_ERR_MSG: &[u8] = b"Energy tier too low";
+static NOT_ENOUGH_ENERGY_ERR_MSG: &[u8] = b"Not enough energy";
+
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug)]
+pub struct CachedEnergyTier {
+    pub week: Week,
+    pub tiers_version: u64,
+    pub tier: EnergyTier,
+}
+
+/// Gates endpoints by the caller's energy, either through a minimum energy or through tiers.
+///
+/// Tier 0 is for users below the first threshold, and tier N for users with at least the N-th threshold.
+/// The tier of each user is cached for the rest of the week,
+/// so a user whose energy grew in the meantime has to call `refreshEnergyTier`.
+#[multiversx_sc::module]
+pub trait EnergyTiersModule: energy_query::EnergyQueryModule {
+    /// The minimum energy for each tier, in increasing order.
+    /// Invalidates the cached tiers of all users.
+    #[only_owner]
+    #[endpoint(setEnergyTiers)]
+    fn set_energy_tiers(&self, thresholds: MultiValueEncoded<BigUint>) {
+        require!(thresholds.len() <= MAX_ENERGY_TIERS, "Too many tiers");
+
+        let mut thresholds_mapper = self.energy_tier_thresholds();
+        thresholds_mapper.clear();
+
+        let mut prev_threshold = BigUint::zero();
+        for threshold in thresholds {
+            require!(threshold > prev_threshold, "Invalid tier thresholds");
+
+            thresholds_mapper.push(&threshold);
+            prev_threshold = threshold;
+        }
+
+        self.energy_tiers_version().update(|version| *version += 1);
+    }
+
+    #[endpoint(refreshEnergyTier)]
+    fn refresh_energy_tier(&self) -> EnergyTier {
+        let caller = self.blockchain().get_caller();
+        let tier = self.compute_energy_tier(&caller);
+        self.cache_energy_tier(&caller, tier);
+
+        tier
+    }
+
+    #[view(getUserEnergyTier)]
+    fn get_user_energy_tier_view(&self, user: ManagedAddress) -> EnergyTier {
+        self.get_cached_energy_tier(&user)
+            .unwrap_or_else(|| self.compute_energy_tier(&user))
+    }
+
+    fn require_energy_tier(&self, user: &ManagedAddress, min_tier: EnergyTier) {
+        require!(
+            self.get_user_energy_tier(user) >= min_tier,
+            ENERGY_TIER_TOO_LOW_ERR_MSG
+        );
+    }
+
+    fn require_min_energy(&self, user: &ManagedAddress, min_energy: &BigUint) {
+        require!(
+            &self.get_energy_amount(user) >= min_energy,
+            NOT_ENOUGH_ENERGY_ERR_MSG
+        );
+    }
+
+    fn get_user_energy_tier(&self, user: &ManagedAddress) -> EnergyTier {
+        if let Some(tier) = self.get_cached_energy_tier(user) {
+            return tier;
+        }
+
+        let tier = self.compute_energy_tier(user);
+        self.cache_energy_tier(user, tier);
+
+        tier
+    }
+
+    fn compute_energy_tier(&self, user: &ManagedAddress) -> EnergyTier {
+        let thresholds_mapper = self.energy_tier_thresholds();
+        if thresholds_mapper.is_empty() {
+            return 0;
+        }
+
+        let user_energy = self.get_energy_amount(user);
+        let mut tier = 0;
+        for threshold in thresholds_mapper.iter() {
+            if user_energy < threshold {
+                break;
+            }
+
+            tier += 1;
+        }
+
+        tier
+    }
+
+    fn get_cached_energy_tier(&self, user: &ManagedAddress) -> Option<EnergyTier> {
+        let cache_mapper = self.user_energy_tier_cache(user);
+        if cache_mapper.is_empty() {
+            return None;
+        }
+
+        let cached_tier = cache_mapper.get();
+        if cached_tier.week != self.get_energy_tier_week()
+            || cached_tier.tiers_version != self.energy_tiers_version().get()
+        {
+            return None;
+        }
+
+        Some(cached_tier.tier)
+    }
+
+    fn cache_energy_tier(&self, user: &ManagedAddress, tier: EnergyTier) {
+        self.user_energy_tier_cache(user).set(CachedEnergyTier {
+            week: self.get_energy_tier_week(),
+            tiers_version: self.energy_tiers_version().get(),
+            tier,
+        });
+    }
+
+    /// Independent of the contract's own week timekeeping, if any
+    fn get_energy_tier_week(&self) -> Week {
+        (self.blockchain().get_block_epoch() / EPOCHS_IN_WEEK) as Week
+    }
+
+    #[view(getEnergyTierThresholds)]
+    #[storage_mapper("energyTierThresholds")]
+    fn energy_tier_thresholds(&self) -> VecMapper<BigUint>;
+
+    #[storage_mapper("energyTiersVersion")]
+    fn energy_tiers_version(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("userEnergyTierCache")]
+    fn user_energy_tier_cache(&self, user: &ManagedAddress) -> SingleValueMapper<CachedEnergyTier>;
+}
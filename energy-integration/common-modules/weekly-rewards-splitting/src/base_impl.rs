@@ -55,6 +55,17 @@ pub trait WeeklyRewardsSplittingTraitsModule {
         week: Week,
         energy_amount: &BigUint<<Self::WeeklyRewardsSplittingMod as ContractBase>::Api>,
         total_energy: &BigUint<<Self::WeeklyRewardsSplittingMod as ContractBase>::Api>,
+    ) -> PaymentsVec<<Self::WeeklyRewardsSplittingMod as ContractBase>::Api> {
+        self.get_energy_share_of_rewards_for_week(sc, week, energy_amount, total_energy)
+    }
+
+    /// The user's share of the week's rewards, proportional to the user's energy.
+    fn get_energy_share_of_rewards_for_week(
+        &self,
+        sc: &Self::WeeklyRewardsSplittingMod,
+        week: Week,
+        energy_amount: &BigUint<<Self::WeeklyRewardsSplittingMod as ContractBase>::Api>,
+        total_energy: &BigUint<<Self::WeeklyRewardsSplittingMod as ContractBase>::Api>,
     ) -> PaymentsVec<<Self::WeeklyRewardsSplittingMod as ContractBase>::Api> {
         let mut user_rewards = ManagedVec::new();
         if energy_amount == &0 || total_energy == &0 {
@@ -5,9 +5,12 @@ multiversx_sc::imports!();
 use energy_query::Energy;
 
 #[multiversx_sc::contract]
-pub trait EnergyFactoryMock {
+pub trait EnergyFactoryMock: energy_factory::energy_checkpoints::EnergyCheckpointsModule {
     #[init]
-    fn init(&self) {}
+    fn init(&self) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        self.init_total_energy_checkpoints(Energy::new_zero_energy(current_epoch));
+    }
 
     #[endpoint(setUserEnergy)]
     fn set_user_energy(
@@ -17,11 +20,12 @@ pub trait EnergyFactoryMock {
         total_locked_tokens: BigUint,
     ) {
         let current_epoch = self.blockchain().get_block_epoch();
-        self.user_energy(&user).set(&Energy::new(
+        let energy = Energy::new(
             BigInt::from(energy_amount),
             current_epoch,
             total_locked_tokens,
-        ));
+        );
+        self.set_energy_entry(&user, energy);
     }
 
     #[view(getEnergyAmountForUser)]
@@ -49,7 +53,25 @@ pub trait EnergyFactoryMock {
         user: ManagedAddress,
         energy: Energy<Self::Api>,
     ) {
-        self.user_energy(&user).set(&energy);
+        self.set_energy_entry(&user, energy);
+    }
+
+    #[view(getEnergyAmountAtEpoch)]
+    fn get_energy_amount_at_epoch(&self, user: ManagedAddress, epoch: u64) -> BigUint {
+        self.get_checkpointed_energy_amount(&self.user_energy_checkpoints(&user), epoch)
+            .unwrap_or_default()
+    }
+
+    fn set_energy_entry(&self, user: &ManagedAddress, energy: Energy<Self::Api>) {
+        let mapper = self.user_energy(user);
+        let opt_prev_energy = if !mapper.is_empty() {
+            Some(mapper.get())
+        } else {
+            None
+        };
+        self.record_energy_checkpoints(user, opt_prev_energy, &energy);
+
+        mapper.set(&energy);
     }
 
     #[storage_mapper("userEnergy")]
@@ -69,6 +69,39 @@ pub trait ConfigModule {
         self.all_tokens().get().into()
     }
 
+    /// Paginated version of getAllTokens. Starts from the given token, or from the first one,
+    /// and also returns the token to continue from, if any.
+    /// Removing tokens keeps the order of the others, so the tokens are a stable cursor.
+    #[view(getAllTokensPaginated)]
+    fn get_all_tokens_paginated(
+        &self,
+        limit: usize,
+        opt_start_token: OptionalValue<TokenIdentifier>,
+    ) -> pagination::KeyPaginatedResult<Self::Api, TokenIdentifier, TokenIdentifier> {
+        let all_tokens = self.all_tokens().get();
+        let start_index = match opt_start_token {
+            OptionalValue::Some(start_token) => match all_tokens.find(&start_token) {
+                Some(index) => index,
+                None => sc_panic!(pagination::UNKNOWN_START_ITEM_ERR_MSG),
+            },
+            OptionalValue::None => 0,
+        };
+        let page = pagination::get_page(start_index, limit, all_tokens.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in page.indexes.clone() {
+            result.push((*all_tokens.get(index)).clone());
+        }
+
+        let opt_next_token = if page.indexes.end < all_tokens.len() {
+            Some((*all_tokens.get(page.indexes.end)).clone())
+        } else {
+            None
+        };
+
+        (opt_next_token, result).into()
+    }
+
     #[view(getAllKnownContracts)]
     #[storage_mapper("knownContracts")]
     fn known_contracts(&self) -> UnorderedSetMapper<ManagedAddress>;
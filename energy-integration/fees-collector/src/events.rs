@@ -2,6 +2,8 @@ multiversx_sc::imports!();
 
 use common_types::Week;
 
+use crate::fee_split::FeeSplitResult;
+
 #[multiversx_sc::module]
 pub trait FeesCollectorEventsModule {
     fn emit_deposit_swap_fees_event(
@@ -13,6 +15,36 @@ pub trait FeesCollectorEventsModule {
         self.deposit_swap_fees_event(caller, current_week, payment);
     }
 
+    fn emit_convert_fees_event(
+        self,
+        week: Week,
+        input_payment: EsdtTokenPayment<Self::Api>,
+        output_payment: EsdtTokenPayment<Self::Api>,
+    ) {
+        self.convert_fees_event(week, input_payment, output_payment);
+    }
+
+    fn emit_fee_split_event(self, week: Week, split_result: FeeSplitResult<Self::Api>) {
+        self.fee_split_event(week, split_result);
+    }
+
+    fn emit_fee_split_distributed_event(
+        self,
+        week: Week,
+        entry_index: usize,
+        split_result: FeeSplitResult<Self::Api>,
+    ) {
+        self.fee_split_distributed_event(week, entry_index, split_result);
+    }
+
+    fn emit_recover_expired_rewards_event(
+        self,
+        week: Week,
+        expired_rewards: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+    ) {
+        self.recover_expired_rewards_event(week, expired_rewards);
+    }
+
     #[event("deposit_swap_fees_event")]
     fn deposit_swap_fees_event(
         &self,
@@ -20,4 +52,34 @@ pub trait FeesCollectorEventsModule {
         #[indexed] current_week: Week,
         #[indexed] payment: EsdtTokenPayment<Self::Api>,
     );
+
+    #[event("convert_fees_event")]
+    fn convert_fees_event(
+        &self,
+        #[indexed] week: Week,
+        #[indexed] input_payment: EsdtTokenPayment<Self::Api>,
+        #[indexed] output_payment: EsdtTokenPayment<Self::Api>,
+    );
+
+    #[event("fee_split_event")]
+    fn fee_split_event(
+        &self,
+        #[indexed] week: Week,
+        #[indexed] split_result: FeeSplitResult<Self::Api>,
+    );
+
+    #[event("fee_split_distributed_event")]
+    fn fee_split_distributed_event(
+        &self,
+        #[indexed] week: Week,
+        #[indexed] entry_index: usize,
+        #[indexed] split_result: FeeSplitResult<Self::Api>,
+    );
+
+    #[event("recover_expired_rewards_event")]
+    fn recover_expired_rewards_event(
+        &self,
+        #[indexed] week: Week,
+        expired_rewards: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+    );
 }
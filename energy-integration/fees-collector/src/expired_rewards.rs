@@ -0,0 +1,174 @@
+multiversx_sc::imports!();
+
+use common_types::{PaymentsVec, Week};
+use weekly_rewards_splitting::USER_MAX_CLAIM_WEEKS;
+
+pub const MAX_RECOVERED_WEEKS_PER_CALL: Week = 10;
+
+#[multiversx_sc::module]
+pub trait ExpiredRewardsModule:
+    crate::config::ConfigModule
+    + crate::fees_accumulation::FeesAccumulationModule
+    + crate::fee_split::FeeSplitModule
+    + crate::events::FeesCollectorEventsModule
+    + week_timekeeping::WeekTimekeepingModule
+{
+    /// Where the expired rewards are sent. If not set, they are added to the current week's rewards.
+    /// Expired LOCKED token rewards are always added to the current week's rewards.
+    #[only_owner]
+    #[endpoint(setExpiredRewardsDestination)]
+    fn set_expired_rewards_destination(&self, opt_destination: OptionalValue<ManagedAddress>) {
+        match opt_destination {
+            OptionalValue::Some(destination) => {
+                require!(!destination.is_zero(), "Invalid destination");
+                self.expired_rewards_destination().set(destination);
+            }
+            OptionalValue::None => self.expired_rewards_destination().clear(),
+        }
+    }
+
+    /// Recovers the rewards that can no longer be claimed, i.e. the unclaimed rewards
+    /// of the weeks older than the users' max claim weeks.
+    /// At most MAX_RECOVERED_WEEKS_PER_CALL weeks are handled per call, so it might have to be called again.
+    #[endpoint(recoverExpiredRewards)]
+    fn recover_expired_rewards(&self) {
+        let expiry_offset = USER_MAX_CLAIM_WEEKS + 1usize;
+        let current_week = self.get_current_week();
+        require!(
+            current_week > expiry_offset,
+            "Current week must be higher than the week offset"
+        );
+
+        let last_recovered_week_mapper = self.last_expired_rewards_recovery_week();
+        let first_week = last_recovered_week_mapper.get() + 1;
+        let last_week = core::cmp::min(
+            current_week - expiry_offset,
+            first_week + MAX_RECOVERED_WEEKS_PER_CALL - 1,
+        );
+        if first_week > last_week {
+            return;
+        }
+
+        let mut total_expired_rewards = PaymentsVec::new();
+        for week in first_week..=last_week {
+            let mut remaining_rewards = self.remaining_rewards_for_week(week).take();
+            if remaining_rewards.is_empty() {
+                // nobody claimed for this week, so its fees might not have been collected
+                remaining_rewards = self.collect_fees_for_week(week);
+                self.remaining_rewards_for_week(week).clear();
+            }
+
+            // fully claimed rewards are left with a 0 amount
+            let mut expired_rewards = PaymentsVec::new();
+            for payment in &remaining_rewards {
+                if payment.amount > 0 {
+                    expired_rewards.push(payment.clone());
+                    self.add_payment(&mut total_expired_rewards, payment);
+                }
+            }
+            if expired_rewards.is_empty() {
+                continue;
+            }
+
+            self.expired_rewards_for_week(week).set(&expired_rewards);
+            self.emit_recover_expired_rewards_event(week, &expired_rewards);
+        }
+        last_recovered_week_mapper.set(last_week);
+
+        let locked_token_id = self.locked_token_id().get();
+        let destination_mapper = self.expired_rewards_destination();
+        let mut payments_to_send = PaymentsVec::new();
+        for payment in &total_expired_rewards {
+            if destination_mapper.is_empty() || payment.token_identifier == locked_token_id {
+                self.accumulated_fees(current_week, &payment.token_identifier)
+                    .update(|amount| *amount += payment.amount);
+            } else {
+                payments_to_send.push(payment);
+            }
+        }
+
+        if !payments_to_send.is_empty() {
+            self.send()
+                .direct_multi(&destination_mapper.get(), &payments_to_send);
+        }
+    }
+
+    /// Takes the fees accumulated in the given week, after the fee splits.
+    /// Also tracks the part of them that is still to be claimed.
+    fn collect_fees_for_week(&self, week: Week) -> PaymentsVec<Self::Api> {
+        let mut results = ManagedVec::new();
+        let all_tokens = self.all_tokens().get();
+        let locked_token_id = self.locked_token_id().get();
+        for token in &all_tokens {
+            let opt_accumulated_fees = self.get_and_clear_accumulated_fees(week, &token);
+            if let Some(mut accumulated_fees) = opt_accumulated_fees {
+                // locked token fees are burned on deposit, so they can only go to the energy holders
+                if token != locked_token_id {
+                    accumulated_fees = self.split_week_fees(week, &token, accumulated_fees);
+                }
+                if accumulated_fees > 0 {
+                    results.push(EsdtTokenPayment::new(token, 0, accumulated_fees));
+                }
+            }
+        }
+
+        self.remaining_rewards_for_week(week).set(&results);
+
+        results
+    }
+
+    fn deduct_claimed_rewards(&self, week: Week, user_rewards: &PaymentsVec<Self::Api>) {
+        let remaining_rewards_mapper = self.remaining_rewards_for_week(week);
+        if remaining_rewards_mapper.is_empty() {
+            return;
+        }
+
+        let mut remaining_rewards = remaining_rewards_mapper.get();
+        for user_reward in user_rewards {
+            for (i, mut remaining_reward) in remaining_rewards.iter().enumerate() {
+                if remaining_reward.token_identifier != user_reward.token_identifier {
+                    continue;
+                }
+
+                // the energy shares can add up to slightly more than the week's total
+                if remaining_reward.amount >= user_reward.amount {
+                    remaining_reward.amount -= &user_reward.amount;
+                } else {
+                    remaining_reward.amount = BigUint::zero();
+                }
+                let _ = remaining_rewards.set(i, &remaining_reward);
+                break;
+            }
+        }
+
+        remaining_rewards_mapper.set(&remaining_rewards);
+    }
+
+    fn add_payment(&self, payments: &mut PaymentsVec<Self::Api>, payment: EsdtTokenPayment) {
+        for (i, mut existing_payment) in payments.iter().enumerate() {
+            if existing_payment.token_identifier == payment.token_identifier {
+                existing_payment.amount += payment.amount;
+                let _ = payments.set(i, &existing_payment);
+                return;
+            }
+        }
+
+        payments.push(payment);
+    }
+
+    #[view(getRemainingRewardsForWeek)]
+    #[storage_mapper("remainingRewardsForWeek")]
+    fn remaining_rewards_for_week(&self, week: Week) -> SingleValueMapper<PaymentsVec<Self::Api>>;
+
+    #[view(getExpiredRewardsForWeek)]
+    #[storage_mapper("expiredRewardsForWeek")]
+    fn expired_rewards_for_week(&self, week: Week) -> SingleValueMapper<PaymentsVec<Self::Api>>;
+
+    #[view(getLastExpiredRewardsRecoveryWeek)]
+    #[storage_mapper("lastExpiredRewardsRecoveryWeek")]
+    fn last_expired_rewards_recovery_week(&self) -> SingleValueMapper<Week>;
+
+    #[view(getExpiredRewardsDestination)]
+    #[storage_mapper("expiredRewardsDestination")]
+    fn expired_rewards_destination(&self) -> SingleValueMapper<ManagedAddress>;
+}
@@ -0,0 +1,252 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use common_types::Week;
+
+use crate::fees_conversion::MAX_PERCENTAGE;
+
+pub const DEPOSIT_SWAP_FEES_FUNC_NAME: &[u8] = b"depositSwapFees";
+
+/// Transfer sends the fees to the destination address.
+/// DepositSwapFees deposits them through the destination's depositSwapFees endpoint, e.g. in the POL treasury.
+/// Burn burns them, for which the contract needs the burn role of each token.
+#[derive(
+    TypeAbi,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    ManagedVecItem,
+    Clone,
+    Copy,
+    PartialEq,
+    Debug,
+)]
+pub enum FeeSplitDestinationType {
+    Transfer,
+    DepositSwapFees,
+    Burn,
+}
+
+#[derive(
+    TypeAbi,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    ManagedVecItem,
+    Clone,
+    PartialEq,
+    Debug,
+)]
+pub struct FeeSplit<M: ManagedTypeApi> {
+    pub destination_type: FeeSplitDestinationType,
+    pub destination: ManagedAddress<M>,
+    pub percentage: u64,
+}
+
+#[derive(
+    TypeAbi,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    ManagedVecItem,
+    Clone,
+    PartialEq,
+    Debug,
+)]
+pub struct FeeSplitResult<M: ManagedTypeApi> {
+    pub destination_type: FeeSplitDestinationType,
+    pub destination: ManagedAddress<M>,
+    pub payment: EsdtTokenPayment<M>,
+    pub distributed: bool,
+}
+
+pub type FeeSplitArgType<M> = MultiValue3<FeeSplitDestinationType, ManagedAddress<M>, u64>;
+
+#[multiversx_sc::module]
+pub trait FeeSplitModule:
+    crate::config::ConfigModule
+    + crate::events::FeesCollectorEventsModule
+    + week_timekeeping::WeekTimekeepingModule
+{
+    /// Replaces the fee split table, as a list of (destination_type, destination, percentage) entries,
+    /// with percentages out of 10_000. The destination of burn entries is ignored.
+    /// The fees left after the splits go to the energy holders.
+    /// Only applies to the fees of weeks that were not finalized yet.
+    #[only_owner]
+    #[endpoint(setFeeSplits)]
+    fn set_fee_splits(&self, fee_splits: MultiValueEncoded<FeeSplitArgType<Self::Api>>) {
+        let mut splits = ManagedVec::<Self::Api, FeeSplit<Self::Api>>::new();
+        let mut total_percentage = 0;
+        for fee_split in fee_splits {
+            let (destination_type, destination, percentage) = fee_split.into_tuple();
+            require!(percentage > 0, "Invalid percentage");
+            self.require_valid_fee_split_destination(destination_type, &destination);
+
+            total_percentage += percentage;
+            splits.push(FeeSplit {
+                destination_type,
+                destination,
+                percentage,
+            });
+        }
+        require!(total_percentage <= MAX_PERCENTAGE, "Invalid percentage");
+
+        self.fee_splits().set(splits);
+    }
+
+    /// Sends the given entries of the fees split from the given week to their destinations,
+    /// or all the entries that were not distributed yet, if none are given.
+    /// The entries are indexed from 0, as returned by getFeeSplitResults.
+    /// The splits are computed when the week is finalized.
+    #[endpoint(distributeFeeSplits)]
+    fn distribute_fee_splits(&self, week: Week, entry_indexes: MultiValueEncoded<usize>) {
+        let results_mapper = self.fee_split_results(week);
+        let mut split_results = results_mapper.get();
+        require!(!split_results.is_empty(), "No fee splits for week");
+
+        let mut indexes_to_distribute = ManagedVec::<Self::Api, usize>::new();
+        if entry_indexes.is_empty() {
+            for (index, split_result) in split_results.iter().enumerate() {
+                if !split_result.distributed {
+                    indexes_to_distribute.push(index);
+                }
+            }
+            require!(!indexes_to_distribute.is_empty(), "Already distributed");
+        } else {
+            for index in entry_indexes {
+                require!(index < split_results.len(), "Invalid entry index");
+                require!(!split_results.get(index).distributed, "Already distributed");
+
+                indexes_to_distribute.push(index);
+            }
+        }
+
+        for index in &indexes_to_distribute {
+            let mut split_result = split_results.get(index);
+            require!(!split_result.distributed, "Already distributed");
+
+            self.send_fee_split(&split_result);
+            split_result.distributed = true;
+            self.emit_fee_split_distributed_event(week, index, split_result.clone());
+            let _ = split_results.set(index, &split_result);
+        }
+
+        results_mapper.set(split_results);
+    }
+
+    /// Changes the destination of an entry of the fees split from the given week that was not distributed yet,
+    /// e.g. when its destination does not accept the payment.
+    #[only_owner]
+    #[endpoint(rerouteFeeSplit)]
+    fn reroute_fee_split(
+        &self,
+        week: Week,
+        entry_index: usize,
+        destination_type: FeeSplitDestinationType,
+        destination: ManagedAddress,
+    ) {
+        self.require_valid_fee_split_destination(destination_type, &destination);
+
+        let results_mapper = self.fee_split_results(week);
+        let mut split_results = results_mapper.get();
+        require!(entry_index < split_results.len(), "Invalid entry index");
+
+        let mut split_result = split_results.get(entry_index);
+        require!(!split_result.distributed, "Already distributed");
+
+        split_result.destination_type = destination_type;
+        split_result.destination = destination;
+        let _ = split_results.set(entry_index, &split_result);
+        results_mapper.set(split_results);
+    }
+
+    fn require_valid_fee_split_destination(
+        &self,
+        destination_type: FeeSplitDestinationType,
+        destination: &ManagedAddress,
+    ) {
+        match destination_type {
+            FeeSplitDestinationType::Transfer => {
+                require!(!destination.is_zero(), "Invalid destination")
+            }
+            FeeSplitDestinationType::DepositSwapFees => require!(
+                self.blockchain().is_smart_contract(destination),
+                "Invalid SC address"
+            ),
+            FeeSplitDestinationType::Burn => {}
+        }
+    }
+
+    fn send_fee_split(&self, split_result: &FeeSplitResult<Self::Api>) {
+        let payment = &split_result.payment;
+        match split_result.destination_type {
+            FeeSplitDestinationType::Transfer => self.send().direct_esdt(
+                &split_result.destination,
+                &payment.token_identifier,
+                0,
+                &payment.amount,
+            ),
+            FeeSplitDestinationType::DepositSwapFees => {
+                let _: IgnoreValue = self
+                    .send()
+                    .contract_call::<()>(
+                        split_result.destination.clone(),
+                        DEPOSIT_SWAP_FEES_FUNC_NAME,
+                    )
+                    .with_esdt_transfer(payment.clone())
+                    .execute_on_dest_context();
+            }
+            FeeSplitDestinationType::Burn => {
+                self.send()
+                    .esdt_local_burn(&payment.token_identifier, 0, &payment.amount)
+            }
+        }
+    }
+
+    /// Sets aside the split parts of the week's fees in the given token.
+    /// The remainders of the rounding go to the energy holders.
+    /// Returns the amount left for the energy holders.
+    fn split_week_fees(&self, week: Week, token_id: &TokenIdentifier, amount: BigUint) -> BigUint {
+        let fee_splits = self.fee_splits().get();
+        if fee_splits.is_empty() {
+            return amount;
+        }
+
+        let mut remaining_amount = amount.clone();
+        let results_mapper = self.fee_split_results(week);
+        let mut split_results = results_mapper.get();
+        for fee_split in &fee_splits {
+            let split_amount = &amount * fee_split.percentage / MAX_PERCENTAGE;
+            if split_amount == 0 {
+                continue;
+            }
+
+            remaining_amount -= &split_amount;
+            let split_result = FeeSplitResult {
+                destination_type: fee_split.destination_type,
+                destination: fee_split.destination,
+                payment: EsdtTokenPayment::new(token_id.clone(), 0, split_amount),
+                distributed: false,
+            };
+            self.emit_fee_split_event(week, split_result.clone());
+            split_results.push(split_result);
+        }
+        results_mapper.set(split_results);
+
+        remaining_amount
+    }
+
+    #[view(getFeeSplits)]
+    #[storage_mapper("feeSplits")]
+    fn fee_splits(&self) -> SingleValueMapper<ManagedVec<FeeSplit<Self::Api>>>;
+
+    #[view(getFeeSplitResults)]
+    #[storage_mapper("feeSplitResults")]
+    fn fee_split_results(
+        &self,
+        week: Week,
+    ) -> SingleValueMapper<ManagedVec<FeeSplitResult<Self::Api>>>;
+}
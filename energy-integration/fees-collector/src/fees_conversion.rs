@@ -0,0 +1,293 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use common_types::Week;
+
+pub const MAX_PERCENTAGE: u64 = 10_000;
+pub const MULTI_PAIR_SWAP_FUNC_NAME: &[u8] = b"multiPairSwap";
+pub const SWAP_TOKENS_FIXED_INPUT_FUNC_NAME: &[u8] = b"swapTokensFixedInput";
+pub const GET_SAFE_PRICE_FUNC_NAME: &[u8] = b"getSafePriceByDefaultOffset";
+
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Debug,
+)]
+pub struct SwapRouteStep<M: ManagedTypeApi> {
+    pub pair_address: ManagedAddress<M>,
+    pub token_out: TokenIdentifier<M>,
+}
+
+/// The pair and router contracts depend on the fees collector,
+/// so they are called without their proxies
+#[multiversx_sc::module]
+pub trait FeesConversionModule:
+    crate::config::ConfigModule
+    + crate::fees_accumulation::FeesAccumulationModule
+    + crate::events::FeesCollectorEventsModule
+    + energy_query::EnergyQueryModule
+    + week_timekeeping::WeekTimekeepingModule
+{
+    /// The token all the other fees are converted into. Must be a known token.
+    #[only_owner]
+    #[endpoint(setPayoutToken)]
+    fn set_payout_token(&self, payout_token_id: TokenIdentifier) {
+        require!(
+            self.known_tokens().contains(&payout_token_id)
+                && payout_token_id != self.locked_token_id().get(),
+            "Invalid payout token"
+        );
+
+        self.payout_token_id().set(payout_token_id);
+    }
+
+    #[only_owner]
+    #[endpoint(setConversionConfig)]
+    fn set_conversion_config(
+        &self,
+        router_address: ManagedAddress,
+        safe_price_view_address: ManagedAddress,
+        max_price_deviation_percentage: u64,
+    ) {
+        require!(
+            self.blockchain().is_smart_contract(&router_address)
+                && self
+                    .blockchain()
+                    .is_smart_contract(&safe_price_view_address),
+            "Invalid SC address"
+        );
+        require!(
+            max_price_deviation_percentage < MAX_PERCENTAGE,
+            "Invalid max price deviation percentage"
+        );
+
+        self.router_address().set(router_address);
+        self.safe_price_view_address().set(safe_price_view_address);
+        self.max_price_deviation_percentage()
+            .set(max_price_deviation_percentage);
+    }
+
+    /// The route used to convert the token, as a list of (pair_address, token_out) pairs.
+    /// The last token_out must be the payout token.
+    #[only_owner]
+    #[endpoint(setConversionRoute)]
+    fn set_conversion_route(
+        &self,
+        token_id: TokenIdentifier,
+        route: MultiValueEncoded<MultiValue2<ManagedAddress, TokenIdentifier>>,
+    ) {
+        require!(
+            self.known_tokens().contains(&token_id) && token_id != self.locked_token_id().get(),
+            "Invalid token ID"
+        );
+
+        let route_steps = self.build_route(route, &self.payout_token_id().get());
+        self.conversion_route(&token_id).set(route_steps);
+    }
+
+    #[only_owner]
+    #[endpoint(removeConversionRoute)]
+    fn remove_conversion_route(&self, token_id: TokenIdentifier) {
+        self.conversion_route(&token_id).clear();
+    }
+
+    /// The route used by claimAndRelock to swap the token into the base token,
+    /// as a list of (pair_address, token_out) pairs.
+    #[only_owner]
+    #[endpoint(setRelockRoute)]
+    fn set_relock_route(
+        &self,
+        token_id: TokenIdentifier,
+        route: MultiValueEncoded<MultiValue2<ManagedAddress, TokenIdentifier>>,
+    ) {
+        require!(
+            self.known_tokens().contains(&token_id) && token_id != self.locked_token_id().get(),
+            "Invalid token ID"
+        );
+
+        let route_steps = self.build_route(route, &self.get_base_token_id());
+        self.relock_route(&token_id).set(route_steps);
+    }
+
+    #[only_owner]
+    #[endpoint(removeRelockRoute)]
+    fn remove_relock_route(&self, token_id: TokenIdentifier) {
+        self.relock_route(&token_id).clear();
+    }
+
+    #[only_owner]
+    #[endpoint(addConversionKeepers)]
+    fn add_conversion_keepers(&self, keepers: MultiValueEncoded<ManagedAddress>) {
+        let mut mapper = self.conversion_keepers();
+        for keeper in keepers {
+            let _ = mapper.insert(keeper);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeConversionKeepers)]
+    fn remove_conversion_keepers(&self, keepers: MultiValueEncoded<ManagedAddress>) {
+        let mut mapper = self.conversion_keepers();
+        for keeper in keepers {
+            let _ = mapper.swap_remove(&keeper);
+        }
+    }
+
+    /// Swaps the fees accumulated for the given week into the payout token, through the router.
+    /// The week must not be finalized yet, otherwise there is nothing left to convert.
+    /// Only the given tokens are converted, or all the tokens with a conversion route if none are given.
+    /// The min amount out of each conversion is given by the safe prices of the route's pairs.
+    #[endpoint(convertFees)]
+    fn convert_fees(&self, week: Week, tokens: MultiValueEncoded<TokenIdentifier>) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.conversion_keepers().contains(&caller)
+                || caller == self.blockchain().get_owner_address(),
+            "Only keepers can convert fees"
+        );
+        require!(week <= self.get_current_week(), "Invalid week");
+        require!(!self.payout_token_id().is_empty(), "Payout token not set");
+
+        let tokens_to_convert = if tokens.is_empty() {
+            self.all_tokens().get()
+        } else {
+            tokens.to_vec()
+        };
+
+        let payout_token_id = self.payout_token_id().get();
+        let mut total_converted_amount = BigUint::zero();
+        for token_id in &tokens_to_convert {
+            let route_mapper = self.conversion_route(&token_id);
+            if token_id == payout_token_id || route_mapper.is_empty() {
+                continue;
+            }
+
+            let amount = self.accumulated_fees(week, &token_id).take();
+            if amount == 0 {
+                continue;
+            }
+
+            let payment = EsdtTokenPayment::new(token_id, 0, amount);
+            let converted_amount = self.swap_through_route(payment.clone(), route_mapper.get());
+            total_converted_amount += &converted_amount;
+
+            self.emit_convert_fees_event(
+                week,
+                payment,
+                EsdtTokenPayment::new(payout_token_id.clone(), 0, converted_amount),
+            );
+        }
+
+        self.accumulated_fees(week, &payout_token_id)
+            .update(|amount| *amount += total_converted_amount);
+    }
+
+    fn build_route(
+        &self,
+        route: MultiValueEncoded<MultiValue2<ManagedAddress, TokenIdentifier>>,
+        final_token_id: &TokenIdentifier,
+    ) -> ManagedVec<SwapRouteStep<Self::Api>> {
+        let mut route_steps = ManagedVec::new();
+        for step in route {
+            let (pair_address, token_out) = step.into_tuple();
+            route_steps.push(SwapRouteStep {
+                pair_address,
+                token_out,
+            });
+        }
+        require!(!route_steps.is_empty(), "Invalid conversion route");
+
+        let last_step = route_steps.get(route_steps.len() - 1);
+        require!(
+            &last_step.token_out == final_token_id,
+            "Invalid conversion route"
+        );
+
+        route_steps
+    }
+
+    fn swap_through_route(
+        &self,
+        payment: EsdtTokenPayment,
+        route: ManagedVec<SwapRouteStep<Self::Api>>,
+    ) -> BigUint {
+        let safe_price_view_address = self.safe_price_view_address().get();
+        let mut contract_call = self
+            .send()
+            .contract_call::<ManagedVec<EsdtTokenPayment>>(
+                self.router_address().get(),
+                MULTI_PAIR_SWAP_FUNC_NAME,
+            )
+            .with_esdt_transfer(payment.clone());
+
+        let mut expected_payment = payment;
+        let last_index = route.len() - 1;
+        for (i, step) in route.iter().enumerate() {
+            expected_payment = self
+                .send()
+                .contract_call::<EsdtTokenPayment>(
+                    safe_price_view_address.clone(),
+                    GET_SAFE_PRICE_FUNC_NAME,
+                )
+                .argument(&step.pair_address)
+                .argument(&expected_payment)
+                .execute_on_dest_context();
+            require!(
+                expected_payment.token_identifier == step.token_out,
+                "Invalid conversion route"
+            );
+
+            // only the final amount is checked, the intermediary steps may deviate
+            let min_amount_out = if i == last_index {
+                self.apply_max_price_deviation(&expected_payment.amount)
+            } else {
+                BigUint::from(1u32)
+            };
+            contract_call = contract_call
+                .argument(&step.pair_address)
+                .argument(&ManagedBuffer::from(SWAP_TOKENS_FIXED_INPUT_FUNC_NAME))
+                .argument(&step.token_out)
+                .argument(&min_amount_out);
+        }
+
+        let output_payments: ManagedVec<EsdtTokenPayment> = contract_call.execute_on_dest_context();
+        output_payments.get(output_payments.len() - 1).amount
+    }
+
+    fn apply_max_price_deviation(&self, amount: &BigUint) -> BigUint {
+        let max_price_deviation_percentage = self.max_price_deviation_percentage().get();
+        amount * (MAX_PERCENTAGE - max_price_deviation_percentage) / MAX_PERCENTAGE
+    }
+
+    #[view(getPayoutTokenId)]
+    #[storage_mapper("payoutTokenId")]
+    fn payout_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getConversionRouterAddress)]
+    #[storage_mapper("conversionRouterAddress")]
+    fn router_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getSafePriceViewAddress)]
+    #[storage_mapper("safePriceViewAddress")]
+    fn safe_price_view_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getMaxPriceDeviationPercentage)]
+    #[storage_mapper("maxPriceDeviationPercentage")]
+    fn max_price_deviation_percentage(&self) -> SingleValueMapper<u64>;
+
+    #[view(getConversionRoute)]
+    #[storage_mapper("conversionRoute")]
+    fn conversion_route(
+        &self,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<ManagedVec<SwapRouteStep<Self::Api>>>;
+
+    #[view(getRelockRoute)]
+    #[storage_mapper("relockRoute")]
+    fn relock_route(
+        &self,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<ManagedVec<SwapRouteStep<Self::Api>>>;
+
+    #[view(getConversionKeepers)]
+    #[storage_mapper("conversionKeepers")]
+    fn conversion_keepers(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
@@ -4,12 +4,16 @@ multiversx_sc::imports!();
 
 use common_types::{PaymentsVec, Week};
 use core::marker::PhantomData;
+use energy_factory::token_merging::ProxyTrait as _;
 use weekly_rewards_splitting::base_impl::WeeklyRewardsSplittingTraitsModule;
 
 pub mod additional_locked_tokens;
 pub mod config;
 pub mod events;
+pub mod expired_rewards;
+pub mod fee_split;
 pub mod fees_accumulation;
+pub mod fees_conversion;
 
 #[multiversx_sc::contract]
 pub trait FeesCollector:
@@ -21,6 +25,9 @@ pub trait FeesCollector:
     + weekly_rewards_splitting::locked_token_buckets::WeeklyRewardsLockedTokenBucketsModule
     + weekly_rewards_splitting::update_claim_progress_energy::UpdateClaimProgressEnergyModule
     + fees_accumulation::FeesAccumulationModule
+    + fees_conversion::FeesConversionModule
+    + fee_split::FeeSplitModule
+    + expired_rewards::ExpiredRewardsModule
     + additional_locked_tokens::AdditionalLockedTokensModule
     + locking_module::lock_with_energy_module::LockWithEnergyModule
     + energy_query::EnergyQueryModule
@@ -81,6 +88,105 @@ pub trait FeesCollector:
         self.claim_rewards(original_caller.clone(), original_caller)
     }
 
+    /// Claims the caller's rewards and locks them for the given lock option, which raises the caller's energy.
+    /// Rewards in tokens with a relock route are first swapped into the base token,
+    /// while rewards in other tokens are sent as they are.
+    /// The base tokens are burned and locked virtually, so the contract needs their burn role.
+    /// LOCKED tokens sent with the call are merged with the newly locked tokens.
+    ///
+    /// Output payments: the rewards that were not locked, and the LOCKED tokens last
+    #[payable("*")]
+    #[endpoint(claimAndRelock)]
+    fn claim_and_relock(&self, lock_epochs: u64) -> PaymentsVec<Self::Api> {
+        require!(self.not_paused(), "Cannot claim while paused");
+
+        let locked_token_id = self.get_locked_token_id();
+        let locked_payments = self.call_value().all_esdt_transfers().clone_value();
+        for payment in &locked_payments {
+            require!(
+                payment.token_identifier == locked_token_id,
+                "Invalid payment token"
+            );
+        }
+
+        self.accumulate_additional_locked_tokens();
+
+        let caller = self.blockchain().get_caller();
+        let wrapper = FeesCollectorWrapper::new();
+        let rewards = self.claim_multi(&wrapper, &caller);
+
+        let base_token_id = self.get_base_token_id();
+        let mut relock_amount = BigUint::zero();
+        let mut output_payments = PaymentsVec::new();
+        for reward in &rewards {
+            if reward.token_identifier == locked_token_id {
+                relock_amount += reward.amount;
+                continue;
+            }
+
+            let relock_route_mapper = self.relock_route(&reward.token_identifier);
+            let base_token_amount = if reward.token_identifier == base_token_id {
+                reward.amount
+            } else if !relock_route_mapper.is_empty() {
+                self.swap_through_route(reward, relock_route_mapper.get())
+            } else {
+                output_payments.push(reward);
+                continue;
+            };
+
+            self.send()
+                .esdt_local_burn(&base_token_id, 0, &base_token_amount);
+            relock_amount += base_token_amount;
+        }
+        require!(relock_amount > 0, "Nothing to relock");
+
+        if !output_payments.is_empty() {
+            self.send().direct_multi(&caller, &output_payments);
+        }
+
+        let locked_tokens = if locked_payments.is_empty() {
+            self.lock_virtual_for_epochs(
+                base_token_id,
+                relock_amount,
+                lock_epochs,
+                caller.clone(),
+                caller.clone(),
+            )
+        } else {
+            let sc_address = self.blockchain().get_sc_address();
+            let new_locked_tokens = self.lock_virtual_for_epochs(
+                base_token_id,
+                relock_amount,
+                lock_epochs,
+                sc_address,
+                caller.clone(),
+            );
+
+            let mut merge_payments = locked_payments;
+            merge_payments.push(new_locked_tokens);
+            let merged_tokens: EsdtTokenPayment = self
+                .get_locking_sc_proxy_instance()
+                .merge_tokens_endpoint(OptionalValue::Some(caller.clone()))
+                .with_multi_token_transfer(merge_payments)
+                .execute_on_dest_context();
+            self.send().direct_esdt(
+                &caller,
+                &merged_tokens.token_identifier,
+                merged_tokens.token_nonce,
+                &merged_tokens.amount,
+            );
+
+            merged_tokens
+        };
+
+        // the new energy already counts for the current week
+        self.update_energy_and_progress(&caller);
+
+        output_payments.push(locked_tokens);
+
+        output_payments
+    }
+
     fn claim_rewards(
         &self,
         caller: ManagedAddress,
@@ -153,20 +259,27 @@ where
 {
     type WeeklyRewardsSplittingMod = T;
 
-    fn collect_rewards_for_week(
+    fn get_user_rewards_for_week(
         &self,
         sc: &Self::WeeklyRewardsSplittingMod,
         week: Week,
+        energy_amount: &BigUint<<Self::WeeklyRewardsSplittingMod as ContractBase>::Api>,
+        total_energy: &BigUint<<Self::WeeklyRewardsSplittingMod as ContractBase>::Api>,
     ) -> PaymentsVec<<Self::WeeklyRewardsSplittingMod as ContractBase>::Api> {
-        let mut results = ManagedVec::new();
-        let all_tokens = sc.all_tokens().get();
-        for token in &all_tokens {
-            let opt_accumulated_fees = sc.get_and_clear_accumulated_fees(week, &token);
-            if let Some(accumulated_fees) = opt_accumulated_fees {
-                results.push(EsdtTokenPayment::new(token, 0, accumulated_fees));
-            }
-        }
+        let user_rewards =
+            self.get_energy_share_of_rewards_for_week(sc, week, energy_amount, total_energy);
+
+        // what is left unclaimed can be recovered once the week expires
+        sc.deduct_claimed_rewards(week, &user_rewards);
+
+        user_rewards
+    }
 
-        results
+    fn collect_rewards_for_week(
+        &self,
+        sc: &Self::WeeklyRewardsSplittingMod,
+        week: Week,
+    ) -> PaymentsVec<<Self::WeeklyRewardsSplittingMod as ContractBase>::Api> {
+        sc.collect_fees_for_week(week)
     }
 }
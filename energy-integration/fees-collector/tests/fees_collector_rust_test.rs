@@ -2,15 +2,32 @@
 
 mod fees_collector_test_setup;
 
+use energy_factory::energy::EnergyModule;
 use energy_query::Energy;
 use fees_collector::additional_locked_tokens::{AdditionalLockedTokensModule, BLOCKS_IN_WEEK};
+use fees_collector::config::ConfigModule;
+use fees_collector::expired_rewards::{ExpiredRewardsModule, MAX_RECOVERED_WEEKS_PER_CALL};
+use fees_collector::fee_split::{FeeSplitDestinationType, FeeSplitModule, FeeSplitResult};
 use fees_collector::fees_accumulation::FeesAccumulationModule;
+use fees_collector::fees_conversion::FeesConversionModule;
+use fees_collector::FeesCollector;
 use fees_collector_test_setup::*;
-use multiversx_sc::types::{BigInt, EsdtTokenPayment, ManagedVec};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{
+    BigInt, EsdtLocalRole, EsdtTokenPayment, ManagedAddress, ManagedVec, MultiValueEncoded,
+};
+use multiversx_sc_scenario::whitebox_legacy::TxTokenTransfer;
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_token_id, managed_token_id_wrapped, rust_biguint,
     DebugApi,
 };
+use pair::{
+    config::ConfigModule as PairConfigModule,
+    pair_actions::{initial_liq::InitialLiquidityModule, swap::SwapModule},
+    Pair,
+};
+use pausable::{PausableModule, State};
+use router::{config::ConfigModule as RouterConfigModule, factory::PairTokens, Router};
 use simple_lock::locked_token::LockedTokenAttributes;
 use weekly_rewards_splitting::locked_token_buckets::LockedTokensBucket;
 use weekly_rewards_splitting::update_claim_progress_energy::UpdateClaimProgressEnergyModule;
@@ -19,6 +36,10 @@ use weekly_rewards_splitting::{
     locked_token_buckets::WeeklyRewardsLockedTokenBucketsModule, ClaimProgress,
 };
 
+const LP_TOKEN_ID: &[u8] = b"LPTOK-123456";
+const POOL_RESERVE: u64 = 1_000_000_000_000;
+const MAX_PRICE_DEVIATION_PERCENTAGE: u64 = 100; // 1%
+
 #[test]
 fn setup_test() {
     let mut fc_setup =
@@ -1419,3 +1440,654 @@ fn additional_locked_tokens_test() {
         })
         .assert_ok();
 }
+
+#[test]
+fn convert_fees_to_payout_token_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut fc_setup =
+        FeesCollectorSetup::new(fees_collector::contract_obj, energy_factory::contract_obj);
+
+    let owner = fc_setup.owner_address.clone();
+    let keeper = fc_setup.b_mock.create_user_account(&rust_zero);
+    let user = fc_setup.b_mock.create_user_account(&rust_zero);
+    fc_setup.set_energy(&user, 500, 1_000);
+
+    // FIRST/SECOND pair, without fees, which also serves as safe price view
+    let router_wrapper = fc_setup.b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        router::contract_obj,
+        "router.wasm",
+    );
+    let pair_wrapper = fc_setup.b_mock.create_sc_account(
+        &rust_zero,
+        Some(router_wrapper.address_ref()),
+        pair::contract_obj,
+        "pair.wasm",
+    );
+    let pair_address = pair_wrapper.address_ref().clone();
+    fc_setup
+        .b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.init(OptionalValue::None);
+            sc.pair_map().insert(
+                PairTokens {
+                    first_token_id: managed_token_id!(FIRST_TOKEN_ID),
+                    second_token_id: managed_token_id!(SECOND_TOKEN_ID),
+                },
+                managed_address!(&pair_address),
+            );
+        })
+        .assert_ok();
+    fc_setup
+        .b_mock
+        .execute_tx(&owner, &pair_wrapper, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(FIRST_TOKEN_ID),
+                managed_token_id!(SECOND_TOKEN_ID),
+                managed_address!(router_wrapper.address_ref()),
+                managed_address!(&owner),
+                0,
+                0,
+                ManagedAddress::<DebugApi>::zero(),
+                MultiValueEncoded::<DebugApi, ManagedAddress<DebugApi>>::new(),
+            );
+            sc.lp_token_identifier()
+                .set(&managed_token_id!(LP_TOKEN_ID));
+        })
+        .assert_ok();
+    fc_setup.b_mock.set_esdt_local_roles(
+        &pair_address,
+        LP_TOKEN_ID,
+        &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+    );
+
+    let pool_reserve = rust_biguint!(POOL_RESERVE);
+    fc_setup
+        .b_mock
+        .set_esdt_balance(&owner, FIRST_TOKEN_ID, &(&pool_reserve * 2u32));
+    fc_setup
+        .b_mock
+        .set_esdt_balance(&owner, SECOND_TOKEN_ID, &pool_reserve);
+    let payments = [
+        TxTokenTransfer {
+            token_identifier: FIRST_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: pool_reserve.clone(),
+        },
+        TxTokenTransfer {
+            token_identifier: SECOND_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: pool_reserve.clone(),
+        },
+    ];
+    fc_setup
+        .b_mock
+        .execute_esdt_multi_transfer(&owner, &pair_wrapper, &payments, |sc| {
+            let _ = sc.add_initial_liquidity();
+            sc.state().set(State::Active);
+        })
+        .assert_ok();
+
+    // record a price observation
+    fc_setup.b_mock.set_block_round(1);
+    fc_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &owner,
+            &pair_wrapper,
+            FIRST_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let _ = sc.swap_tokens_fixed_input(
+                    managed_token_id!(SECOND_TOKEN_ID),
+                    managed_biguint!(1),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+    fc_setup.b_mock.set_block_round(10);
+
+    fc_setup
+        .b_mock
+        .execute_tx(&owner, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.set_payout_token(managed_token_id!(SECOND_TOKEN_ID));
+            sc.set_conversion_config(
+                managed_address!(router_wrapper.address_ref()),
+                managed_address!(&pair_address),
+                MAX_PRICE_DEVIATION_PERCENTAGE,
+            );
+
+            let mut route = MultiValueEncoded::new();
+            route.push(
+                (
+                    managed_address!(&pair_address),
+                    managed_token_id!(SECOND_TOKEN_ID),
+                )
+                    .into(),
+            );
+            sc.set_conversion_route(managed_token_id!(FIRST_TOKEN_ID), route);
+
+            let mut keepers = MultiValueEncoded::new();
+            keepers.push(managed_address!(&keeper));
+            sc.add_conversion_keepers(keepers);
+        })
+        .assert_ok();
+
+    // locked tokens are burned on deposit, so they can't be converted
+    fc_setup
+        .b_mock
+        .execute_tx(&owner, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            let mut route = MultiValueEncoded::new();
+            route.push(
+                (
+                    managed_address!(&pair_address),
+                    managed_token_id!(SECOND_TOKEN_ID),
+                )
+                    .into(),
+            );
+            sc.set_conversion_route(managed_token_id!(LOCKED_TOKEN_ID), route);
+        })
+        .assert_user_error("Invalid token ID");
+    fc_setup
+        .b_mock
+        .execute_tx(&owner, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.set_relock_route(managed_token_id!(LOCKED_TOKEN_ID), MultiValueEncoded::new());
+        })
+        .assert_user_error("Invalid token ID");
+
+    fc_setup.deposit(FIRST_TOKEN_ID, 1_000).assert_ok();
+    fc_setup.deposit(SECOND_TOKEN_ID, 500).assert_ok();
+    fc_setup.claim(&user).assert_ok();
+
+    fc_setup
+        .b_mock
+        .execute_tx(&user, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.convert_fees(1, MultiValueEncoded::new());
+        })
+        .assert_user_error("Only keepers can convert fees");
+
+    fc_setup
+        .b_mock
+        .execute_tx(&keeper, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.convert_fees(1, MultiValueEncoded::new());
+        })
+        .assert_ok();
+
+    // 1_000 FIRST tokens were swapped for 999 SECOND tokens
+    fc_setup
+        .b_mock
+        .execute_query(&fc_setup.fc_wrapper, |sc| {
+            assert_eq!(
+                sc.accumulated_fees(1, &managed_token_id!(FIRST_TOKEN_ID))
+                    .get(),
+                managed_biguint!(0)
+            );
+            assert_eq!(
+                sc.accumulated_fees(1, &managed_token_id!(SECOND_TOKEN_ID))
+                    .get(),
+                managed_biguint!(1_499)
+            );
+        })
+        .assert_ok();
+
+    // the rewards are paid in the payout token only
+    fc_setup.advance_week();
+    fc_setup.claim(&user).assert_ok();
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&user, FIRST_TOKEN_ID, &rust_zero);
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&user, SECOND_TOKEN_ID, &rust_biguint!(1_499));
+}
+
+#[test]
+fn claim_and_relock_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut fc_setup =
+        FeesCollectorSetup::new(fees_collector::contract_obj, energy_factory::contract_obj);
+
+    let user = fc_setup.b_mock.create_user_account(&rust_zero);
+    fc_setup.set_energy(&user, 500, 1_000);
+
+    let owner = fc_setup.owner_address.clone();
+    fc_setup
+        .b_mock
+        .execute_tx(&owner, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            let mut tokens = MultiValueEncoded::new();
+            tokens.push(managed_token_id!(BASE_ASSET_TOKEN_ID));
+            sc.add_known_tokens(tokens);
+        })
+        .assert_ok();
+    fc_setup.b_mock.set_esdt_local_roles(
+        fc_setup.fc_wrapper.address_ref(),
+        BASE_ASSET_TOKEN_ID,
+        &[EsdtLocalRole::Burn],
+    );
+    let depositor = fc_setup.depositor_address.clone();
+    fc_setup
+        .b_mock
+        .set_esdt_balance(&depositor, BASE_ASSET_TOKEN_ID, &rust_biguint!(1_000));
+
+    fc_setup.deposit(FIRST_TOKEN_ID, 300).assert_ok();
+    fc_setup.deposit(BASE_ASSET_TOKEN_ID, 500).assert_ok();
+    fc_setup
+        .deposit_locked_tokens(LOCKED_TOKEN_ID, 1, 1_000)
+        .assert_ok();
+    fc_setup.claim(&user).assert_ok();
+
+    fc_setup.advance_week();
+
+    // the locked and base token rewards are locked together, the others are sent as they are
+    let mut locked_token_nonce = 0;
+    fc_setup
+        .b_mock
+        .execute_tx(&user, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            let output_payments = sc.claim_and_relock(LOCK_OPTIONS[0]);
+            assert_eq!(output_payments.len(), 2);
+
+            let locked_tokens = output_payments.get(1);
+            assert_eq!(
+                locked_tokens.token_identifier,
+                managed_token_id!(LOCKED_TOKEN_ID)
+            );
+            assert_eq!(locked_tokens.amount, managed_biguint!(1_500));
+            locked_token_nonce = locked_tokens.token_nonce;
+        })
+        .assert_ok();
+
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&user, FIRST_TOKEN_ID, &rust_biguint!(300));
+    fc_setup.b_mock.check_esdt_balance(
+        fc_setup.fc_wrapper.address_ref(),
+        BASE_ASSET_TOKEN_ID,
+        &rust_zero,
+    );
+    fc_setup
+        .b_mock
+        .check_nft_balance::<LockedTokenAttributes<DebugApi>>(
+            &user,
+            LOCKED_TOKEN_ID,
+            locked_token_nonce,
+            &rust_biguint!(1_500),
+            None,
+        );
+
+    let mut energy_after_relock = 0;
+    fc_setup
+        .b_mock
+        .execute_query(&fc_setup.energy_factory_wrapper, |sc| {
+            let energy = sc.get_updated_energy_entry_for_user(&managed_address!(&user));
+            assert_eq!(energy.get_total_locked_tokens(), &managed_biguint!(2_000));
+            energy_after_relock = energy.get_energy_amount().to_u64().unwrap();
+        })
+        .assert_ok();
+
+    fc_setup
+        .b_mock
+        .execute_tx(&user, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            let _ = sc.claim_and_relock(LOCK_OPTIONS[0]);
+        })
+        .assert_user_error("Nothing to relock");
+
+    // the next rewards are merged into the existing position
+    fc_setup.deposit(BASE_ASSET_TOKEN_ID, 500).assert_ok();
+    fc_setup.advance_week();
+
+    fc_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user,
+            &fc_setup.fc_wrapper,
+            LOCKED_TOKEN_ID,
+            locked_token_nonce,
+            &rust_biguint!(1_500),
+            |sc| {
+                let output_payments = sc.claim_and_relock(LOCK_OPTIONS[0]);
+                assert_eq!(output_payments.len(), 1);
+                assert_eq!(output_payments.get(0).amount, managed_biguint!(2_000));
+            },
+        )
+        .assert_ok();
+
+    fc_setup
+        .b_mock
+        .execute_query(&fc_setup.energy_factory_wrapper, |sc| {
+            let energy = sc.get_updated_energy_entry_for_user(&managed_address!(&user));
+            assert_eq!(energy.get_total_locked_tokens(), &managed_biguint!(2_500));
+            assert!(energy.get_energy_amount().to_u64().unwrap() > energy_after_relock);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn fee_splits_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut fc_setup =
+        FeesCollectorSetup::new(fees_collector::contract_obj, energy_factory::contract_obj);
+
+    let owner = fc_setup.owner_address.clone();
+    let treasury = fc_setup.b_mock.create_user_account(&rust_zero);
+    let user = fc_setup.b_mock.create_user_account(&rust_zero);
+    let no_deposit_sc = fc_setup.energy_factory_wrapper.address_ref().clone();
+    fc_setup.set_energy(&user, 500, 1_000_000);
+
+    // 10% to the treasury and 5% to a contract without depositSwapFees
+    fc_setup
+        .b_mock
+        .execute_tx(&owner, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            let mut fee_splits = MultiValueEncoded::new();
+            fee_splits.push(
+                (
+                    FeeSplitDestinationType::Transfer,
+                    managed_address!(&treasury),
+                    1_000,
+                )
+                    .into(),
+            );
+            fee_splits.push(
+                (
+                    FeeSplitDestinationType::DepositSwapFees,
+                    managed_address!(&no_deposit_sc),
+                    500,
+                )
+                    .into(),
+            );
+            sc.set_fee_splits(fee_splits);
+        })
+        .assert_ok();
+    for token_id in [FIRST_TOKEN_ID, SECOND_TOKEN_ID] {
+        fc_setup.b_mock.set_esdt_local_roles(
+            fc_setup.fc_wrapper.address_ref(),
+            token_id,
+            &[EsdtLocalRole::Burn],
+        );
+    }
+
+    fc_setup.deposit(FIRST_TOKEN_ID, 1_000).assert_ok();
+    fc_setup.deposit(SECOND_TOKEN_ID, 333).assert_ok();
+    fc_setup
+        .deposit_locked_tokens(LOCKED_TOKEN_ID, 1, 100)
+        .assert_ok();
+    fc_setup.claim(&user).assert_ok();
+
+    fc_setup.advance_week();
+
+    fc_setup
+        .b_mock
+        .execute_tx(&user, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.distribute_fee_splits(1, MultiValueEncoded::new());
+        })
+        .assert_user_error("No fee splits for week");
+
+    // the rounding remainders go to the energy holders
+    fc_setup.claim(&user).assert_ok();
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&user, FIRST_TOKEN_ID, &rust_biguint!(850));
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&user, SECOND_TOKEN_ID, &rust_biguint!(284));
+
+    fc_setup
+        .b_mock
+        .execute_query(&fc_setup.fc_wrapper, |sc| {
+            let split_results = sc.fee_split_results(1).get();
+            assert_eq!(split_results.len(), 4);
+            assert_eq!(
+                split_results.get(2),
+                FeeSplitResult {
+                    destination_type: FeeSplitDestinationType::Transfer,
+                    destination: managed_address!(&treasury),
+                    payment: EsdtTokenPayment::new(
+                        managed_token_id!(SECOND_TOKEN_ID),
+                        0,
+                        managed_biguint!(33)
+                    ),
+                    distributed: false,
+                }
+            );
+        })
+        .assert_ok();
+
+    // the failing destination makes distributing all the entries fail, but the others can go on their own
+    fc_setup
+        .b_mock
+        .execute_tx(&user, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.distribute_fee_splits(1, MultiValueEncoded::new());
+        })
+        .assert_error(1, "invalid function (not found)");
+    fc_setup
+        .b_mock
+        .execute_tx(&user, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            let mut entry_indexes = MultiValueEncoded::new();
+            entry_indexes.push(0);
+            entry_indexes.push(2);
+            sc.distribute_fee_splits(1, entry_indexes);
+        })
+        .assert_ok();
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&treasury, FIRST_TOKEN_ID, &rust_biguint!(100));
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&treasury, SECOND_TOKEN_ID, &rust_biguint!(33));
+
+    fc_setup
+        .b_mock
+        .execute_tx(&owner, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.reroute_fee_split(1, 0, FeeSplitDestinationType::Burn, ManagedAddress::zero());
+        })
+        .assert_user_error("Already distributed");
+    fc_setup
+        .b_mock
+        .execute_tx(&owner, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.reroute_fee_split(1, 1, FeeSplitDestinationType::Burn, ManagedAddress::zero());
+            sc.reroute_fee_split(1, 3, FeeSplitDestinationType::Burn, ManagedAddress::zero());
+        })
+        .assert_ok();
+
+    fc_setup
+        .b_mock
+        .execute_tx(&user, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.distribute_fee_splits(1, MultiValueEncoded::new());
+        })
+        .assert_ok();
+
+    // the burned part is no longer in the contract
+    let fc_address = fc_setup.fc_wrapper.address_ref().clone();
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&fc_address, FIRST_TOKEN_ID, &rust_zero);
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&fc_address, SECOND_TOKEN_ID, &rust_zero);
+
+    fc_setup
+        .b_mock
+        .execute_tx(&user, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.distribute_fee_splits(1, MultiValueEncoded::new());
+        })
+        .assert_user_error("Already distributed");
+    fc_setup
+        .b_mock
+        .execute_query(&fc_setup.fc_wrapper, |sc| {
+            for split_result in &sc.fee_split_results(1).get() {
+                assert!(split_result.distributed);
+            }
+        })
+        .assert_ok();
+}
+
+#[test]
+fn recover_expired_rewards_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut fc_setup =
+        FeesCollectorSetup::new(fees_collector::contract_obj, energy_factory::contract_obj);
+
+    let owner = fc_setup.owner_address.clone();
+    let treasury = fc_setup.b_mock.create_user_account(&rust_zero);
+    let first_user = fc_setup.b_mock.create_user_account(&rust_zero);
+    let second_user = fc_setup.b_mock.create_user_account(&rust_zero);
+    fc_setup.set_energy(&first_user, 500, 1_000_000);
+    fc_setup.set_energy(&second_user, 500, 1_000_000);
+
+    fc_setup.deposit(FIRST_TOKEN_ID, 1_000).assert_ok();
+    fc_setup.claim(&first_user).assert_ok();
+    fc_setup.claim(&second_user).assert_ok();
+
+    // only the first user claims for week 1, and nobody for week 2
+    fc_setup.advance_week();
+    fc_setup.deposit(FIRST_TOKEN_ID, 200).assert_ok();
+    fc_setup.claim(&first_user).assert_ok();
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&first_user, FIRST_TOKEN_ID, &rust_biguint!(500));
+    fc_setup
+        .b_mock
+        .execute_query(&fc_setup.fc_wrapper, |sc| {
+            let remaining_rewards = sc.remaining_rewards_for_week(1).get();
+            assert_eq!(remaining_rewards.get(0).amount, managed_biguint!(500));
+        })
+        .assert_ok();
+
+    fc_setup
+        .b_mock
+        .execute_tx(&owner, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.set_expired_rewards_destination(OptionalValue::Some(managed_address!(&treasury)));
+        })
+        .assert_ok();
+
+    // week 1 is still claimable
+    fc_setup
+        .b_mock
+        .execute_tx(&treasury, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.recover_expired_rewards();
+        })
+        .assert_user_error("Current week must be higher than the week offset");
+
+    for _ in 0..5 {
+        fc_setup.advance_week();
+    }
+    assert_eq!(fc_setup.get_current_week(), 7);
+
+    fc_setup
+        .b_mock
+        .execute_tx(&treasury, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.recover_expired_rewards();
+        })
+        .assert_ok();
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&treasury, FIRST_TOKEN_ID, &rust_biguint!(700));
+    fc_setup
+        .b_mock
+        .execute_query(&fc_setup.fc_wrapper, |sc| {
+            assert_eq!(sc.last_expired_rewards_recovery_week().get(), 2);
+            assert_eq!(
+                sc.expired_rewards_for_week(1).get().get(0).amount,
+                managed_biguint!(500)
+            );
+            assert_eq!(
+                sc.expired_rewards_for_week(2).get().get(0).amount,
+                managed_biguint!(200)
+            );
+            assert!(sc.remaining_rewards_for_week(1).is_empty());
+        })
+        .assert_ok();
+
+    // the same weeks are not recovered twice
+    fc_setup
+        .b_mock
+        .execute_tx(&treasury, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.recover_expired_rewards();
+        })
+        .assert_ok();
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&treasury, FIRST_TOKEN_ID, &rust_biguint!(700));
+
+    // week 7 is fully claimed, so nothing is left to recover for it
+    fc_setup.deposit(FIRST_TOKEN_ID, 400).assert_ok();
+    fc_setup.claim(&first_user).assert_ok();
+    fc_setup.claim(&second_user).assert_ok();
+    fc_setup.advance_week();
+    fc_setup.claim(&first_user).assert_ok();
+    fc_setup.claim(&second_user).assert_ok();
+    fc_setup
+        .b_mock
+        .execute_query(&fc_setup.fc_wrapper, |sc| {
+            let remaining_rewards = sc.remaining_rewards_for_week(7).get();
+            assert_eq!(remaining_rewards.len(), 1);
+            assert_eq!(remaining_rewards.get(0).amount, managed_biguint!(0));
+        })
+        .assert_ok();
+
+    while fc_setup.get_current_week() < 20 {
+        fc_setup.advance_week();
+    }
+
+    // at most MAX_RECOVERED_WEEKS_PER_CALL weeks are handled per call
+    fc_setup
+        .b_mock
+        .execute_tx(&treasury, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.recover_expired_rewards();
+            assert_eq!(
+                sc.last_expired_rewards_recovery_week().get(),
+                2 + MAX_RECOVERED_WEEKS_PER_CALL
+            );
+
+            sc.recover_expired_rewards();
+            assert_eq!(sc.last_expired_rewards_recovery_week().get(), 15);
+        })
+        .assert_ok();
+    fc_setup
+        .b_mock
+        .execute_query(&fc_setup.fc_wrapper, |sc| {
+            assert!(sc.remaining_rewards_for_week(7).is_empty());
+            assert!(sc.expired_rewards_for_week(7).is_empty());
+        })
+        .assert_ok();
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&treasury, FIRST_TOKEN_ID, &rust_biguint!(700));
+}
+
+#[test]
+fn deduct_claimed_rewards_saturates_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut fc_setup =
+        FeesCollectorSetup::new(fees_collector::contract_obj, energy_factory::contract_obj);
+
+    let owner = fc_setup.owner_address.clone();
+    fc_setup
+        .b_mock
+        .execute_tx(&owner, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            let mut remaining_rewards = ManagedVec::new();
+            remaining_rewards.push(EsdtTokenPayment::new(
+                managed_token_id!(FIRST_TOKEN_ID),
+                0,
+                managed_biguint!(10),
+            ));
+            sc.remaining_rewards_for_week(1).set(&remaining_rewards);
+
+            // more than what is left, e.g. from the rounding of the energy shares
+            let mut user_rewards = ManagedVec::new();
+            user_rewards.push(EsdtTokenPayment::new(
+                managed_token_id!(FIRST_TOKEN_ID),
+                0,
+                managed_biguint!(15),
+            ));
+            sc.deduct_claimed_rewards(1, &user_rewards);
+
+            assert_eq!(
+                sc.remaining_rewards_for_week(1).get().get(0).amount,
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           37
+// Endpoints:                           66
 // Async Callback (empty):               1
-// Total number of exported functions:  40
+// Total number of exported functions:  69
 
 #![no_std]
 
@@ -22,12 +22,14 @@ multiversx_sc_wasm_adapter::endpoints! {
         upgrade => upgrade
         claimRewards => claim_rewards_endpoint
         claimBoostedRewards => claim_boosted_rewards
+        claimAndRelock => claim_and_relock
         addKnownContracts => add_known_contracts
         removeKnownContracts => remove_known_contracts
         addKnownTokens => add_known_tokens
         removeKnownTokens => remove_known_tokens
         getLockedTokenId => locked_token_id
         getAllTokens => get_all_tokens
+        getAllTokensPaginated => get_all_tokens_paginated
         getAllKnownContracts => known_contracts
         getAllowExternalClaimRewards => allow_external_claim_rewards
         getLastActiveWeekForUser => get_last_active_week_for_user_view
@@ -40,6 +42,33 @@ multiversx_sc_wasm_adapter::endpoints! {
         getCurrentClaimProgress => current_claim_progress
         depositSwapFees => deposit_swap_fees
         getAccumulatedFees => accumulated_fees
+        setPayoutToken => set_payout_token
+        setConversionConfig => set_conversion_config
+        setConversionRoute => set_conversion_route
+        removeConversionRoute => remove_conversion_route
+        setRelockRoute => set_relock_route
+        removeRelockRoute => remove_relock_route
+        addConversionKeepers => add_conversion_keepers
+        removeConversionKeepers => remove_conversion_keepers
+        convertFees => convert_fees
+        getPayoutTokenId => payout_token_id
+        getConversionRouterAddress => router_address
+        getSafePriceViewAddress => safe_price_view_address
+        getMaxPriceDeviationPercentage => max_price_deviation_percentage
+        getConversionRoute => conversion_route
+        getRelockRoute => relock_route
+        getConversionKeepers => conversion_keepers
+        setFeeSplits => set_fee_splits
+        distributeFeeSplits => distribute_fee_splits
+        rerouteFeeSplit => reroute_fee_split
+        getFeeSplits => fee_splits
+        getFeeSplitResults => fee_split_results
+        setExpiredRewardsDestination => set_expired_rewards_destination
+        recoverExpiredRewards => recover_expired_rewards
+        getRemainingRewardsForWeek => remaining_rewards_for_week
+        getExpiredRewardsForWeek => expired_rewards_for_week
+        getLastExpiredRewardsRecoveryWeek => last_expired_rewards_recovery_week
+        getExpiredRewardsDestination => expired_rewards_destination
         setLockedTokensPerBlock => set_locked_tokens_per_block
         getLastLockedTokensAddWeek => last_locked_token_add_week
         getLockedTokensPerBlock => locked_tokens_per_block
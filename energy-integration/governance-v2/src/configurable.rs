@@ -67,12 +67,6 @@ pub trait ConfigurablePropertiesModule:
         self.try_change_quorum_percentage(new_value);
     }
 
-    #[only_owner]
-    #[endpoint(changeWithdrawPercentage)]
-    fn change_withdraw_percentage(&self, new_value: u64) {
-        self.try_change_withdraw_percentage_defeated(new_value);
-    }
-
     #[only_owner]
     #[endpoint(changeVotingDelayInBlocks)]
     fn change_voting_delay_in_blocks(&self, new_value: u64) {
@@ -129,16 +123,6 @@ pub trait ConfigurablePropertiesModule:
         self.voting_period_in_blocks().set(new_voting_period);
     }
 
-    fn try_change_withdraw_percentage_defeated(&self, new_withdraw_percentage: u64) {
-        require!(
-            new_withdraw_percentage <= FULL_PERCENTAGE,
-            "Not valid value for withdraw percentage if defeated!"
-        );
-
-        self.withdraw_percentage_defeated()
-            .set(new_withdraw_percentage);
-    }
-
     fn try_change_fee_token_id(&self, fee_token_id: TokenIdentifier) {
         require!(fee_token_id.is_valid_esdt_identifier(), ERROR_NOT_AN_ESDT);
         self.fee_token_id().set_if_empty(&fee_token_id);
@@ -171,8 +155,4 @@ pub trait ConfigurablePropertiesModule:
     #[view(getFeeTokenId)]
     #[storage_mapper("feeTokenId")]
     fn fee_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
-
-    #[view(getWithdrawPercentageDefeated)]
-    #[storage_mapper("witdrawPercentageDefeated")]
-    fn withdraw_percentage_defeated(&self) -> SingleValueMapper<u64>;
 }
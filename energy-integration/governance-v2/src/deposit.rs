@@ -0,0 +1,292 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    configurable::FULL_PERCENTAGE,
+    errors::*,
+    proposal::{GovernanceProposal, GovernanceProposalStatus, ProposalId},
+};
+
+pub const MAX_PROPOSAL_SPONSORS: usize = 10;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ProposalDepositState<M: ManagedTypeApi> {
+    pub token_id: TokenIdentifier<M>,
+    pub required_amount: BigUint<M>,
+    pub proposer_amount: BigUint<M>,
+    pub sponsored_amount: BigUint<M>,
+    pub fully_funded: bool,
+    pub refund_percentage: Option<u64>,
+    pub proposer_withdrawn: bool,
+}
+
+/// The proposal deposits can be split between the proposer and up to MAX_PROPOSAL_SPONSORS co-sponsors.
+/// Voting only starts counting once the deposit is complete, and the refund is shared pro rata.
+#[multiversx_sc::module]
+pub trait DepositModule:
+    crate::views::ViewsModule
+    + crate::proposal_storage::ProposalStorageModule
+    + crate::configurable::ConfigurablePropertiesModule
+    + crate::multiple_choice::MultipleChoiceModule
+    + crate::parameter_range::ParameterRangeModule
+    + crate::events::EventsModule
+    + permissions_module::PermissionsModule
+    + energy_query::EnergyQueryModule
+{
+    /// Proposers with at least `min_energy`, at least `min_succeeded_proposals` succeeded proposals
+    /// and no proposals defeated with veto only have to deposit `reduced_fee`.
+    /// A `reduced_fee` of 0 disables the reduction.
+    #[only_owner]
+    #[endpoint(setReducedFeeForPropose)]
+    fn set_reduced_fee_for_propose(
+        &self,
+        reduced_fee: BigUint,
+        min_energy: BigUint,
+        min_succeeded_proposals: u64,
+    ) {
+        require!(
+            reduced_fee <= self.min_fee_for_propose().get(),
+            "Reduced fee higher than the minimum fee"
+        );
+
+        self.reduced_fee_for_propose().set(reduced_fee);
+        self.min_energy_for_reduced_fee().set(min_energy);
+        self.min_succeeded_proposals_for_reduced_fee()
+            .set(min_succeeded_proposals);
+    }
+
+    /// Add to the deposit of a pending proposal, up to the required amount.
+    /// The sponsors withdraw their part of the deposit once the proposal has ended, like the proposer.
+    #[payable("*")]
+    #[endpoint(sponsorProposal)]
+    fn sponsor_proposal(&self, proposal_id: ProposalId) {
+        require!(
+            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Pending,
+            PROPOSAL_NOT_PENDING
+        );
+
+        let payment = self.call_value().single_esdt();
+        let mut proposal = self.proposals().get(proposal_id);
+        require!(
+            payment.token_identifier == proposal.fee_payment.token_identifier,
+            WRONG_TOKEN_ID
+        );
+
+        let missing_amount = self.get_proposal_required_deposit(proposal_id, &proposal)
+            - self.get_proposal_total_deposit(proposal_id, &proposal);
+        require!(payment.amount <= missing_amount, DEPOSIT_TOO_HIGH);
+
+        let caller = self.blockchain().get_caller();
+        if caller == proposal.proposer {
+            proposal.fee_payment.amount += &payment.amount;
+            self.proposals().set(proposal_id, &proposal);
+        } else {
+            let _ = self.proposal_sponsors(proposal_id).insert(caller.clone());
+            require!(
+                self.proposal_sponsors(proposal_id).len() <= MAX_PROPOSAL_SPONSORS,
+                TOO_MANY_SPONSORS
+            );
+
+            self.sponsor_deposit(proposal_id, &caller)
+                .update(|deposit| *deposit += &payment.amount);
+            self.proposal_sponsored_amount(proposal_id)
+                .update(|amount| *amount += &payment.amount);
+        }
+
+        self.proposal_sponsored_event(&caller, proposal_id, &payment.amount);
+    }
+
+    #[view(getRequiredFeeForProposer)]
+    fn get_required_fee_for_proposer(&self, proposer: ManagedAddress) -> BigUint {
+        let min_fee = self.min_fee_for_propose().get();
+        let reduced_fee = self.reduced_fee_for_propose().get();
+        if reduced_fee == 0 || self.proposer_vetoed_proposals(&proposer).get() > 0 {
+            return min_fee;
+        }
+
+        let succeeded_proposals = self.proposer_succeeded_proposals(&proposer).get();
+        if succeeded_proposals < self.min_succeeded_proposals_for_reduced_fee().get() {
+            return min_fee;
+        }
+
+        let proposer_energy = self.get_energy_amount(&proposer);
+        if proposer_energy < self.min_energy_for_reduced_fee().get() {
+            return min_fee;
+        }
+
+        reduced_fee
+    }
+
+    #[view(getProposalDeposit)]
+    fn get_proposal_deposit(&self, proposal_id: ProposalId) -> ProposalDepositState<Self::Api> {
+        require!(self.proposal_exists(proposal_id), NO_PROPOSAL);
+
+        let proposal = self.proposals().get(proposal_id);
+        let status = self.get_proposal_status(proposal_id);
+        ProposalDepositState {
+            required_amount: self.get_proposal_required_deposit(proposal_id, &proposal),
+            sponsored_amount: self.proposal_sponsored_amount(proposal_id).get(),
+            fully_funded: self.is_deposit_fully_funded(proposal_id, &proposal),
+            refund_percentage: self.get_deposit_refund_percentage(proposal_id, &proposal, status),
+            proposer_withdrawn: proposal.fee_withdrawn,
+            token_id: proposal.fee_payment.token_identifier,
+            proposer_amount: proposal.fee_payment.amount,
+        }
+    }
+
+    /// Returns pairs of (sponsor, deposit not yet withdrawn)
+    #[view(getProposalSponsors)]
+    fn get_proposal_sponsors(
+        &self,
+        proposal_id: ProposalId,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for sponsor in self.proposal_sponsors(proposal_id).iter() {
+            let deposit = self.sponsor_deposit(proposal_id, &sponsor).get();
+            result.push((sponsor, deposit).into());
+        }
+
+        result
+    }
+
+    /// The part of the deposit given back to each depositor, once the proposal has ended.
+    /// Proposals whose deposit was never completed could not be voted, so they are refunded in full.
+    fn get_deposit_refund_percentage(
+        &self,
+        proposal_id: ProposalId,
+        proposal: &GovernanceProposal<Self::Api>,
+        status: GovernanceProposalStatus,
+    ) -> Option<u64> {
+        match status {
+            GovernanceProposalStatus::None
+            | GovernanceProposalStatus::Pending
+            | GovernanceProposalStatus::Active => None,
+            _ if !self.is_deposit_fully_funded(proposal_id, proposal) => Some(FULL_PERCENTAGE),
+            GovernanceProposalStatus::Succeeded | GovernanceProposalStatus::Defeated => {
+                Some(FULL_PERCENTAGE)
+            }
+            GovernanceProposalStatus::DefeatedWithVeto => Some(0),
+        }
+    }
+
+    fn refund_deposit(
+        &self,
+        proposal: &GovernanceProposal<Self::Api>,
+        depositor: &ManagedAddress,
+        deposit_amount: &BigUint,
+        refund_percentage: u64,
+    ) {
+        let refund_amount = deposit_amount * refund_percentage / FULL_PERCENTAGE;
+        let withheld_amount = deposit_amount - &refund_amount;
+        self.send().esdt_non_zero_local_burn(
+            &proposal.fee_payment.token_identifier,
+            proposal.fee_payment.token_nonce,
+            &withheld_amount,
+        );
+        self.send().direct_non_zero_esdt_payment(
+            depositor,
+            &EsdtTokenPayment::new(
+                proposal.fee_payment.token_identifier.clone(),
+                proposal.fee_payment.token_nonce,
+                refund_amount,
+            ),
+        );
+    }
+
+    /// Refunds the sponsors in full, e.g. when the proposal is canceled
+    fn refund_all_sponsors(&self, proposal: &GovernanceProposal<Self::Api>) {
+        for sponsor in self.proposal_sponsors(proposal.proposal_id).iter() {
+            let deposit = self.sponsor_deposit(proposal.proposal_id, &sponsor).take();
+            self.refund_deposit(proposal, &sponsor, &deposit, FULL_PERCENTAGE);
+        }
+    }
+
+    /// Burns the whole remaining deposit, returning the burned amount
+    fn slash_deposit(&self, proposal: &GovernanceProposal<Self::Api>) -> BigUint {
+        let mut slashed_amount = proposal.fee_payment.amount.clone();
+        for sponsor in self.proposal_sponsors(proposal.proposal_id).iter() {
+            slashed_amount += self.sponsor_deposit(proposal.proposal_id, &sponsor).take();
+        }
+
+        self.send().esdt_non_zero_local_burn(
+            &proposal.fee_payment.token_identifier,
+            proposal.fee_payment.token_nonce,
+            &slashed_amount,
+        );
+
+        slashed_amount
+    }
+
+    fn clear_proposal_deposit(&self, proposal_id: ProposalId) {
+        self.proposal_required_deposit(proposal_id).clear();
+        self.proposal_sponsored_amount(proposal_id).clear();
+        self.proposal_sponsors(proposal_id).clear();
+    }
+
+    fn is_deposit_fully_funded(
+        &self,
+        proposal_id: ProposalId,
+        proposal: &GovernanceProposal<Self::Api>,
+    ) -> bool {
+        self.get_proposal_total_deposit(proposal_id, proposal)
+            >= self.get_proposal_required_deposit(proposal_id, proposal)
+    }
+
+    fn get_proposal_total_deposit(
+        &self,
+        proposal_id: ProposalId,
+        proposal: &GovernanceProposal<Self::Api>,
+    ) -> BigUint {
+        &proposal.fee_payment.amount + &self.proposal_sponsored_amount(proposal_id).get()
+    }
+
+    /// Proposals created before the co-sponsoring was introduced were always paid in full
+    fn get_proposal_required_deposit(
+        &self,
+        proposal_id: ProposalId,
+        proposal: &GovernanceProposal<Self::Api>,
+    ) -> BigUint {
+        let required_deposit = self.proposal_required_deposit(proposal_id).get();
+        if required_deposit == 0 {
+            proposal.fee_payment.amount.clone()
+        } else {
+            required_deposit
+        }
+    }
+
+    #[view(getReducedFeeForPropose)]
+    #[storage_mapper("reducedFeeForPropose")]
+    fn reduced_fee_for_propose(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getMinEnergyForReducedFee)]
+    #[storage_mapper("minEnergyForReducedFee")]
+    fn min_energy_for_reduced_fee(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getMinSucceededProposalsForReducedFee)]
+    #[storage_mapper("minSucceededProposalsForReducedFee")]
+    fn min_succeeded_proposals_for_reduced_fee(&self) -> SingleValueMapper<u64>;
+
+    #[view(getProposerSucceededProposals)]
+    #[storage_mapper("proposerSucceededProposals")]
+    fn proposer_succeeded_proposals(&self, proposer: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[view(getProposerVetoedProposals)]
+    #[storage_mapper("proposerVetoedProposals")]
+    fn proposer_vetoed_proposals(&self, proposer: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposalRequiredDeposit")]
+    fn proposal_required_deposit(&self, proposal_id: ProposalId) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("proposalSponsoredAmount")]
+    fn proposal_sponsored_amount(&self, proposal_id: ProposalId) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("proposalSponsors")]
+    fn proposal_sponsors(&self, proposal_id: ProposalId) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("sponsorDeposit")]
+    fn sponsor_deposit(
+        &self,
+        proposal_id: ProposalId,
+        sponsor: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+}
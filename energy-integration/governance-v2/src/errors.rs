@@ -9,6 +9,24 @@ pub const EXEEDED_MAX_ACTIONS: &[u8] = b"Exceeded max actions per proposal";
 pub const ONLY_PROPOSER_CANCEL: &[u8] = b"Only original proposer may cancel a pending proposal";
 pub const ONLY_PROPOSER_WITHDRAW: &[u8] = b"Only original proposer may withdraw a pending proposal";
 pub const FEE_ALREADY_WITHDRAWN: &[u8] = b"Fee already withdrawn!";
+pub const SPONSOR_DEPOSIT_ALREADY_WITHDRAWN: &[u8] = b"Sponsor deposit already withdrawn";
 pub const NO_PROPOSAL: &[u8] = b"Proposal does not exist";
 pub const WITHDRAW_NOT_ALLOWED: &[u8] = b"You may not withdraw funds from this proposal!";
 pub const PROPOSAL_NOT_ALLOWED_FOR_SC: &[u8] = b"Smart Contracts are not allowed to propose!";
+pub const INVALID_OPTIONS_COUNT: &[u8] = b"Invalid number of options";
+pub const EMPTY_OPTION_LABEL: &[u8] = b"Empty option label";
+pub const INVALID_OPTION: &[u8] = b"Invalid option";
+pub const NOT_MULTIPLE_CHOICE_PROPOSAL: &[u8] = b"Not a multiple-choice proposal";
+pub const MULTIPLE_CHOICE_PROPOSAL: &[u8] =
+    b"Multiple-choice proposals are voted through voteForOption";
+pub const INVALID_PARAMETER_RANGE: &[u8] = b"Invalid parameter range";
+pub const VALUE_OUT_OF_RANGE: &[u8] = b"Value out of range";
+pub const NOT_PARAMETER_RANGE_PROPOSAL: &[u8] = b"Not a parameter range proposal";
+pub const PARAMETER_RANGE_PROPOSAL: &[u8] =
+    b"Parameter range proposals are voted through voteForValue";
+pub const PROPOSAL_NOT_PENDING: &[u8] = b"Proposal is not pending";
+pub const DEPOSIT_TOO_HIGH: &[u8] = b"Deposit higher than required";
+pub const TOO_MANY_SPONSORS: &[u8] = b"Too many sponsors";
+pub const DEPOSIT_NOT_COMPLETE: &[u8] = b"Proposal deposit is not complete";
+pub const NO_ENERGY_AT_SNAPSHOT: &[u8] = b"No energy at the proposal snapshot epoch";
+pub const NO_TOTAL_ENERGY_AT_SNAPSHOT: &[u8] = b"No total energy at the proposal snapshot epoch";
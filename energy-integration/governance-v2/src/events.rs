@@ -50,6 +50,41 @@ pub trait EventsModule {
         #[indexed] user_quorum: &BigUint,
     );
 
+    #[event("optionVoteCast")]
+    fn option_vote_cast_event(
+        &self,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] proposal_id: ProposalId,
+        #[indexed] option_id: usize,
+        #[indexed] voting_power: &BigUint,
+        #[indexed] user_quorum: &BigUint,
+    );
+
+    #[event("valueVoteCast")]
+    fn value_vote_cast_event(
+        &self,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] proposal_id: ProposalId,
+        #[indexed] value: &BigUint,
+        #[indexed] voting_power: &BigUint,
+        #[indexed] user_quorum: &BigUint,
+    );
+
+    #[event("proposalSponsored")]
+    fn proposal_sponsored_event(
+        &self,
+        #[indexed] sponsor: &ManagedAddress,
+        #[indexed] proposal_id: ProposalId,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("proposalDepositSlashed")]
+    fn proposal_deposit_slashed_event(
+        &self,
+        #[indexed] proposal_id: ProposalId,
+        #[indexed] slashed_amount: &BigUint,
+    );
+
     #[event("proposalCanceled")]
     fn proposal_canceled_event(&self, #[indexed] proposal_id: ProposalId);
 
@@ -3,12 +3,21 @@
 multiversx_sc::imports!();
 
 pub mod configurable;
+pub mod deposit;
 mod errors;
 pub mod events;
+pub mod multiple_choice;
+pub mod parameter_range;
 pub mod proposal;
 pub mod proposal_storage;
 pub mod views;
 
+use energy_factory::energy::ProxyTrait as _;
+use energy_factory::energy_checkpoints::ProxyTrait as _;
+use multiple_choice::{
+    OptionId, ProposalOption, ProposalOptionVotes, MAX_PROPOSAL_OPTIONS, MIN_PROPOSAL_OPTIONS,
+};
+use parameter_range::{ParameterRange, ParameterRangeVotes};
 use proposal::*;
 use proposal_storage::VoteType;
 use weekly_rewards_splitting::events::Week;
@@ -25,6 +34,9 @@ pub trait GovernanceV2:
     + events::EventsModule
     + proposal_storage::ProposalStorageModule
     + views::ViewsModule
+    + multiple_choice::MultipleChoiceModule
+    + parameter_range::ParameterRangeModule
+    + deposit::DepositModule
     + energy_query::EnergyQueryModule
     + permissions_module::PermissionsModule
 {
@@ -33,7 +45,6 @@ pub trait GovernanceV2:
     /// - `quorum_percentage` - the minimum number of (`votes` minus `downvotes`) at the end of voting period  
     /// - `votingDelayInBlocks` - Number of blocks to wait after a block is proposed before being able to vote/downvote that proposal
     /// - `votingPeriodInBlocks` - Number of blocks the voting period lasts (voting delay does not count towards this)  
-    /// - `withdraw_percentage_defeated` - Percetange of the fee to be returned if proposal defetead
     /// - `energy_factory_address`
     /// - `fees_collector_address`
     /// - `fee_token` - The token used to pay the fee
@@ -45,7 +56,6 @@ pub trait GovernanceV2:
         quorum_percentage: u64,
         voting_delay_in_blocks: u64,
         voting_period_in_blocks: u64,
-        withdraw_percentage_defeated: u64,
         energy_factory_address: ManagedAddress,
         fees_collector_address: ManagedAddress,
         fee_token: TokenIdentifier,
@@ -55,7 +65,6 @@ pub trait GovernanceV2:
         self.try_change_quorum_percentage(quorum_percentage);
         self.try_change_voting_delay_in_blocks(voting_delay_in_blocks);
         self.try_change_voting_period_in_blocks(voting_period_in_blocks);
-        self.try_change_withdraw_percentage_defeated(withdraw_percentage_defeated);
         self.set_energy_factory_address(energy_factory_address);
         self.fees_collector_address().set(&fees_collector_address);
         self.try_change_fee_token_id(fee_token);
@@ -77,55 +86,140 @@ pub trait GovernanceV2:
         description: ManagedBuffer,
         actions: MultiValueEncoded<GovernanceActionAsMultiArg<Self::Api>>,
     ) -> ProposalId {
-        let proposer = self.blockchain().get_caller();
-        require!(
-            !self.blockchain().is_smart_contract(&proposer),
-            PROPOSAL_NOT_ALLOWED_FOR_SC
-        );
-
+        self.require_valid_proposer();
         require!(
             actions.len() <= MAX_GOVERNANCE_PROPOSAL_ACTIONS,
             EXEEDED_MAX_ACTIONS
         );
 
-        let user_energy = self.get_energy_amount(&proposer);
-        let min_energy_for_propose = self.min_energy_for_propose().get();
-        require!(user_energy >= min_energy_for_propose, NOT_ENOUGH_ENERGY);
+        let mut gov_actions = ArrayVec::new();
+        for action_multiarg in actions {
+            let gov_action = GovernanceAction::from(action_multiarg);
+            self.require_valid_action_gas(&gov_action);
+
+            unsafe {
+                gov_actions.push_unchecked(gov_action);
+            }
+        }
 
-        let user_fee = self.call_value().single_esdt();
         require!(
-            self.fee_token_id().get() == user_fee.token_identifier,
-            WRONG_TOKEN_ID
+            self.total_gas_needed(&gov_actions) < MAX_GAS_LIMIT_PER_BLOCK,
+            TOO_MUCH_GAS
         );
+
+        self.create_proposal(description, gov_actions)
+    }
+
+    /// Propose between MIN_PROPOSAL_OPTIONS and MAX_PROPOSAL_OPTIONS labelled options,
+    /// each with its own, possibly empty, list of actions.
+    ///
+    /// The proposal is voted through voteForOption, with the same voting power as the other proposals.
+    /// The option with the most votes wins, if the energy of its voters reaches the quorum.
+    ///
+    /// Returns the ID of the newly created proposal.
+    #[payable("*")]
+    #[endpoint(proposeMultipleChoice)]
+    fn propose_multiple_choice(
+        &self,
+        description: ManagedBuffer,
+        options: MultiValueEncoded<ProposalOption<Self::Api>>,
+    ) -> ProposalId {
+        self.require_valid_proposer();
         require!(
-            self.min_fee_for_propose().get() == user_fee.amount,
-            NOT_ENOUGH_FEE
+            (MIN_PROPOSAL_OPTIONS..=MAX_PROPOSAL_OPTIONS).contains(&options.len()),
+            INVALID_OPTIONS_COUNT
         );
 
-        let mut gov_actions = ArrayVec::new();
-        for action_multiarg in actions {
-            let gov_action = GovernanceAction::from(action_multiarg);
+        let options = options.to_vec();
+        for option in &options {
+            require!(!option.label.is_empty(), EMPTY_OPTION_LABEL);
             require!(
-                gov_action.gas_limit < MAX_GAS_LIMIT_PER_BLOCK,
-                "A single action cannot use more than the max gas limit per block"
+                option.actions.len() <= MAX_GOVERNANCE_PROPOSAL_ACTIONS,
+                EXEEDED_MAX_ACTIONS
             );
 
-            unsafe {
-                gov_actions.push_unchecked(gov_action);
+            let mut total_gas = 0;
+            for action in &option.actions {
+                self.require_valid_action_gas(&action);
+                total_gas += action.gas_limit;
             }
+            require!(total_gas < MAX_GAS_LIMIT_PER_BLOCK, TOO_MUCH_GAS);
+        }
+
+        let proposal_id = self.create_proposal(description, ArrayVec::new());
+        let mut options_mapper = self.proposal_options(proposal_id);
+        for option in &options {
+            let option_id = options_mapper.push(&option);
+            self.proposal_option_votes(proposal_id, option_id)
+                .set(ProposalOptionVotes::default());
         }
 
+        proposal_id
+    }
+
+    /// Propose picking a value for a parameter, between `min_value` and `max_value`, inclusive.
+    ///
+    /// The proposal is voted through voteForValue, with the same voting power as the other proposals.
+    /// The result is the average of the voted values, weighted by the voting power,
+    /// if the energy of the voters reaches the quorum.
+    ///
+    /// Returns the ID of the newly created proposal.
+    #[payable("*")]
+    #[endpoint(proposeParameterRange)]
+    fn propose_parameter_range(
+        &self,
+        description: ManagedBuffer,
+        min_value: BigUint,
+        max_value: BigUint,
+    ) -> ProposalId {
+        self.require_valid_proposer();
+        require!(min_value < max_value, INVALID_PARAMETER_RANGE);
+
+        let proposal_id = self.create_proposal(description, ArrayVec::new());
+        self.proposal_parameter_range(proposal_id)
+            .set(ParameterRange {
+                min_value,
+                max_value,
+            });
+        self.parameter_range_votes(proposal_id)
+            .set(ParameterRangeVotes::default());
+
+        proposal_id
+    }
+
+    fn create_proposal(
+        &self,
+        description: ManagedBuffer,
+        gov_actions: ArrayVec<GovernanceAction<Self::Api>, MAX_GOVERNANCE_PROPOSAL_ACTIONS>,
+    ) -> ProposalId {
+        let proposer = self.blockchain().get_caller();
+        let user_energy = self.get_energy_amount(&proposer);
+        let min_energy_for_propose = self.min_energy_for_propose().get();
+        require!(user_energy >= min_energy_for_propose, NOT_ENOUGH_ENERGY);
+
+        // the rest of the deposit can be added by sponsors, before the voting starts
+        let required_deposit = self.get_required_fee_for_proposer(proposer.clone());
+        let user_fee = self.call_value().single_esdt();
         require!(
-            self.total_gas_needed(&gov_actions) < MAX_GAS_LIMIT_PER_BLOCK,
-            TOO_MUCH_GAS
+            self.fee_token_id().get() == user_fee.token_identifier,
+            WRONG_TOKEN_ID
         );
+        require!(user_fee.amount > 0, NOT_ENOUGH_FEE);
+        require!(user_fee.amount <= required_deposit, DEPOSIT_TOO_HIGH);
 
         let minimum_quorum = self.quorum_percentage().get();
         let voting_delay_in_blocks = self.voting_delay_in_blocks().get();
         let voting_period_in_blocks = self.voting_period_in_blocks().get();
-        let withdraw_percentage_defeated = self.withdraw_percentage_defeated().get();
         let current_block = self.blockchain().get_block_nonce();
 
+        // energy gained after the proposal was created does not count towards it
+        let snapshot_epoch = self.blockchain().get_block_epoch().saturating_sub(1);
+        let total_quorum = match self.get_total_energy_at_epoch(snapshot_epoch) {
+            OptionalValue::Some(total_energy) => total_energy,
+            OptionalValue::None => sc_panic!(NO_TOTAL_ENERGY_AT_SNAPSHOT),
+        };
+        require!(total_quorum > 0, NO_TOTAL_ENERGY_AT_SNAPSHOT);
+
         let proposal = GovernanceProposal {
             proposal_id: self.proposals().len() + 1,
             proposer: proposer.clone(),
@@ -135,12 +229,16 @@ pub trait GovernanceV2:
             minimum_quorum,
             voting_delay_in_blocks,
             voting_period_in_blocks,
-            withdraw_percentage_defeated,
-            total_quorum: BigUint::zero(),
+            withdraw_percentage_defeated: 0,
+            total_quorum,
             proposal_start_block: current_block,
             fee_withdrawn: false,
         };
         let proposal_id = self.proposals().push(&proposal);
+        self.proposal_required_deposit(proposal_id)
+            .set(required_deposit);
+        self.proposal_snapshot_epoch(proposal_id)
+            .set(Some(snapshot_epoch));
 
         self.proposal_votes(proposal_id)
             .set(ProposalVotes::default());
@@ -149,41 +247,20 @@ pub trait GovernanceV2:
         proposal_id
     }
 
-    /// Vote on a proposal. The voting power depends on the user's energy.
+    /// Vote on a proposal. The voting power depends on the user's energy at the proposal's snapshot epoch.
     #[endpoint]
     fn vote(&self, proposal_id: ProposalId, vote: VoteType) {
         self.require_valid_proposal_id(proposal_id);
         require!(
-            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
-            PROPOSAL_NOT_ACTIVE
+            !self.is_multiple_choice_proposal(proposal_id),
+            MULTIPLE_CHOICE_PROPOSAL
+        );
+        require!(
+            !self.is_parameter_range_proposal(proposal_id),
+            PARAMETER_RANGE_PROPOSAL
         );
 
-        let voter = self.blockchain().get_caller();
-        let new_user = self.user_voted_proposals(&voter).insert(proposal_id);
-        require!(new_user, ALREADY_VOTED_ERR_MSG);
-
-        let current_quorum = self.proposal_votes(proposal_id).get().quorum;
-
-        // First voter -> update total_energy
-        if current_quorum == BigUint::zero() {
-            let fees_collector_addr = self.fees_collector_address().get();
-            let last_global_update_week: Week = self
-                .fees_collector_proxy(fees_collector_addr.clone())
-                .last_global_update_week()
-                .execute_on_dest_context();
-
-            let total_quorum: BigUint = self
-                .fees_collector_proxy(fees_collector_addr)
-                .total_energy_for_week(last_global_update_week)
-                .execute_on_dest_context();
-
-            let mut proposal = self.proposals().get(proposal_id);
-            proposal.total_quorum = total_quorum;
-            self.proposals().set(proposal_id, &proposal);
-        }
-
-        let user_quorum = self.get_energy_amount_non_zero(&voter);
-        let voting_power = self.smoothing_function(&user_quorum);
+        let (voter, user_quorum, voting_power) = self.register_vote(proposal_id);
 
         match vote {
             VoteType::UpVote => {
@@ -217,6 +294,59 @@ pub trait GovernanceV2:
         }
     }
 
+    /// Vote for one of the options of a multiple-choice proposal. Option IDs start from 1.
+    #[endpoint(voteForOption)]
+    fn vote_for_option(&self, proposal_id: ProposalId, option_id: OptionId) {
+        self.require_valid_proposal_id(proposal_id);
+        require!(
+            self.is_multiple_choice_proposal(proposal_id),
+            NOT_MULTIPLE_CHOICE_PROPOSAL
+        );
+        require!(
+            option_id >= 1 && option_id <= self.proposal_options(proposal_id).len(),
+            INVALID_OPTION
+        );
+
+        let (voter, user_quorum, voting_power) = self.register_vote(proposal_id);
+        self.proposal_option_votes(proposal_id, option_id)
+            .update(|option_votes| {
+                option_votes.votes += &voting_power;
+                option_votes.quorum += &user_quorum;
+            });
+        self.proposal_votes(proposal_id).update(|proposal_votes| {
+            proposal_votes.quorum += &user_quorum;
+        });
+        self.option_vote_cast_event(&voter, proposal_id, option_id, &voting_power, &user_quorum);
+    }
+
+    /// Vote for a value of a parameter range proposal, within the proposed range.
+    #[endpoint(voteForValue)]
+    fn vote_for_value(&self, proposal_id: ProposalId, value: BigUint) {
+        self.require_valid_proposal_id(proposal_id);
+        require!(
+            self.is_parameter_range_proposal(proposal_id),
+            NOT_PARAMETER_RANGE_PROPOSAL
+        );
+
+        let range = self.proposal_parameter_range(proposal_id).get();
+        require!(
+            value >= range.min_value && value <= range.max_value,
+            VALUE_OUT_OF_RANGE
+        );
+
+        let (voter, user_quorum, voting_power) = self.register_vote(proposal_id);
+        self.parameter_range_votes(proposal_id)
+            .update(|range_votes| {
+                range_votes.weighted_values_sum += &value * &voting_power;
+                range_votes.votes += &voting_power;
+                range_votes.quorum += &user_quorum;
+            });
+        self.proposal_votes(proposal_id).update(|proposal_votes| {
+            proposal_votes.quorum += &user_quorum;
+        });
+        self.value_vote_cast_event(&voter, proposal_id, &value, &voting_power, &user_quorum);
+    }
+
     /// Cancel a proposed action. This can be done only during Pending status
     #[endpoint]
     fn cancel(&self, proposal_id: ProposalId) {
@@ -230,7 +360,11 @@ pub trait GovernanceV2:
 
                 require!(caller == proposal.proposer, ONLY_PROPOSER_CANCEL);
                 self.refund_proposal_fee(&proposal, &proposal.fee_payment.amount);
+                self.refund_all_sponsors(&proposal);
                 self.clear_proposal(proposal_id);
+                self.clear_proposal_options(proposal_id);
+                self.clear_parameter_range(proposal_id);
+                self.clear_proposal_deposit(proposal_id);
                 self.proposal_canceled_event(proposal_id);
             }
             _ => {
@@ -239,55 +373,154 @@ pub trait GovernanceV2:
         }
     }
 
-    /// When a proposal was defeated, the proposer can withdraw
-    /// If DefeatedWithVeto only part of the fee  can be withdrawn
+    /// Once a proposal has ended, the proposer and each sponsor withdraw their part of the deposit.
+    /// Succeeded and Defeated proposals, and the ones whose deposit was never completed, are refunded in full.
+    /// The whole deposit of DefeatedWithVeto proposals is burned, by anyone calling this endpoint.
     #[endpoint(withdrawDeposit)]
     fn withdraw_deposit(&self, proposal_id: ProposalId) {
-        let caller = self.blockchain().get_caller();
+        let status = self.get_proposal_status(proposal_id);
+        require!(status != GovernanceProposalStatus::None, NO_PROPOSAL);
 
-        match self.get_proposal_status(proposal_id) {
-            GovernanceProposalStatus::None => {
-                sc_panic!(NO_PROPOSAL);
+        let caller = self.blockchain().get_caller();
+        let mut proposal = self.proposals().get(proposal_id);
+        let is_succeeded = status == GovernanceProposalStatus::Succeeded;
+        let is_vetoed = status == GovernanceProposalStatus::DefeatedWithVeto;
+        let refund_percentage =
+            match self.get_deposit_refund_percentage(proposal_id, &proposal, status) {
+                Some(refund_percentage) => refund_percentage,
+                None => sc_panic!(WITHDRAW_NOT_ALLOWED),
+            };
+
+        if is_vetoed && self.is_deposit_fully_funded(proposal_id, &proposal) {
+            require!(!proposal.fee_withdrawn, FEE_ALREADY_WITHDRAWN);
+
+            let slashed_amount = self.slash_deposit(&proposal);
+            proposal.fee_withdrawn = true;
+            self.proposals().set(proposal_id, &proposal);
+            self.proposer_vetoed_proposals(&proposal.proposer)
+                .update(|vetoed| *vetoed += 1);
+            self.proposal_deposit_slashed_event(proposal_id, &slashed_amount);
+        } else if caller == proposal.proposer {
+            require!(!proposal.fee_withdrawn, FEE_ALREADY_WITHDRAWN);
+
+            self.refund_deposit(
+                &proposal,
+                &caller,
+                &proposal.fee_payment.amount,
+                refund_percentage,
+            );
+            proposal.fee_withdrawn = true;
+            self.proposals().set(proposal_id, &proposal);
+            if is_succeeded {
+                self.proposer_succeeded_proposals(&caller)
+                    .update(|succeeded| *succeeded += 1);
             }
-            GovernanceProposalStatus::Succeeded | GovernanceProposalStatus::Defeated => {
-                let mut proposal = self.proposals().get(proposal_id);
+        } else {
+            require!(
+                self.proposal_sponsors(proposal_id).contains(&caller),
+                ONLY_PROPOSER_WITHDRAW
+            );
 
-                require!(caller == proposal.proposer, ONLY_PROPOSER_WITHDRAW);
-                require!(!proposal.fee_withdrawn, FEE_ALREADY_WITHDRAWN);
+            let sponsor_deposit = self.sponsor_deposit(proposal_id, &caller).take();
+            require!(sponsor_deposit > 0, SPONSOR_DEPOSIT_ALREADY_WITHDRAWN);
 
-                self.refund_proposal_fee(&proposal, &proposal.fee_payment.amount);
-                proposal.fee_withdrawn = true;
-                self.proposals().set(proposal_id, &proposal);
-            }
-            GovernanceProposalStatus::DefeatedWithVeto => {
-                let mut proposal = self.proposals().get(proposal_id);
-
-                require!(!proposal.fee_withdrawn, FEE_ALREADY_WITHDRAWN);
-
-                let refund_percentage = BigUint::from(proposal.withdraw_percentage_defeated);
-                let refund_amount =
-                    refund_percentage * proposal.fee_payment.amount.clone() / FULL_PERCENTAGE;
-
-                // Burn remaining fees
-                let remaining_fee = proposal.fee_payment.amount.clone() - refund_amount.clone();
-                self.send().esdt_non_zero_local_burn(
-                    &proposal.fee_payment.token_identifier,
-                    proposal.fee_payment.token_nonce,
-                    &remaining_fee,
-                );
-
-                // Mark this proposal that fee is withdrawn
-                self.refund_proposal_fee(&proposal, &refund_amount);
-                proposal.fee_withdrawn = true;
-                self.proposals().set(proposal_id, &proposal);
-            }
-            _ => {
-                sc_panic!(WITHDRAW_NOT_ALLOWED);
-            }
+            self.refund_deposit(&proposal, &caller, &sponsor_deposit, refund_percentage);
         }
+
         self.proposal_withdraw_after_defeated_event(proposal_id);
     }
 
+    fn require_valid_proposer(&self) {
+        let proposer = self.blockchain().get_caller();
+        require!(
+            !self.blockchain().is_smart_contract(&proposer),
+            PROPOSAL_NOT_ALLOWED_FOR_SC
+        );
+    }
+
+    fn require_valid_action_gas(&self, action: &GovernanceAction<Self::Api>) {
+        require!(
+            action.gas_limit < MAX_GAS_LIMIT_PER_BLOCK,
+            "A single action cannot use more than the max gas limit per block"
+        );
+    }
+
+    /// Returns the voter, their energy and their voting power
+    fn register_vote(&self, proposal_id: ProposalId) -> (ManagedAddress, BigUint, BigUint) {
+        require!(
+            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
+            PROPOSAL_NOT_ACTIVE
+        );
+
+        let proposal = self.proposals().get(proposal_id);
+        require!(
+            self.is_deposit_fully_funded(proposal_id, &proposal),
+            DEPOSIT_NOT_COMPLETE
+        );
+
+        let voter = self.blockchain().get_caller();
+        let new_user = self.user_voted_proposals(&voter).insert(proposal_id);
+        require!(new_user, ALREADY_VOTED_ERR_MSG);
+
+        let user_quorum = self.get_voter_energy_for_proposal(&voter, proposal_id);
+        let voting_power = self.smoothing_function(&user_quorum);
+
+        (voter, user_quorum, voting_power)
+    }
+
+    fn get_voter_energy_for_proposal(
+        &self,
+        voter: &ManagedAddress,
+        proposal_id: ProposalId,
+    ) -> BigUint {
+        let snapshot_epoch = match self.proposal_snapshot_epoch(proposal_id).get() {
+            Some(snapshot_epoch) => snapshot_epoch,
+            None => return self.get_legacy_voter_energy(voter, proposal_id),
+        };
+
+        let energy_factory_address = self.energy_factory_address().get();
+        let user_energy: BigUint = self
+            .energy_factory_proxy(energy_factory_address)
+            .get_energy_amount_at_epoch(voter.clone(), snapshot_epoch)
+            .execute_on_dest_context();
+        require!(user_energy > 0, NO_ENERGY_AT_SNAPSHOT);
+
+        user_energy
+    }
+
+    /// Proposals created before the snapshots use the live energy,
+    /// and the total energy of the fees collector's last global update week
+    fn get_legacy_voter_energy(&self, voter: &ManagedAddress, proposal_id: ProposalId) -> BigUint {
+        let current_quorum = self.proposal_votes(proposal_id).get().quorum;
+
+        // First voter -> update total_energy
+        if current_quorum == BigUint::zero() {
+            let fees_collector_addr = self.fees_collector_address().get();
+            let last_global_update_week: Week = self
+                .fees_collector_proxy(fees_collector_addr.clone())
+                .last_global_update_week()
+                .execute_on_dest_context();
+
+            let total_quorum: BigUint = self
+                .fees_collector_proxy(fees_collector_addr)
+                .total_energy_for_week(last_global_update_week)
+                .execute_on_dest_context();
+
+            let mut proposal = self.proposals().get(proposal_id);
+            proposal.total_quorum = total_quorum;
+            self.proposals().set(proposal_id, &proposal);
+        }
+
+        self.get_energy_amount_non_zero(voter)
+    }
+
+    fn get_total_energy_at_epoch(&self, epoch: u64) -> OptionalValue<BigUint> {
+        let energy_factory_address = self.energy_factory_address().get();
+        self.energy_factory_proxy(energy_factory_address)
+            .get_total_energy_at_epoch(epoch)
+            .execute_on_dest_context()
+    }
+
     fn total_gas_needed(
         &self,
         actions: &ArrayVec<GovernanceAction<Self::Api>, MAX_GOVERNANCE_PROPOSAL_ACTIONS>,
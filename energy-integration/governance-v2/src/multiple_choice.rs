@@ -0,0 +1,105 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::configurable::FULL_PERCENTAGE;
+use crate::proposal::{GovernanceAction, ProposalId};
+
+pub const MIN_PROPOSAL_OPTIONS: usize = 2;
+pub const MAX_PROPOSAL_OPTIONS: usize = 10;
+
+pub type OptionId = usize;
+
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug,
+)]
+pub struct ProposalOption<M: ManagedTypeApi> {
+    pub label: ManagedBuffer<M>,
+    pub actions: ManagedVec<M, GovernanceAction<M>>,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ProposalOptionVotes<M: ManagedTypeApi> {
+    pub votes: BigUint<M>,
+    pub quorum: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> Default for ProposalOptionVotes<M> {
+    fn default() -> Self {
+        ProposalOptionVotes {
+            votes: BigUint::zero(),
+            quorum: BigUint::zero(),
+        }
+    }
+}
+
+/// Proposals where the voters pick one of several labelled options, instead of voting for or against.
+/// The option with the most votes wins, if the energy of its voters reaches the proposal's quorum.
+#[multiversx_sc::module]
+pub trait MultipleChoiceModule: crate::proposal_storage::ProposalStorageModule {
+    /// The option that has the most votes and clears the quorum, if any. Ties have no winner.
+    #[view(getWinningOption)]
+    fn get_winning_option_view(&self, proposal_id: ProposalId) -> OptionalValue<OptionId> {
+        self.get_winning_option(proposal_id).into()
+    }
+
+    #[view(getProposalOptionVotes)]
+    fn get_proposal_option_votes(
+        &self,
+        proposal_id: ProposalId,
+    ) -> MultiValueEncoded<ProposalOptionVotes<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for option_id in 1..=self.proposal_options(proposal_id).len() {
+            result.push(self.proposal_option_votes(proposal_id, option_id).get());
+        }
+
+        result
+    }
+
+    fn get_winning_option(&self, proposal_id: ProposalId) -> Option<OptionId> {
+        let mut opt_winner = None;
+        let mut max_votes = BigUint::zero();
+        for option_id in 1..=self.proposal_options(proposal_id).len() {
+            let option_votes = self.proposal_option_votes(proposal_id, option_id).get();
+            if option_votes.votes > max_votes {
+                max_votes = option_votes.votes;
+                opt_winner = Some((option_id, option_votes.quorum));
+            } else if option_votes.votes == max_votes {
+                opt_winner = None;
+            }
+        }
+
+        let (option_id, option_quorum) = opt_winner?;
+        let proposal = self.proposals().get(proposal_id);
+        let required_minimum_percentage = BigUint::from(proposal.minimum_quorum);
+        if option_quorum * FULL_PERCENTAGE >= required_minimum_percentage * proposal.total_quorum {
+            Some(option_id)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn is_multiple_choice_proposal(&self, proposal_id: ProposalId) -> bool {
+        !self.proposal_options(proposal_id).is_empty()
+    }
+
+    fn clear_proposal_options(&self, proposal_id: ProposalId) {
+        let mut options_mapper = self.proposal_options(proposal_id);
+        for option_id in 1..=options_mapper.len() {
+            self.proposal_option_votes(proposal_id, option_id).clear();
+        }
+
+        options_mapper.clear();
+    }
+
+    #[view(getProposalOptions)]
+    #[storage_mapper("proposalOptions")]
+    fn proposal_options(&self, proposal_id: ProposalId) -> VecMapper<ProposalOption<Self::Api>>;
+
+    #[storage_mapper("proposalOptionVotes")]
+    fn proposal_option_votes(
+        &self,
+        proposal_id: ProposalId,
+        option_id: OptionId,
+    ) -> SingleValueMapper<ProposalOptionVotes<Self::Api>>;
+}
@@ -0,0 +1,81 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::configurable::FULL_PERCENTAGE;
+use crate::proposal::ProposalId;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ParameterRange<M: ManagedTypeApi> {
+    pub min_value: BigUint<M>,
+    pub max_value: BigUint<M>,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ParameterRangeVotes<M: ManagedTypeApi> {
+    pub weighted_values_sum: BigUint<M>,
+    pub votes: BigUint<M>,
+    pub quorum: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> Default for ParameterRangeVotes<M> {
+    fn default() -> Self {
+        ParameterRangeVotes {
+            weighted_values_sum: BigUint::zero(),
+            votes: BigUint::zero(),
+            quorum: BigUint::zero(),
+        }
+    }
+}
+
+/// Proposals where the voters pick a value for a parameter, out of a range, instead of voting for or against.
+/// The result is the average of the picked values, weighted by the voting power,
+/// if the energy of all the voters reaches the proposal's quorum.
+#[multiversx_sc::module]
+pub trait ParameterRangeModule: crate::proposal_storage::ProposalStorageModule {
+    /// The value picked by the voters, if the quorum was reached
+    #[view(getParameterRangeResult)]
+    fn get_parameter_range_result_view(&self, proposal_id: ProposalId) -> OptionalValue<BigUint> {
+        self.get_parameter_range_result(proposal_id).into()
+    }
+
+    fn get_parameter_range_result(&self, proposal_id: ProposalId) -> Option<BigUint> {
+        let range_votes = self.parameter_range_votes(proposal_id).get();
+        if range_votes.votes == 0 {
+            return None;
+        }
+
+        let proposal = self.proposals().get(proposal_id);
+        let required_minimum_percentage = BigUint::from(proposal.minimum_quorum);
+        if range_votes.quorum * FULL_PERCENTAGE
+            < required_minimum_percentage * proposal.total_quorum
+        {
+            return None;
+        }
+
+        Some(range_votes.weighted_values_sum / range_votes.votes)
+    }
+
+    #[inline]
+    fn is_parameter_range_proposal(&self, proposal_id: ProposalId) -> bool {
+        !self.proposal_parameter_range(proposal_id).is_empty()
+    }
+
+    fn clear_parameter_range(&self, proposal_id: ProposalId) {
+        self.proposal_parameter_range(proposal_id).clear();
+        self.parameter_range_votes(proposal_id).clear();
+    }
+
+    #[view(getProposalParameterRange)]
+    #[storage_mapper("proposalParameterRange")]
+    fn proposal_parameter_range(
+        &self,
+        proposal_id: ProposalId,
+    ) -> SingleValueMapper<ParameterRange<Self::Api>>;
+
+    #[view(getParameterRangeVotes)]
+    #[storage_mapper("parameterRangeVotes")]
+    fn parameter_range_votes(
+        &self,
+        proposal_id: ProposalId,
+    ) -> SingleValueMapper<ParameterRangeVotes<Self::Api>>;
+}
@@ -20,7 +20,17 @@ pub enum GovernanceProposalStatus {
     Succeeded,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+#[derive(
+    TypeAbi,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    ManagedVecItem,
+    Clone,
+    PartialEq,
+    Debug,
+)]
 pub struct GovernanceAction<M: ManagedTypeApi> {
     pub gas_limit: u64,
     pub dest_address: ManagedAddress<M>,
@@ -64,6 +74,7 @@ pub struct GovernanceProposal<M: ManagedTypeApi> {
     pub minimum_quorum: u64,
     pub voting_delay_in_blocks: u64,
     pub voting_period_in_blocks: u64,
+    /// Unused, kept for the storage layout of existing proposals
     pub withdraw_percentage_defeated: u64,
     pub total_quorum: BigUint<M>,
     pub proposal_start_block: u64,
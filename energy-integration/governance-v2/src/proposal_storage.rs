@@ -56,6 +56,12 @@ pub trait ProposalStorageModule {
     #[storage_mapper("userVotedProposals")]
     fn user_voted_proposals(&self, user: &ManagedAddress) -> UnorderedSetMapper<ProposalId>;
 
+    /// The epoch at which the voting power and the total energy are measured.
+    /// None for the proposals created before the snapshots were introduced.
+    #[view(getProposalSnapshotEpoch)]
+    #[storage_mapper("proposalSnapshotEpoch")]
+    fn proposal_snapshot_epoch(&self, proposal_id: ProposalId) -> SingleValueMapper<Option<u64>>;
+
     #[view(getProposalVotes)]
     #[storage_mapper("proposalVotes")]
     fn proposal_votes(
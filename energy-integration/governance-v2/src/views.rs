@@ -11,6 +11,8 @@ pub trait ViewsModule:
     + crate::configurable::ConfigurablePropertiesModule
     + permissions_module::PermissionsModule
     + energy_query::EnergyQueryModule
+    + crate::multiple_choice::MultipleChoiceModule
+    + crate::parameter_range::ParameterRangeModule
 {
     #[view(getProposalStatus)]
     fn get_proposal_status(&self, proposal_id: ProposalId) -> GovernanceProposalStatus {
@@ -35,6 +37,20 @@ pub trait ViewsModule:
             return GovernanceProposalStatus::Active;
         }
 
+        if self.is_multiple_choice_proposal(proposal_id) {
+            return match self.get_winning_option(proposal_id) {
+                Some(_) => GovernanceProposalStatus::Succeeded,
+                None => GovernanceProposalStatus::Defeated,
+            };
+        }
+
+        if self.is_parameter_range_proposal(proposal_id) {
+            return match self.get_parameter_range_result(proposal_id) {
+                Some(_) => GovernanceProposalStatus::Succeeded,
+                None => GovernanceProposalStatus::Defeated,
+            };
+        }
+
         if self.quorum_reached(proposal_id) && self.vote_reached(proposal_id) {
             GovernanceProposalStatus::Succeeded
         } else if self.vote_down_with_veto(proposal_id) {
@@ -2,13 +2,16 @@
 
 mod gov_test_setup;
 
+use energy_factory::{energy::Energy, energy_checkpoints::EnergyCheckpointsModule};
 use gov_test_setup::*;
 use governance_v2::{
-    configurable::ConfigurablePropertiesModule, proposal::GovernanceProposalStatus,
-    proposal_storage::ProposalStorageModule, views::ViewsModule,
+    configurable::ConfigurablePropertiesModule, deposit::DepositModule,
+    multiple_choice::MultipleChoiceModule, parameter_range::ParameterRangeModule,
+    proposal::GovernanceProposalStatus, proposal_storage::ProposalStorageModule,
+    views::ViewsModule,
 };
-use multiversx_sc::types::ManagedVec;
-use multiversx_sc_scenario::{managed_buffer, rust_biguint};
+use multiversx_sc::types::{BigInt, ManagedVec};
+use multiversx_sc_scenario::{managed_address, managed_biguint, managed_buffer, rust_biguint};
 
 #[test]
 fn init_gov_test() {
@@ -119,6 +122,67 @@ fn gov_propose_total_energy_0_test() {
         .assert_ok();
 }
 
+#[test]
+fn gov_propose_in_total_energy_init_epoch_test() {
+    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
+    let min_fee = rust_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST;
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
+
+    // the total energy is only tracked from epoch 5 onwards
+    let total_energy = 3 * USER_ENERGY + 210_000;
+    gov_setup.b_mock.set_block_epoch(5);
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &gov_setup.owner,
+            &gov_setup.energy_factory_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.total_energy_checkpoints().clear();
+                sc.init_total_energy_checkpoints(Energy::new(
+                    BigInt::from(managed_biguint!(total_energy)),
+                    5,
+                    managed_biguint!(0),
+                ));
+            },
+        )
+        .assert_ok();
+
+    let (result, _) = gov_setup.propose(
+        &first_user_addr,
+        &min_fee,
+        &sc_addr,
+        b"changeTODO",
+        vec![1_000u64.to_be_bytes().to_vec()],
+    );
+    result.assert_user_error("No total energy at the proposal snapshot epoch");
+
+    gov_setup.b_mock.set_block_epoch(6);
+    let (result, proposal_id) = gov_setup.propose(
+        &first_user_addr,
+        &min_fee,
+        &sc_addr,
+        b"changeTODO",
+        vec![1_000u64.to_be_bytes().to_vec()],
+    );
+    result.assert_ok();
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert_eq!(
+                sc.proposals().get(proposal_id).total_quorum,
+                managed_biguint!(total_energy)
+            );
+        })
+        .assert_ok();
+}
+
 #[test]
 fn gov_no_veto_vote_test() {
     let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
@@ -401,10 +465,6 @@ fn gov_modify_withdraw_defeated_proposal_test() {
 
     gov_setup.increment_block_nonce(LOCKING_PERIOD_BLOCKS);
 
-    gov_setup
-        .change_withdraw_percentage(FULL_PERCENTAGE + 1u64)
-        .assert_error(4, "Not valid value for withdraw percentage if defeated!");
-
     gov_setup
         .b_mock
         .execute_query(&gov_setup.gov_wrapper, |sc| {
@@ -412,10 +472,6 @@ fn gov_modify_withdraw_defeated_proposal_test() {
                 sc.get_proposal_status(1) == GovernanceProposalStatus::Defeated,
                 "Action should have been Defeated"
             );
-
-            sc.try_change_withdraw_percentage_defeated(WITHDRAW_PERCENTAGE / 5);
-
-            assert!(sc.withdraw_percentage_defeated().get() == WITHDRAW_PERCENTAGE / 5);
         })
         .assert_ok();
 
@@ -482,7 +538,7 @@ fn gov_withdraw_no_with_veto_defeated_proposal_test() {
         })
         .assert_ok();
 
-    // Other user (not proposer) withdraw the fee
+    // Other user (not proposer) slashes the fee
     gov_setup
         .withdraw_after_defeated(&third_user_addr, proposal_id)
         .assert_ok();
@@ -490,7 +546,7 @@ fn gov_withdraw_no_with_veto_defeated_proposal_test() {
     // Check proposer balance (fee)
     gov_setup
         .b_mock
-        .check_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &(&min_fee / 2u64));
+        .check_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &rust_biguint!(0));
 
     // Withdraw the fee twice - error
     gov_setup
@@ -498,115 +554,6 @@ fn gov_withdraw_no_with_veto_defeated_proposal_test() {
         .assert_error(4, "Fee already withdrawn!");
 }
 
-#[test]
-fn gov_withdraw_no_with_veto_penalty_limits_test() {
-    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
-
-    let first_user_addr = gov_setup.first_user.clone();
-    let third_user_addr = gov_setup.third_user.clone();
-    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
-    let min_fee = rust_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST;
-    // Give proposer the minimum fee
-    gov_setup
-        .b_mock
-        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
-
-    gov_setup.change_withdraw_percentage(0).assert_ok();
-    let (result, proposal_id) = gov_setup.propose(
-        &first_user_addr,
-        &min_fee,
-        &sc_addr,
-        b"changeTODO",
-        vec![1_000u64.to_be_bytes().to_vec()],
-    );
-    result.assert_ok();
-    assert_eq!(proposal_id, 1);
-
-    // Check proposer balance
-    gov_setup
-        .b_mock
-        .check_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &rust_biguint!(0));
-
-    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
-
-    gov_setup.up_vote(&first_user_addr, proposal_id).assert_ok();
-    gov_setup
-        .down_veto_vote(&third_user_addr, proposal_id)
-        .assert_ok();
-
-    gov_setup.increment_block_nonce(LOCKING_PERIOD_BLOCKS);
-
-    gov_setup
-        .b_mock
-        .execute_query(&gov_setup.gov_wrapper, |sc| {
-            assert!(
-                sc.get_proposal_status(1) == GovernanceProposalStatus::DefeatedWithVeto,
-                "Action should have been Defeated"
-            );
-        })
-        .assert_ok();
-
-    // Other user (not proposer) withdraw the fee
-    gov_setup
-        .withdraw_after_defeated(&third_user_addr, proposal_id)
-        .assert_ok();
-
-    // Check proposer balance (fee)
-    gov_setup
-        .b_mock
-        .check_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &rust_biguint!(0));
-
-    // Give proposer the minimum fee
-    gov_setup
-        .b_mock
-        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
-
-    gov_setup.change_withdraw_percentage(10_000).assert_ok();
-    let (result, proposal_id) = gov_setup.propose(
-        &first_user_addr,
-        &min_fee,
-        &sc_addr,
-        b"changeTODO",
-        vec![1_000u64.to_be_bytes().to_vec()],
-    );
-    result.assert_ok();
-    assert_eq!(proposal_id, 2);
-
-    // Check proposer balance
-    gov_setup
-        .b_mock
-        .check_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &rust_biguint!(0));
-
-    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
-
-    gov_setup.up_vote(&first_user_addr, proposal_id).assert_ok();
-    gov_setup
-        .down_veto_vote(&third_user_addr, proposal_id)
-        .assert_ok();
-
-    gov_setup.increment_block_nonce(LOCKING_PERIOD_BLOCKS);
-
-    gov_setup
-        .b_mock
-        .execute_query(&gov_setup.gov_wrapper, |sc| {
-            assert!(
-                sc.get_proposal_status(1) == GovernanceProposalStatus::DefeatedWithVeto,
-                "Action should have been Defeated"
-            );
-        })
-        .assert_ok();
-
-    // Other user (not proposer) withdraw the fee
-    gov_setup
-        .withdraw_after_defeated(&third_user_addr, proposal_id)
-        .assert_ok();
-
-    // Check proposer balance (fee)
-    gov_setup
-        .b_mock
-        .check_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
-}
-
 #[test]
 fn gov_propose_cancel_proposal_id_test() {
     let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
@@ -732,3 +679,336 @@ fn gov_propose_cancel_proposal_id_test() {
         .check_proposal_id_consistency(&first_user_addr, proposal_id)
         .assert_ok();
 }
+
+#[test]
+fn gov_snapshot_voting_test() {
+    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let no_energy_user_addr = gov_setup.no_energy_user.clone();
+    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
+    let min_fee = rust_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST;
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
+
+    let (result, proposal_id) = gov_setup.propose(
+        &first_user_addr,
+        &min_fee,
+        &sc_addr,
+        b"changeTODO",
+        vec![1_000u64.to_be_bytes().to_vec()],
+    );
+    result.assert_ok();
+
+    // the total energy is fixed at creation
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert_eq!(sc.proposal_snapshot_epoch(proposal_id).get(), Some(0));
+            assert_eq!(
+                sc.proposals().get(proposal_id).total_quorum,
+                managed_biguint!(3 * USER_ENERGY + 210_000)
+            );
+        })
+        .assert_ok();
+
+    // energy gained or lost after the snapshot does not count
+    gov_setup.set_user_energy(&no_energy_user_addr, 10 * USER_ENERGY);
+    gov_setup.set_user_energy(&second_user_addr, 0);
+
+    gov_setup.increment_block_nonce(VOTING_DELAY_BLOCKS);
+
+    gov_setup
+        .up_vote(&no_energy_user_addr, proposal_id)
+        .assert_user_error("No energy at the proposal snapshot epoch");
+    gov_setup
+        .down_vote(&second_user_addr, proposal_id)
+        .assert_ok();
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            let proposal_votes = sc.proposal_votes(proposal_id).get();
+            assert_eq!(proposal_votes.quorum, managed_biguint!(USER_ENERGY));
+            assert_eq!(proposal_votes.down_votes, managed_biguint!(1_000));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn gov_multiple_choice_test() {
+    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let third_user_addr = gov_setup.third_user.clone();
+    let min_fee = rust_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST;
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &(&min_fee * 2u64));
+
+    let (result, _) =
+        gov_setup.propose_multiple_choice(&first_user_addr, &min_fee, &[b"only option"]);
+    result.assert_user_error("Invalid number of options");
+
+    let (result, proposal_id) =
+        gov_setup.propose_multiple_choice(&first_user_addr, &min_fee, &[b"1%", b"2%", b"5%"]);
+    result.assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_DELAY_BLOCKS);
+
+    gov_setup
+        .up_vote(&first_user_addr, proposal_id)
+        .assert_user_error("Multiple-choice proposals are voted through voteForOption");
+    gov_setup
+        .vote_for_option(&first_user_addr, proposal_id, 4)
+        .assert_user_error("Invalid option");
+
+    gov_setup
+        .vote_for_option(&first_user_addr, proposal_id, 2)
+        .assert_ok();
+    gov_setup
+        .vote_for_option(&second_user_addr, proposal_id, 2)
+        .assert_ok();
+    gov_setup
+        .vote_for_option(&third_user_addr, proposal_id, 1)
+        .assert_ok();
+    gov_setup
+        .vote_for_option(&first_user_addr, proposal_id, 1)
+        .assert_user_error("Already voted for this proposal");
+
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert_eq!(sc.get_proposal_option_votes(proposal_id).len(), 3);
+            assert_eq!(
+                sc.proposal_option_votes(proposal_id, 2).get().quorum,
+                managed_biguint!(2 * USER_ENERGY)
+            );
+            assert_eq!(
+                sc.proposal_option_votes(proposal_id, 3).get().votes,
+                managed_biguint!(0)
+            );
+            assert_eq!(sc.get_winning_option(proposal_id), Some(2));
+            assert!(sc.get_proposal_status(proposal_id) == GovernanceProposalStatus::Succeeded);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn gov_parameter_range_test() {
+    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let third_user_addr = gov_setup.third_user.clone();
+    let min_fee = rust_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST;
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &(&min_fee * 2u64));
+
+    let (result, _) = gov_setup.propose_parameter_range(&first_user_addr, &min_fee, 100, 100);
+    result.assert_user_error("Invalid parameter range");
+
+    let (result, proposal_id) =
+        gov_setup.propose_parameter_range(&first_user_addr, &min_fee, 0, 100);
+    result.assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_DELAY_BLOCKS);
+
+    gov_setup
+        .up_vote(&first_user_addr, proposal_id)
+        .assert_user_error("Parameter range proposals are voted through voteForValue");
+    gov_setup
+        .vote_for_option(&first_user_addr, proposal_id, 1)
+        .assert_user_error("Not a multiple-choice proposal");
+    gov_setup
+        .vote_for_value(&third_user_addr, proposal_id, 150)
+        .assert_user_error("Value out of range");
+
+    gov_setup
+        .vote_for_value(&first_user_addr, proposal_id, 10)
+        .assert_ok();
+    gov_setup
+        .vote_for_value(&second_user_addr, proposal_id, 20)
+        .assert_ok();
+    gov_setup
+        .vote_for_value(&third_user_addr, proposal_id, 45)
+        .assert_ok();
+    gov_setup
+        .vote_for_value(&first_user_addr, proposal_id, 30)
+        .assert_user_error("Already voted for this proposal");
+
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            // voting power is 1_000 for the first two users and 1_100 for the third
+            let range_votes = sc.parameter_range_votes(proposal_id).get();
+            assert_eq!(range_votes.votes, managed_biguint!(3_100));
+            assert_eq!(
+                range_votes.weighted_values_sum,
+                managed_biguint!(10 * 1_000 + 20 * 1_000 + 45 * 1_100)
+            );
+            assert_eq!(
+                sc.get_parameter_range_result(proposal_id),
+                Some(managed_biguint!(79_500 / 3_100))
+            );
+            assert!(sc.get_proposal_status(proposal_id) == GovernanceProposalStatus::Succeeded);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn gov_proposal_deposit_sponsoring_test() {
+    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
+
+    let owner_addr = gov_setup.owner.clone();
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let third_user_addr = gov_setup.third_user.clone();
+    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
+    let min_fee = rust_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST;
+    let half_fee = &min_fee / 2u64;
+    let quarter_fee = &min_fee / 4u64;
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&second_user_addr, MEX_TOKEN_ID, &quarter_fee);
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&third_user_addr, MEX_TOKEN_ID, &half_fee);
+
+    // one succeeded proposal is enough for the reduced fee
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &owner_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_reduced_fee_for_propose(
+                    managed_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST / 10u64,
+                    managed_biguint!(USER_ENERGY),
+                    1,
+                );
+            },
+        )
+        .assert_ok();
+
+    // the first proposal is sponsored, the second one never gets its full deposit
+    let (result, proposal_id) = gov_setup.propose(
+        &first_user_addr,
+        &half_fee,
+        &sc_addr,
+        b"changeTODO",
+        vec![1_000u64.to_be_bytes().to_vec()],
+    );
+    result.assert_ok();
+    let (result, unfunded_proposal_id) = gov_setup.propose(
+        &first_user_addr,
+        &quarter_fee,
+        &sc_addr,
+        b"changeTODO",
+        vec![1_000u64.to_be_bytes().to_vec()],
+    );
+    result.assert_ok();
+
+    gov_setup
+        .sponsor_proposal(&second_user_addr, proposal_id, &quarter_fee)
+        .assert_ok();
+    gov_setup
+        .sponsor_proposal(&third_user_addr, proposal_id, &half_fee)
+        .assert_user_error("Deposit higher than required");
+    gov_setup
+        .sponsor_proposal(&third_user_addr, proposal_id, &quarter_fee)
+        .assert_ok();
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            let deposit = sc.get_proposal_deposit(proposal_id);
+            assert!(deposit.fully_funded);
+            assert_eq!(deposit.refund_percentage, None);
+            assert_eq!(
+                deposit.sponsored_amount,
+                managed_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST / 2u64
+            );
+            assert!(!sc.get_proposal_deposit(unfunded_proposal_id).fully_funded);
+        })
+        .assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+
+    gov_setup
+        .sponsor_proposal(&third_user_addr, unfunded_proposal_id, &quarter_fee)
+        .assert_user_error("Proposal is not pending");
+    gov_setup
+        .up_vote(&first_user_addr, unfunded_proposal_id)
+        .assert_user_error("Proposal deposit is not complete");
+
+    gov_setup.up_vote(&first_user_addr, proposal_id).assert_ok();
+    gov_setup
+        .up_vote(&second_user_addr, proposal_id)
+        .assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+
+    // each depositor gets their own part back
+    gov_setup
+        .withdraw_after_defeated(&first_user_addr, proposal_id)
+        .assert_ok();
+    gov_setup
+        .withdraw_after_defeated(&second_user_addr, proposal_id)
+        .assert_ok();
+    gov_setup
+        .withdraw_after_defeated(&second_user_addr, proposal_id)
+        .assert_user_error("Sponsor deposit already withdrawn");
+    gov_setup
+        .withdraw_after_defeated(&owner_addr, proposal_id)
+        .assert_user_error("Only original proposer may withdraw a pending proposal");
+    gov_setup
+        .withdraw_after_defeated(&third_user_addr, proposal_id)
+        .assert_ok();
+
+    // the unfunded proposal could not be voted, so it is refunded in full
+    gov_setup
+        .withdraw_after_defeated(&first_user_addr, unfunded_proposal_id)
+        .assert_ok();
+
+    gov_setup
+        .b_mock
+        .check_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
+    gov_setup
+        .b_mock
+        .check_esdt_balance(&second_user_addr, MEX_TOKEN_ID, &quarter_fee);
+    gov_setup
+        .b_mock
+        .check_esdt_balance(&third_user_addr, MEX_TOKEN_ID, &half_fee);
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert_eq!(
+                sc.proposer_succeeded_proposals(&managed_address!(&first_user_addr))
+                    .get(),
+                1
+            );
+            assert_eq!(
+                sc.get_required_fee_for_proposer(managed_address!(&first_user_addr)),
+                managed_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST / 10u64
+            );
+            assert_eq!(
+                sc.get_required_fee_for_proposer(managed_address!(&second_user_addr)),
+                managed_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST
+            );
+        })
+        .assert_ok();
+}
@@ -1,16 +1,17 @@
 #![allow(deprecated)]
 
 use energy_factory_mock::EnergyFactoryMock;
-use energy_query::Energy;
 use fees_collector::FeesCollector;
 use governance_v2::{
     configurable::ConfigurablePropertiesModule,
+    deposit::DepositModule,
+    multiple_choice::ProposalOption,
     proposal_storage::{ProposalStorageModule, VoteType},
     GovernanceV2,
 };
 use multiversx_sc::{
     codec::multi_types::OptionalValue,
-    types::{Address, BigInt, EsdtLocalRole, ManagedVec, MultiValueEncoded},
+    types::{Address, EsdtLocalRole, ManagedVec, MultiValueEncoded},
 };
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_buffer, managed_token_id, rust_biguint,
@@ -26,14 +27,14 @@ pub const QUORUM_PERCENTAGE: u64 = 4_000; // 40%
 pub const VOTING_DELAY_BLOCKS: u64 = 1;
 pub const VOTING_PERIOD_BLOCKS: u64 = 144_000; // 10 days
 pub const LOCKING_PERIOD_BLOCKS: u64 = 30;
-pub const WITHDRAW_PERCENTAGE: u64 = 5_000; // 50%
 pub const MEX_TOKEN_ID: &[u8] = b"MEX-123456";
 pub const XMEX_TOKEN_ID: &[u8] = b"XMEX-123456";
 pub const DECIMALS_CONST: u64 = 1_000_000_000_000_000_000;
-pub const FULL_PERCENTAGE: u64 = 10_000;
 pub const USER_ENERGY: u64 = 1_000_000;
 pub const GAS_LIMIT: u64 = 1_000_000;
 
+pub type EnergyFactoryMockBuilder = fn() -> energy_factory_mock::ContractObj<DebugApi>;
+
 pub struct GovSetup<GovBuilder>
 where
     GovBuilder: 'static + Copy + Fn() -> governance_v2::ContractObj<DebugApi>,
@@ -45,6 +46,8 @@ where
     pub third_user: Address,
     pub no_energy_user: Address,
     pub gov_wrapper: ContractObjWrapper<governance_v2::ContractObj<DebugApi>, GovBuilder>,
+    pub energy_factory_wrapper:
+        ContractObjWrapper<energy_factory_mock::ContractObj<DebugApi>, EnergyFactoryMockBuilder>,
     pub current_block: u64,
 }
 
@@ -65,7 +68,7 @@ where
         let energy_factory_wrapper = b_mock.create_sc_account(
             &rust_zero,
             Some(&owner),
-            energy_factory_mock::contract_obj,
+            energy_factory_mock::contract_obj as EnergyFactoryMockBuilder,
             "energy factory path",
         );
 
@@ -80,30 +83,26 @@ where
         b_mock
             .execute_tx(&owner, &energy_factory_wrapper, &rust_zero, |sc| {
                 sc.init();
-                sc.user_energy(&managed_address!(&first_user))
-                    .set(&Energy::new(
-                        BigInt::from(managed_biguint!(USER_ENERGY)),
-                        0,
-                        managed_biguint!(0),
-                    ));
-                sc.user_energy(&managed_address!(&second_user))
-                    .set(&Energy::new(
-                        BigInt::from(managed_biguint!(USER_ENERGY)),
-                        0,
-                        managed_biguint!(0),
-                    ));
-                sc.user_energy(&managed_address!(&third_user))
-                    .set(&Energy::new(
-                        BigInt::from(managed_biguint!(USER_ENERGY + 210_000)),
-                        0,
-                        managed_biguint!(0),
-                    ));
-                sc.user_energy(&managed_address!(&no_energy_user))
-                    .set(&Energy::new(
-                        BigInt::from(managed_biguint!(0)),
-                        0,
-                        managed_biguint!(0),
-                    ));
+                sc.set_user_energy(
+                    managed_address!(&first_user),
+                    managed_biguint!(USER_ENERGY),
+                    managed_biguint!(0),
+                );
+                sc.set_user_energy(
+                    managed_address!(&second_user),
+                    managed_biguint!(USER_ENERGY),
+                    managed_biguint!(0),
+                );
+                sc.set_user_energy(
+                    managed_address!(&third_user),
+                    managed_biguint!(USER_ENERGY + 210_000),
+                    managed_biguint!(0),
+                );
+                sc.set_user_energy(
+                    managed_address!(&no_energy_user),
+                    managed_biguint!(0),
+                    managed_biguint!(0),
+                );
             })
             .assert_ok();
 
@@ -161,7 +160,6 @@ where
                     QUORUM_PERCENTAGE,
                     VOTING_DELAY_BLOCKS,
                     VOTING_PERIOD_BLOCKS,
-                    WITHDRAW_PERCENTAGE,
                     managed_address!(energy_factory_wrapper.address_ref()),
                     managed_address!(fees_collector_wrapper.address_ref()),
                     managed_token_id!(MEX_TOKEN_ID),
@@ -178,6 +176,9 @@ where
         let vote_nft_roles = [EsdtLocalRole::Mint, EsdtLocalRole::Burn];
         b_mock.set_esdt_local_roles(gov_wrapper.address_ref(), MEX_TOKEN_ID, &vote_nft_roles[..]);
 
+        // the proposals' snapshot is the previous epoch
+        b_mock.set_block_epoch(1);
+
         Self {
             b_mock,
             owner,
@@ -186,6 +187,7 @@ where
             third_user,
             no_energy_user,
             gov_wrapper,
+            energy_factory_wrapper,
             current_block: 0,
         }
     }
@@ -229,6 +231,80 @@ where
         (result, proposal_id)
     }
 
+    pub fn propose_multiple_choice(
+        &mut self,
+        proposer: &Address,
+        fee_amount: &BigUint,
+        labels: &[&[u8]],
+    ) -> (TxResult, usize) {
+        let mut proposal_id = 0;
+        let result = self.b_mock.execute_esdt_transfer(
+            proposer,
+            &self.gov_wrapper,
+            MEX_TOKEN_ID,
+            0,
+            fee_amount,
+            |sc| {
+                let mut options = MultiValueEncoded::new();
+                for label in labels {
+                    options.push(ProposalOption {
+                        label: managed_buffer!(label),
+                        actions: ManagedVec::new(),
+                    });
+                }
+
+                proposal_id = sc.propose_multiple_choice(managed_buffer!(b"changeTODO"), options);
+            },
+        );
+
+        (result, proposal_id)
+    }
+
+    pub fn vote_for_option(
+        &mut self,
+        voter: &Address,
+        proposal_id: usize,
+        option_id: usize,
+    ) -> TxResult {
+        self.b_mock
+            .execute_tx(voter, &self.gov_wrapper, &rust_biguint!(0), |sc| {
+                sc.vote_for_option(proposal_id, option_id);
+            })
+    }
+
+    pub fn propose_parameter_range(
+        &mut self,
+        proposer: &Address,
+        fee_amount: &BigUint,
+        min_value: u64,
+        max_value: u64,
+    ) -> (TxResult, usize) {
+        let mut proposal_id = 0;
+        let result = self.b_mock.execute_esdt_transfer(
+            proposer,
+            &self.gov_wrapper,
+            MEX_TOKEN_ID,
+            0,
+            fee_amount,
+            |sc| {
+                proposal_id = sc.propose_parameter_range(
+                    managed_buffer!(b"changeTODO"),
+                    managed_biguint!(min_value),
+                    managed_biguint!(max_value),
+                );
+            },
+        );
+
+        (result, proposal_id)
+    }
+
+    pub fn vote_for_value(&mut self, voter: &Address, proposal_id: usize, value: u64) -> TxResult {
+        self.b_mock
+            .execute_tx(voter, &self.gov_wrapper, &rust_biguint!(0), |sc| {
+                sc.vote_for_value(proposal_id, managed_biguint!(value));
+            })
+    }
+
     pub fn up_vote(&mut self, voter: &Address, proposal_id: usize) -> TxResult {
         self.b_mock
             .execute_tx(voter, &self.gov_wrapper, &rust_biguint!(0), |sc| {
@@ -264,11 +340,22 @@ where
             })
     }
 
-    pub fn change_withdraw_percentage(&mut self, withdraw_value: u64) -> TxResult {
-        self.b_mock
-            .execute_tx(&self.owner, &self.gov_wrapper, &rust_biguint!(0), |sc| {
-                sc.change_withdraw_percentage(withdraw_value);
-            })
+    pub fn sponsor_proposal(
+        &mut self,
+        sponsor: &Address,
+        proposal_id: usize,
+        amount: &BigUint,
+    ) -> TxResult {
+        self.b_mock.execute_esdt_transfer(
+            sponsor,
+            &self.gov_wrapper,
+            MEX_TOKEN_ID,
+            0,
+            amount,
+            |sc| {
+                sc.sponsor_proposal(proposal_id);
+            },
+        )
     }
 
     pub fn change_min_energy(&mut self, min_energy_for_propose: usize) -> TxResult {
@@ -300,6 +387,23 @@ where
                 )
             })
     }
+    pub fn set_user_energy(&mut self, user: &Address, energy_amount: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner,
+                &self.energy_factory_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.set_user_energy(
+                        managed_address!(user),
+                        managed_biguint!(energy_amount),
+                        managed_biguint!(0),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
     pub fn increment_block_nonce(&mut self, inc_amount: u64) {
         self.current_block += inc_amount;
         self.b_mock.set_block_nonce(self.current_block);
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           29
+// Endpoints:                           48
 // Async Callback (empty):               1
-// Total number of exported functions:  32
+// Total number of exported functions:  51
 
 #![no_std]
 
@@ -21,13 +21,16 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         propose => propose
+        proposeMultipleChoice => propose_multiple_choice
+        proposeParameterRange => propose_parameter_range
         vote => vote
+        voteForOption => vote_for_option
+        voteForValue => vote_for_value
         cancel => cancel
         withdrawDeposit => withdraw_deposit
         changeMinEnergyForProposal => change_min_energy_for_propose
         changeMinFeeForProposal => change_min_fee_for_propose
         changeQuorumPercentage => change_quorum_percentage
-        changeWithdrawPercentage => change_withdraw_percentage
         changeVotingDelayInBlocks => change_voting_delay_in_blocks
         changeVotingPeriodInBlocks => change_voting_period_in_blocks
         getMinEnergyForPropose => min_energy_for_propose
@@ -36,13 +39,29 @@ multiversx_sc_wasm_adapter::endpoints! {
         getVotingDelayInBlocks => voting_delay_in_blocks
         getVotingPeriodInBlocks => voting_period_in_blocks
         getFeeTokenId => fee_token_id
-        getWithdrawPercentageDefeated => withdraw_percentage_defeated
         getProposals => proposals
         getUserVotedProposals => user_voted_proposals
+        getProposalSnapshotEpoch => proposal_snapshot_epoch
         getProposalVotes => proposal_votes
         getProposalStatus => get_proposal_status
         changeFeesCollectorAddress => change_fees_collector_address
         getFeesCollectorAddress => fees_collector_address
+        getWinningOption => get_winning_option_view
+        getProposalOptionVotes => get_proposal_option_votes
+        getProposalOptions => proposal_options
+        getParameterRangeResult => get_parameter_range_result_view
+        getProposalParameterRange => proposal_parameter_range
+        getParameterRangeVotes => parameter_range_votes
+        setReducedFeeForPropose => set_reduced_fee_for_propose
+        sponsorProposal => sponsor_proposal
+        getRequiredFeeForProposer => get_required_fee_for_proposer
+        getProposalDeposit => get_proposal_deposit
+        getProposalSponsors => get_proposal_sponsors
+        getReducedFeeForPropose => reduced_fee_for_propose
+        getMinEnergyForReducedFee => min_energy_for_reduced_fee
+        getMinSucceededProposalsForReducedFee => min_succeeded_proposals_for_reduced_fee
+        getProposerSucceededProposals => proposer_succeeded_proposals
+        getProposerVetoedProposals => proposer_vetoed_proposals
         setEnergyFactoryAddress => set_energy_factory_address
         getEnergyFactoryAddress => energy_factory_address
         addAdmin => add_admin_endpoint
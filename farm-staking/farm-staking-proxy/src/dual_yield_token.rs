@@ -1,14 +1,56 @@
 use fixed_supply_token::FixedSupplyToken;
+use multiversx_sc::codec::{NestedDecodeInput, TopDecodeInput};
+
+use crate::farms_registry::{FarmsTripleId, DEFAULT_FARMS_TRIPLE_ID};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Debug)]
+#[derive(TypeAbi, TopEncode, NestedEncode, Clone, PartialEq, Debug)]
 pub struct DualYieldTokenAttributes<M: ManagedTypeApi> {
     pub lp_farm_token_nonce: u64,
     pub lp_farm_token_amount: BigUint<M>,
     pub staking_farm_token_nonce: u64,
     pub staking_farm_token_amount: BigUint<M>,
+    pub farms_triple_id: FarmsTripleId,
+}
+
+impl<M: ManagedTypeApi> TopDecode for DualYieldTokenAttributes<M> {
+    fn top_decode<I>(input: I) -> Result<Self, DecodeError>
+    where
+        I: TopDecodeInput,
+    {
+        let mut buffer = input.into_nested_buffer();
+        Self::dep_decode(&mut buffer)
+    }
+}
+
+impl<M: ManagedTypeApi> NestedDecode for DualYieldTokenAttributes<M> {
+    fn dep_decode<I: NestedDecodeInput>(input: &mut I) -> Result<Self, DecodeError> {
+        let lp_farm_token_nonce = u64::dep_decode(input)?;
+        let lp_farm_token_amount = BigUint::dep_decode(input)?;
+        let staking_farm_token_nonce = u64::dep_decode(input)?;
+        let staking_farm_token_amount = BigUint::dep_decode(input)?;
+
+        // tokens created before the farms registry belong to the default triple
+        let farms_triple_id = if !input.is_depleted() {
+            FarmsTripleId::dep_decode(input)?
+        } else {
+            DEFAULT_FARMS_TRIPLE_ID
+        };
+
+        if !input.is_depleted() {
+            return Result::Err(DecodeError::INPUT_TOO_LONG);
+        }
+
+        Result::Ok(DualYieldTokenAttributes {
+            lp_farm_token_nonce,
+            lp_farm_token_amount,
+            staking_farm_token_nonce,
+            staking_farm_token_amount,
+            farms_triple_id,
+        })
+    }
 }
 
 impl<M: ManagedTypeApi> FixedSupplyToken<M> for DualYieldTokenAttributes<M> {
@@ -30,6 +72,7 @@ impl<M: ManagedTypeApi> FixedSupplyToken<M> for DualYieldTokenAttributes<M> {
             lp_farm_token_amount: new_lp_farm_token_amount,
             staking_farm_token_nonce: self.staking_farm_token_nonce,
             staking_farm_token_amount: new_staking_farm_token_amount,
+            farms_triple_id: self.farms_triple_id,
         }
     }
 }
@@ -14,28 +14,37 @@ use pair::{
     safe_price_view::ProxyTrait as _,
 };
 
-use crate::result_types::*;
+use crate::{
+    farms_registry::{FarmsTriple, DEFAULT_FARMS_TRIPLE_ID},
+    result_types::*,
+};
 
 pub type SafePriceResult<Api> = MultiValue2<EsdtTokenPayment<Api>, EsdtTokenPayment<Api>>;
 
 #[multiversx_sc::module]
 pub trait ExternalContractsInteractionsModule:
-    crate::lp_farm_token::LpFarmTokenModule + utils::UtilsModule + energy_query::EnergyQueryModule
+    crate::lp_farm_token::LpFarmTokenModule
+    + crate::farms_registry::FarmsRegistryModule
+    + utils::UtilsModule
+    + energy_query::EnergyQueryModule
 {
     // lp farm
 
     fn lp_farm_claim_rewards(
         &self,
+        farms_triple: &FarmsTriple<Self::Api>,
         orig_caller: ManagedAddress,
-        lp_farm_token_id: TokenIdentifier,
         lp_farm_token_nonce: u64,
         lp_farm_token_amount: BigUint,
     ) -> LpFarmClaimRewardsResult<Self::Api> {
-        let lp_farm_address = self.lp_farm_address().get();
         let lp_farm_result: ClaimRewardsResultType<Self::Api> = self
-            .lp_farm_proxy_obj(lp_farm_address)
+            .lp_farm_proxy_obj(farms_triple.lp_farm_address.clone())
             .claim_rewards_endpoint(OptionalValue::Some(orig_caller))
-            .with_esdt_transfer((lp_farm_token_id, lp_farm_token_nonce, lp_farm_token_amount))
+            .with_esdt_transfer((
+                farms_triple.lp_farm_token_id.clone(),
+                lp_farm_token_nonce,
+                lp_farm_token_amount,
+            ))
             .execute_on_dest_context();
         let (new_lp_farm_tokens, lp_farm_rewards) = lp_farm_result.into_tuple();
 
@@ -47,16 +56,19 @@ pub trait ExternalContractsInteractionsModule:
 
     fn lp_farm_exit(
         &self,
+        farms_triple: &FarmsTriple<Self::Api>,
         orig_caller: ManagedAddress,
         lp_farm_token_nonce: u64,
         lp_farm_token_amount: BigUint,
     ) -> LpFarmExitResult<Self::Api> {
-        let lp_farm_token_id = self.lp_farm_token_id().get();
-        let lp_farm_address = self.lp_farm_address().get();
         let exit_farm_result: ExitFarmWithPartialPosResultType<Self::Api> = self
-            .lp_farm_proxy_obj(lp_farm_address)
+            .lp_farm_proxy_obj(farms_triple.lp_farm_address.clone())
             .exit_farm_endpoint(OptionalValue::Some(orig_caller))
-            .with_esdt_transfer((lp_farm_token_id, lp_farm_token_nonce, lp_farm_token_amount))
+            .with_esdt_transfer((
+                farms_triple.lp_farm_token_id.clone(),
+                lp_farm_token_nonce,
+                lp_farm_token_amount,
+            ))
             .execute_on_dest_context();
         let (lp_tokens, lp_farm_rewards) = exit_farm_result.into_tuple();
 
@@ -68,6 +80,7 @@ pub trait ExternalContractsInteractionsModule:
 
     fn merge_lp_farm_tokens(
         &self,
+        farms_triple: &FarmsTriple<Self::Api>,
         orig_caller: ManagedAddress,
         base_lp_farm_token: EsdtTokenPayment,
         mut additional_lp_farm_tokens: PaymentsVec<Self::Api>,
@@ -80,8 +93,7 @@ pub trait ExternalContractsInteractionsModule:
 
         additional_lp_farm_tokens.push(base_lp_farm_token);
 
-        let lp_farm_address = self.lp_farm_address().get();
-        self.lp_farm_proxy_obj(lp_farm_address)
+        self.lp_farm_proxy_obj(farms_triple.lp_farm_address.clone())
             .merge_farm_tokens_endpoint(OptionalValue::Some(orig_caller))
             .with_multi_token_transfer(additional_lp_farm_tokens)
             .execute_on_dest_context()
@@ -91,13 +103,13 @@ pub trait ExternalContractsInteractionsModule:
 
     fn staking_farm_enter(
         &self,
+        farms_triple: &FarmsTriple<Self::Api>,
         orig_caller: ManagedAddress,
         staking_token_amount: BigUint,
         staking_farm_tokens: PaymentsVec<Self::Api>,
     ) -> StakingFarmEnterResult<Self::Api> {
-        let staking_farm_address = self.staking_farm_address().get();
         let enter_result: EnterFarmResultType<Self::Api> = self
-            .staking_farm_proxy_obj(staking_farm_address)
+            .staking_farm_proxy_obj(farms_triple.staking_farm_address.clone())
             .stake_farm_through_proxy(staking_token_amount, orig_caller)
             .with_multi_token_transfer(staking_farm_tokens)
             .execute_on_dest_context();
@@ -111,18 +123,17 @@ pub trait ExternalContractsInteractionsModule:
 
     fn staking_farm_claim_rewards(
         &self,
+        farms_triple: &FarmsTriple<Self::Api>,
         orig_caller: ManagedAddress,
-        staking_farm_token_id: TokenIdentifier,
         staking_farm_token_nonce: u64,
         staking_farm_token_amount: BigUint,
         new_staking_farm_value: BigUint,
     ) -> StakingFarmClaimRewardsResult<Self::Api> {
-        let staking_farm_address = self.staking_farm_address().get();
         let staking_farm_result: ClaimRewardsResultType<Self::Api> = self
-            .staking_farm_proxy_obj(staking_farm_address)
+            .staking_farm_proxy_obj(farms_triple.staking_farm_address.clone())
             .claim_rewards_with_new_value(new_staking_farm_value, orig_caller)
             .with_esdt_transfer((
-                staking_farm_token_id,
+                farms_triple.staking_farm_token_id.clone(),
                 staking_farm_token_nonce,
                 staking_farm_token_amount,
             ))
@@ -137,22 +148,21 @@ pub trait ExternalContractsInteractionsModule:
 
     fn staking_farm_unstake(
         &self,
+        farms_triple: &FarmsTriple<Self::Api>,
         orig_caller: ManagedAddress,
         staking_tokens: EsdtTokenPayment<Self::Api>,
         farm_token_nonce: u64,
         farm_token_amount: BigUint,
     ) -> StakingFarmExitResult<Self::Api> {
-        let staking_farm_token_id = self.staking_farm_token_id().get();
         let mut payments = ManagedVec::from_single_item(staking_tokens);
         payments.push(EsdtTokenPayment::new(
-            staking_farm_token_id,
+            farms_triple.staking_farm_token_id.clone(),
             farm_token_nonce,
             farm_token_amount,
         ));
 
-        let staking_farm_address = self.staking_farm_address().get();
         let unstake_result: ExitFarmWithPartialPosResultType<Self::Api> = self
-            .staking_farm_proxy_obj(staking_farm_address)
+            .staking_farm_proxy_obj(farms_triple.staking_farm_address.clone())
             .unstake_farm_through_proxy(orig_caller)
             .with_multi_token_transfer(payments)
             .execute_on_dest_context();
@@ -168,24 +178,24 @@ pub trait ExternalContractsInteractionsModule:
 
     fn pair_remove_liquidity(
         &self,
+        farms_triple: &FarmsTriple<Self::Api>,
         lp_tokens: EsdtTokenPayment<Self::Api>,
         pair_first_token_min_amount: BigUint,
         pair_second_token_min_amount: BigUint,
     ) -> PairRemoveLiquidityResult<Self::Api> {
-        let pair_address = self.pair_address().get();
         let pair_withdraw_result: RemoveLiquidityResultType<Self::Api> = self
-            .pair_proxy_obj(pair_address)
+            .pair_proxy_obj(farms_triple.pair_address.clone())
             .remove_liquidity(pair_first_token_min_amount, pair_second_token_min_amount)
             .with_esdt_transfer(lp_tokens)
             .execute_on_dest_context();
         let (pair_first_token_payment, pair_second_token_payment) =
             pair_withdraw_result.into_tuple();
 
-        let staking_token_id = self.staking_token_id().get();
+        let staking_token_id = &farms_triple.staking_token_id;
         let (staking_token_payment, other_token_payment) =
-            if pair_first_token_payment.token_identifier == staking_token_id {
+            if &pair_first_token_payment.token_identifier == staking_token_id {
                 (pair_first_token_payment, pair_second_token_payment)
-            } else if pair_second_token_payment.token_identifier == staking_token_id {
+            } else if &pair_second_token_payment.token_identifier == staking_token_id {
                 (pair_second_token_payment, pair_first_token_payment)
             } else {
                 sc_panic!("Invalid payments received from Pair");
@@ -197,18 +207,21 @@ pub trait ExternalContractsInteractionsModule:
         }
     }
 
-    fn get_lp_tokens_safe_price(&self, lp_tokens_amount: BigUint) -> BigUint {
-        let pair_address = self.pair_address().get();
+    fn get_lp_tokens_safe_price(
+        &self,
+        farms_triple: &FarmsTriple<Self::Api>,
+        lp_tokens_amount: BigUint,
+    ) -> BigUint {
         let result: SafePriceResult<Self::Api> = self
-            .pair_proxy_obj(pair_address)
+            .pair_proxy_obj(farms_triple.pair_address.clone())
             .update_and_get_tokens_for_given_position_with_safe_price(lp_tokens_amount)
             .execute_on_dest_context();
         let (first_token_info, second_token_info) = result.into_tuple();
-        let staking_token_id = self.staking_token_id().get();
+        let staking_token_id = &farms_triple.staking_token_id;
 
-        if first_token_info.token_identifier == staking_token_id {
+        if &first_token_info.token_identifier == staking_token_id {
             first_token_info.amount
-        } else if second_token_info.token_identifier == staking_token_id {
+        } else if &second_token_info.token_identifier == staking_token_id {
             second_token_info.amount
         } else {
             sc_panic!("Invalid Pair contract called");
@@ -229,29 +242,39 @@ pub trait ExternalContractsInteractionsModule:
     #[proxy]
     fn pair_proxy_obj(&self, sc_address: ManagedAddress) -> pair::Proxy<Self::Api>;
 
-    // storage
+    // views of the default farms triple, kept for compatibility
 
     #[view(getLpFarmAddress)]
-    #[storage_mapper("lpFarmAddress")]
-    fn lp_farm_address(&self) -> SingleValueMapper<ManagedAddress>;
+    fn lp_farm_address(&self) -> ManagedAddress {
+        self.get_farms_triple(DEFAULT_FARMS_TRIPLE_ID)
+            .lp_farm_address
+    }
 
     #[view(getStakingFarmAddress)]
-    #[storage_mapper("stakingFarmAddress")]
-    fn staking_farm_address(&self) -> SingleValueMapper<ManagedAddress>;
+    fn staking_farm_address(&self) -> ManagedAddress {
+        self.get_farms_triple(DEFAULT_FARMS_TRIPLE_ID)
+            .staking_farm_address
+    }
 
     #[view(getPairAddress)]
-    #[storage_mapper("pairAddress")]
-    fn pair_address(&self) -> SingleValueMapper<ManagedAddress>;
+    fn pair_address(&self) -> ManagedAddress {
+        self.get_farms_triple(DEFAULT_FARMS_TRIPLE_ID).pair_address
+    }
 
     #[view(getStakingTokenId)]
-    #[storage_mapper("stakingTokenId")]
-    fn staking_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+    fn staking_token_id(&self) -> TokenIdentifier {
+        self.get_farms_triple(DEFAULT_FARMS_TRIPLE_ID)
+            .staking_token_id
+    }
 
     #[view(getFarmTokenId)]
-    #[storage_mapper("farmTokenId")]
-    fn staking_farm_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+    fn staking_farm_token_id(&self) -> TokenIdentifier {
+        self.get_farms_triple(DEFAULT_FARMS_TRIPLE_ID)
+            .staking_farm_token_id
+    }
 
     #[view(getLpTokenId)]
-    #[storage_mapper("lpTokenId")]
-    fn lp_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+    fn lp_token_id(&self) -> TokenIdentifier {
+        self.get_farms_triple(DEFAULT_FARMS_TRIPLE_ID).lp_token_id
+    }
 }
@@ -0,0 +1,177 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type FarmsTripleId = u64;
+
+/// The triple registered at deploy time.
+/// Dual yield tokens created before the registry existed belong to it.
+pub const DEFAULT_FARMS_TRIPLE_ID: FarmsTripleId = 1;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct FarmsTriple<M: ManagedTypeApi> {
+    pub pair_address: ManagedAddress<M>,
+    pub lp_farm_address: ManagedAddress<M>,
+    pub staking_farm_address: ManagedAddress<M>,
+    pub staking_token_id: TokenIdentifier<M>,
+    pub lp_farm_token_id: TokenIdentifier<M>,
+    pub staking_farm_token_id: TokenIdentifier<M>,
+    pub lp_token_id: TokenIdentifier<M>,
+}
+
+#[multiversx_sc::module]
+pub trait FarmsRegistryModule: utils::UtilsModule {
+    /// Registers a new (pair, LP farm, staking farm) triple.
+    /// The proxy has to be whitelisted in both farms.
+    /// Each LP farm token can belong to a single active triple,
+    /// as the LP farm token sent on stake selects the triple.
+    #[only_owner]
+    #[endpoint(registerFarmsTriple)]
+    fn register_farms_triple(
+        &self,
+        pair_address: ManagedAddress,
+        lp_farm_address: ManagedAddress,
+        staking_farm_address: ManagedAddress,
+        staking_token_id: TokenIdentifier,
+        lp_farm_token_id: TokenIdentifier,
+        staking_farm_token_id: TokenIdentifier,
+        lp_token_id: TokenIdentifier,
+    ) -> FarmsTripleId {
+        self.add_farms_triple(FarmsTriple {
+            pair_address,
+            lp_farm_address,
+            staking_farm_address,
+            staking_token_id,
+            lp_farm_token_id,
+            staking_farm_token_id,
+            lp_token_id,
+        })
+    }
+
+    /// Retired triples can no longer be entered.
+    /// Existing dual yield tokens can still be used to claim rewards and unstake.
+    #[only_owner]
+    #[endpoint(retireFarmsTriple)]
+    fn retire_farms_triple(&self, farms_triple_id: FarmsTripleId) {
+        let was_active = self.active_farms_triples().swap_remove(&farms_triple_id);
+        require!(was_active, "Farms triple not active");
+
+        let farms_triple = self.farms_triple(farms_triple_id).get();
+        self.farms_triple_id_for_lp_farm_token(&farms_triple.lp_farm_token_id)
+            .clear();
+    }
+
+    #[view(getActiveFarmsTriples)]
+    fn get_active_farms_triples(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<FarmsTripleId, FarmsTriple<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for farms_triple_id in self.active_farms_triples().iter() {
+            let farms_triple = self.farms_triple(farms_triple_id).get();
+            result.push((farms_triple_id, farms_triple).into());
+        }
+
+        result
+    }
+
+    fn add_farms_triple(&self, farms_triple: FarmsTriple<Self::Api>) -> FarmsTripleId {
+        self.require_sc_address(&farms_triple.pair_address);
+        self.require_sc_address(&farms_triple.lp_farm_address);
+        self.require_sc_address(&farms_triple.staking_farm_address);
+
+        self.require_valid_token_id(&farms_triple.staking_token_id);
+        self.require_valid_token_id(&farms_triple.lp_farm_token_id);
+        self.require_valid_token_id(&farms_triple.staking_farm_token_id);
+        self.require_valid_token_id(&farms_triple.lp_token_id);
+
+        let lp_farm_token_mapper =
+            self.farms_triple_id_for_lp_farm_token(&farms_triple.lp_farm_token_id);
+        require!(
+            lp_farm_token_mapper.is_empty(),
+            "LP farm token already used by an active triple"
+        );
+
+        let farms_triple_id = self.last_farms_triple_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        lp_farm_token_mapper.set(farms_triple_id);
+        let _ = self.active_farms_triples().insert(farms_triple_id);
+        self.farms_triple(farms_triple_id).set(farms_triple);
+
+        farms_triple_id
+    }
+
+    fn get_farms_triple(&self, farms_triple_id: FarmsTripleId) -> FarmsTriple<Self::Api> {
+        let mapper = self.farms_triple(farms_triple_id);
+        require!(!mapper.is_empty(), "Unknown farms triple");
+
+        mapper.get()
+    }
+
+    fn get_active_farms_triple_id(&self, lp_farm_token_id: &TokenIdentifier) -> FarmsTripleId {
+        let mapper = self.farms_triple_id_for_lp_farm_token(lp_farm_token_id);
+        require!(!mapper.is_empty(), "Invalid first payment");
+
+        mapper.get()
+    }
+
+    /// Moves the single triple the contract was previously deployed with into the registry.
+    fn migrate_legacy_farms_triple(&self) {
+        if self.last_farms_triple_id().get() > 0 || self.legacy_lp_farm_address().is_empty() {
+            return;
+        }
+
+        let _ = self.add_farms_triple(FarmsTriple {
+            pair_address: self.legacy_pair_address().take(),
+            lp_farm_address: self.legacy_lp_farm_address().take(),
+            staking_farm_address: self.legacy_staking_farm_address().take(),
+            staking_token_id: self.legacy_staking_token_id().take(),
+            lp_farm_token_id: self.legacy_lp_farm_token_id().take(),
+            staking_farm_token_id: self.legacy_staking_farm_token_id().take(),
+            lp_token_id: self.legacy_lp_token_id().take(),
+        });
+    }
+
+    #[view(getFarmsTriple)]
+    #[storage_mapper("farmsTriple")]
+    fn farms_triple(
+        &self,
+        farms_triple_id: FarmsTripleId,
+    ) -> SingleValueMapper<FarmsTriple<Self::Api>>;
+
+    #[view(getFarmsTripleIdForLpFarmToken)]
+    #[storage_mapper("farmsTripleIdForLpFarmToken")]
+    fn farms_triple_id_for_lp_farm_token(
+        &self,
+        lp_farm_token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<FarmsTripleId>;
+
+    #[storage_mapper("activeFarmsTriples")]
+    fn active_farms_triples(&self) -> UnorderedSetMapper<FarmsTripleId>;
+
+    #[storage_mapper("lastFarmsTripleId")]
+    fn last_farms_triple_id(&self) -> SingleValueMapper<FarmsTripleId>;
+
+    // storage of the single triple the contract was previously deployed with
+
+    #[storage_mapper("lpFarmAddress")]
+    fn legacy_lp_farm_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("stakingFarmAddress")]
+    fn legacy_staking_farm_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("pairAddress")]
+    fn legacy_pair_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("stakingTokenId")]
+    fn legacy_staking_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("lpFarmTokenId")]
+    fn legacy_lp_farm_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("farmTokenId")]
+    fn legacy_staking_farm_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("lpTokenId")]
+    fn legacy_lp_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+}
@@ -4,6 +4,7 @@ multiversx_sc::imports!();
 
 pub mod dual_yield_token;
 pub mod external_contracts_interactions;
+pub mod farms_registry;
 pub mod lp_farm_token;
 pub mod proxy_actions;
 pub mod result_types;
@@ -12,6 +13,7 @@ pub mod result_types;
 pub trait FarmStakingProxy:
     dual_yield_token::DualYieldTokenModule
     + external_contracts_interactions::ExternalContractsInteractionsModule
+    + farms_registry::FarmsRegistryModule
     + lp_farm_token::LpFarmTokenModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + utils::UtilsModule
@@ -35,29 +37,24 @@ pub trait FarmStakingProxy:
         lp_token_id: TokenIdentifier,
     ) {
         self.require_sc_address(&energy_factory_address);
-        self.require_sc_address(&lp_farm_address);
-        self.require_sc_address(&staking_farm_address);
-        self.require_sc_address(&pair_address);
-
-        self.require_valid_token_id(&staking_token_id);
-        self.require_valid_token_id(&lp_farm_token_id);
-        self.require_valid_token_id(&staking_farm_token_id);
-        self.require_valid_token_id(&lp_token_id);
-
         self.energy_factory_address()
             .set_if_empty(&energy_factory_address);
-        self.lp_farm_address().set_if_empty(&lp_farm_address);
-        self.staking_farm_address()
-            .set_if_empty(&staking_farm_address);
-        self.pair_address().set_if_empty(&pair_address);
 
-        self.staking_token_id().set_if_empty(&staking_token_id);
-        self.lp_farm_token_id().set_if_empty(&lp_farm_token_id);
-        self.staking_farm_token_id()
-            .set_if_empty(&staking_farm_token_id);
-        self.lp_token_id().set_if_empty(&lp_token_id);
+        if self.last_farms_triple_id().get() == 0 {
+            let _ = self.add_farms_triple(farms_registry::FarmsTriple {
+                pair_address,
+                lp_farm_address,
+                staking_farm_address,
+                staking_token_id,
+                lp_farm_token_id,
+                staking_farm_token_id,
+                lp_token_id,
+            });
+        }
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.migrate_legacy_farms_triple();
+    }
 }
@@ -4,18 +4,22 @@ multiversx_sc::derive_imports!();
 use common_structs::FarmTokenAttributes;
 use fixed_supply_token::FixedSupplyToken;
 
+use crate::farms_registry::DEFAULT_FARMS_TRIPLE_ID;
+
 #[multiversx_sc::module]
-pub trait LpFarmTokenModule {
+pub trait LpFarmTokenModule:
+    crate::farms_registry::FarmsRegistryModule + utils::UtilsModule
+{
     fn get_lp_tokens_in_farm_position(
         &self,
+        lp_farm_token_id: &TokenIdentifier,
         farm_token_nonce: u64,
         farm_token_amount: &BigUint,
     ) -> BigUint {
-        let lp_farm_token_id = self.lp_farm_token_id().get();
         let attributes = self
             .blockchain()
             .get_token_attributes::<FarmTokenAttributes<Self::Api>>(
-                &lp_farm_token_id,
+                lp_farm_token_id,
                 farm_token_nonce,
             )
             .into_part(farm_token_amount);
@@ -23,7 +27,10 @@ pub trait LpFarmTokenModule {
         attributes.current_farm_amount
     }
 
+    /// Kept for compatibility, returns the LP farm token of the default farms triple.
     #[view(getLpFarmTokenId)]
-    #[storage_mapper("lpFarmTokenId")]
-    fn lp_farm_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+    fn lp_farm_token_id(&self) -> TokenIdentifier {
+        self.get_farms_triple(DEFAULT_FARMS_TRIPLE_ID)
+            .lp_farm_token_id
+    }
 }
@@ -12,6 +12,7 @@ pub struct InternalClaimResult<M: ManagedTypeApi> {
 pub trait ProxyClaimModule:
     crate::dual_yield_token::DualYieldTokenModule
     + crate::external_contracts_interactions::ExternalContractsInteractionsModule
+    + crate::farms_registry::FarmsRegistryModule
     + crate::lp_farm_token::LpFarmTokenModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + utils::UtilsModule
@@ -62,24 +63,25 @@ pub trait ProxyClaimModule:
         attributes: DualYieldTokenAttributes<Self::Api>,
     ) -> InternalClaimResult<Self::Api> {
         let orig_caller = self.get_orig_caller_from_opt(caller, opt_orig_caller);
+        let farms_triple = self.get_farms_triple(attributes.farms_triple_id);
 
         let lp_tokens_in_position = self.get_lp_tokens_in_farm_position(
+            &farms_triple.lp_farm_token_id,
             attributes.lp_farm_token_nonce,
             &attributes.lp_farm_token_amount,
         );
-        let new_staking_farm_value = self.get_lp_tokens_safe_price(lp_tokens_in_position);
+        let new_staking_farm_value =
+            self.get_lp_tokens_safe_price(&farms_triple, lp_tokens_in_position);
 
-        let staking_farm_token_id = self.staking_farm_token_id().get();
-        let lp_farm_token_id = self.lp_farm_token_id().get();
         let lp_farm_claim_rewards_result = self.lp_farm_claim_rewards(
+            &farms_triple,
             orig_caller.clone(),
-            lp_farm_token_id,
             attributes.lp_farm_token_nonce,
             attributes.lp_farm_token_amount,
         );
         let staking_farm_claim_rewards_result = self.staking_farm_claim_rewards(
+            &farms_triple,
             orig_caller,
-            staking_farm_token_id,
             attributes.staking_farm_token_nonce,
             staking_claim_amount,
             new_staking_farm_value,
@@ -92,6 +94,7 @@ pub trait ProxyClaimModule:
             lp_farm_token_amount: new_lp_farm_tokens.amount,
             staking_farm_token_nonce: new_staking_farm_tokens.token_nonce,
             staking_farm_token_amount: new_staking_farm_tokens.amount,
+            farms_triple_id: attributes.farms_triple_id,
         };
 
         InternalClaimResult {
@@ -6,6 +6,7 @@ multiversx_sc::imports!();
 pub trait ProxyStakeModule:
     crate::dual_yield_token::DualYieldTokenModule
     + crate::external_contracts_interactions::ExternalContractsInteractionsModule
+    + crate::farms_registry::FarmsRegistryModule
     + crate::lp_farm_token::LpFarmTokenModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + utils::UtilsModule
@@ -25,30 +26,31 @@ pub trait ProxyStakeModule:
         let lp_farm_token_payment = payments.get(0);
         let additional_payments = payments.slice(1, payments.len()).unwrap_or_default();
 
-        let lp_farm_token_id = self.lp_farm_token_id().get();
-        require!(
-            lp_farm_token_payment.token_identifier == lp_farm_token_id,
-            "Invalid first payment"
-        );
+        let farms_triple_id =
+            self.get_active_farms_triple_id(&lp_farm_token_payment.token_identifier);
+        let farms_triple = self.farms_triple(farms_triple_id).get();
 
         let dual_yield_token_mapper = self.dual_yield_token();
         dual_yield_token_mapper.require_all_same_token(&additional_payments);
 
-        let staking_farm_token_id = self.staking_farm_token_id().get();
         let mut additional_staking_farm_tokens = ManagedVec::new();
         let mut additional_lp_farm_tokens = ManagedVec::new();
         for p in &additional_payments {
             let attributes: DualYieldTokenAttributes<Self::Api> =
                 self.get_attributes_as_part_of_fixed_supply(&p, &dual_yield_token_mapper);
+            require!(
+                attributes.farms_triple_id == farms_triple_id,
+                "Dual yield token belongs to another farms triple"
+            );
 
             additional_staking_farm_tokens.push(EsdtTokenPayment::new(
-                staking_farm_token_id.clone(),
+                farms_triple.staking_farm_token_id.clone(),
                 attributes.staking_farm_token_nonce,
                 attributes.staking_farm_token_amount,
             ));
 
             additional_lp_farm_tokens.push(EsdtTokenPayment::new(
-                lp_farm_token_id.clone(),
+                farms_triple.lp_farm_token_id.clone(),
                 attributes.lp_farm_token_nonce,
                 attributes.lp_farm_token_amount,
             ));
@@ -57,11 +59,13 @@ pub trait ProxyStakeModule:
         }
 
         let lp_tokens_in_farm = self.get_lp_tokens_in_farm_position(
+            &farms_triple.lp_farm_token_id,
             lp_farm_token_payment.token_nonce,
             &lp_farm_token_payment.amount,
         );
-        let staking_token_amount = self.get_lp_tokens_safe_price(lp_tokens_in_farm);
+        let staking_token_amount = self.get_lp_tokens_safe_price(&farms_triple, lp_tokens_in_farm);
         let staking_farm_enter_result = self.staking_farm_enter(
+            &farms_triple,
             orig_caller.clone(),
             staking_token_amount,
             additional_staking_farm_tokens,
@@ -70,6 +74,7 @@ pub trait ProxyStakeModule:
 
         let (merged_lp_farm_tokens, lp_farm_boosted_rewards) = self
             .merge_lp_farm_tokens(
+                &farms_triple,
                 orig_caller,
                 lp_farm_token_payment,
                 additional_lp_farm_tokens,
@@ -81,6 +86,7 @@ pub trait ProxyStakeModule:
             lp_farm_token_amount: merged_lp_farm_tokens.amount,
             staking_farm_token_nonce: received_staking_farm_token.token_nonce,
             staking_farm_token_amount: received_staking_farm_token.amount,
+            farms_triple_id,
         };
         let new_dual_yield_tokens =
             self.create_dual_yield_tokens(&dual_yield_token_mapper, &new_attributes);
@@ -8,6 +8,7 @@ multiversx_sc::imports!();
 pub trait ProxyUnstakeModule:
     crate::dual_yield_token::DualYieldTokenModule
     + crate::external_contracts_interactions::ExternalContractsInteractionsModule
+    + crate::farms_registry::FarmsRegistryModule
     + crate::lp_farm_token::LpFarmTokenModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + utils::UtilsModule
@@ -34,19 +35,23 @@ pub trait ProxyUnstakeModule:
 
         let exit_attributes: DualYieldTokenAttributes<Self::Api> =
             full_attributes.into_part(&payment.amount);
+        let farms_triple = self.get_farms_triple(exit_attributes.farms_triple_id);
 
         let lp_farm_exit_result = self.lp_farm_exit(
+            &farms_triple,
             orig_caller.clone(),
             exit_attributes.lp_farm_token_nonce,
             exit_attributes.lp_farm_token_amount,
         );
         let remove_liq_result = self.pair_remove_liquidity(
+            &farms_triple,
             lp_farm_exit_result.lp_tokens,
             pair_first_token_min_amount,
             pair_second_token_min_amount,
         );
 
         let staking_farm_exit_result = self.staking_farm_unstake(
+            &farms_triple,
             orig_caller,
             remove_liq_result.staking_token_payment,
             exit_attributes.staking_farm_token_nonce,
@@ -10,7 +10,12 @@ multiversx_sc::imports!();
 use config::ConfigModule;
 use constants::*;
 use farm_staking_proxy::dual_yield_token::DualYieldTokenAttributes;
+use farm_staking_proxy::external_contracts_interactions::ExternalContractsInteractionsModule;
+use farm_staking_proxy::farms_registry::DEFAULT_FARMS_TRIPLE_ID;
+use farm_staking_proxy::lp_farm_token::LpFarmTokenModule;
 
+use farm_staking_proxy::farms_registry::FarmsRegistryModule;
+use farm_staking_proxy::proxy_actions::stake::ProxyStakeModule;
 use farm_staking_proxy::proxy_actions::unstake::ProxyUnstakeModule;
 
 use farm_staking::{
@@ -19,7 +24,8 @@ use farm_staking::{
 use farm_with_locked_rewards::Farm;
 use multiversx_sc::codec::Empty;
 use multiversx_sc_scenario::{
-    managed_address, managed_biguint, managed_token_id, rust_biguint, DebugApi,
+    managed_address, managed_biguint, managed_token_id, rust_biguint,
+    whitebox_legacy::TxTokenTransfer, DebugApi,
 };
 use pair::pair_actions::swap::SwapModule;
 use simple_lock::locked_token::LockedTokenAttributes;
@@ -437,6 +443,7 @@ fn test_stake_farm_through_proxy_with_merging() {
                 lp_farm_token_amount: managed_biguint!(400_000_000),
                 staking_farm_token_nonce: 1,
                 staking_farm_token_amount: managed_biguint!(400_000_000),
+                farms_triple_id: DEFAULT_FARMS_TRIPLE_ID,
             }),
         )
     });
@@ -467,6 +474,7 @@ fn test_stake_farm_through_proxy_with_merging() {
                 lp_farm_token_amount: managed_biguint!(1_000_000_000),
                 staking_farm_token_nonce: 2,
                 staking_farm_token_amount: managed_biguint!(1_000_000_000),
+                farms_triple_id: DEFAULT_FARMS_TRIPLE_ID,
             }),
         )
     });
@@ -540,6 +548,7 @@ fn test_farm_stake_proxy_merging_boosted_rewards() {
                 lp_farm_token_amount: managed_biguint!(farm_amount),
                 staking_farm_token_nonce: 1,
                 staking_farm_token_amount: managed_biguint!(farm_amount),
+                farms_triple_id: DEFAULT_FARMS_TRIPLE_ID,
             }),
         )
     });
@@ -601,6 +610,7 @@ fn test_farm_stake_proxy_merging_boosted_rewards() {
                 lp_farm_token_amount: managed_biguint!(farm_amount * 2),
                 staking_farm_token_nonce: 2,
                 staking_farm_token_amount: managed_biguint!(farm_amount * 2),
+                farms_triple_id: DEFAULT_FARMS_TRIPLE_ID,
             }),
         )
     });
@@ -735,6 +745,7 @@ fn claim_for_others_positive_test() {
                 lp_farm_token_amount: managed_biguint!(farm_amount),
                 staking_farm_token_nonce: 1,
                 staking_farm_token_amount: managed_biguint!(farm_amount),
+                farms_triple_id: DEFAULT_FARMS_TRIPLE_ID,
             }),
         )
     });
@@ -905,6 +916,7 @@ fn stake_farm_through_proxy_migration_test() {
                 lp_farm_token_amount: managed_biguint!(farm_amount),
                 staking_farm_token_nonce: 1,
                 staking_farm_token_amount: managed_biguint!(farm_amount),
+                farms_triple_id: DEFAULT_FARMS_TRIPLE_ID,
             }),
         )
     });
@@ -946,6 +958,7 @@ fn stake_farm_through_proxy_migration_test() {
                 lp_farm_token_amount: managed_biguint!(farm_amount * 2),
                 staking_farm_token_nonce: 4,
                 staking_farm_token_amount: managed_biguint!(farm_amount * 2),
+                farms_triple_id: DEFAULT_FARMS_TRIPLE_ID,
             }),
         )
     });
@@ -982,6 +995,7 @@ fn stake_farm_through_proxy_migration_test() {
                 lp_farm_token_amount: managed_biguint!(farm_amount),
                 staking_farm_token_nonce: 5,
                 staking_farm_token_amount: managed_biguint!(farm_amount),
+                farms_triple_id: DEFAULT_FARMS_TRIPLE_ID,
             }),
         )
     });
@@ -1081,7 +1095,11 @@ fn total_farm_position_after_claim_and_exit_metastaking_test() {
             0,
             &rust_biguint!(100_000_000u64),
             |sc| {
-                sc.swap_tokens_fixed_input(managed_token_id!(RIDE_TOKEN_ID), managed_biguint!(1));
+                sc.swap_tokens_fixed_input(
+                    managed_token_id!(RIDE_TOKEN_ID),
+                    managed_biguint!(1),
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -1097,7 +1115,11 @@ fn total_farm_position_after_claim_and_exit_metastaking_test() {
             0,
             &rust_biguint!(100_000_000u64),
             |sc| {
-                sc.swap_tokens_fixed_input(managed_token_id!(RIDE_TOKEN_ID), managed_biguint!(1));
+                sc.swap_tokens_fixed_input(
+                    managed_token_id!(RIDE_TOKEN_ID),
+                    managed_biguint!(1),
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -1113,7 +1135,11 @@ fn total_farm_position_after_claim_and_exit_metastaking_test() {
             0,
             &rust_biguint!(100_000_000u64),
             |sc| {
-                sc.swap_tokens_fixed_input(managed_token_id!(RIDE_TOKEN_ID), managed_biguint!(1));
+                sc.swap_tokens_fixed_input(
+                    managed_token_id!(RIDE_TOKEN_ID),
+                    managed_biguint!(1),
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -1216,3 +1242,201 @@ fn total_farm_position_after_claim_and_exit_metastaking_test() {
     // Total farm position should be 0 after full unstake
     setup.check_user_total_staking_farm_position(&user_address, 0);
 }
+
+#[test]
+fn farms_registry_register_and_retire_test() {
+    let mut setup = FarmStakingSetup::new(
+        pair::contract_obj,
+        farm_with_locked_rewards::contract_obj,
+        energy_factory::contract_obj,
+        farm_staking::contract_obj,
+        farm_staking_proxy::contract_obj,
+    );
+
+    let expected_staking_token_amount = 1_001_000_000;
+    let first_dual_yield_token_nonce =
+        setup.stake_farm_lp_proxy(1, USER_TOTAL_LP_TOKENS, 1, expected_staking_token_amount);
+
+    let owner_addr = setup.owner_addr.clone();
+    let user_addr = setup.user_addr.clone();
+    let lp_farm_addr = setup.lp_farm_wrapper.address_ref().clone();
+    let staking_farm_addr = setup.staking_farm_wrapper.address_ref().clone();
+    let pair_addr = setup.pair_wrapper.address_ref().clone();
+
+    // views kept for compatibility read the default triple
+    setup
+        .b_mock
+        .execute_query(&setup.proxy_wrapper, |sc| {
+            assert_eq!(sc.lp_farm_address(), managed_address!(&lp_farm_addr));
+            assert_eq!(
+                sc.staking_farm_address(),
+                managed_address!(&staking_farm_addr)
+            );
+            assert_eq!(sc.pair_address(), managed_address!(&pair_addr));
+            assert_eq!(sc.staking_token_id(), managed_token_id!(STAKING_TOKEN_ID));
+            assert_eq!(
+                sc.staking_farm_token_id(),
+                managed_token_id!(STAKING_FARM_TOKEN_ID)
+            );
+            assert_eq!(sc.lp_token_id(), managed_token_id!(LP_TOKEN_ID));
+            assert_eq!(sc.lp_farm_token_id(), managed_token_id!(LP_FARM_TOKEN_ID));
+        })
+        .assert_ok();
+
+    // the LP farm token already belongs to the default triple
+    setup
+        .b_mock
+        .execute_tx(&owner_addr, &setup.proxy_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.register_farms_triple(
+                managed_address!(&pair_addr),
+                managed_address!(&lp_farm_addr),
+                managed_address!(&staking_farm_addr),
+                managed_token_id!(STAKING_TOKEN_ID),
+                managed_token_id!(LP_FARM_TOKEN_ID),
+                managed_token_id!(STAKING_FARM_TOKEN_ID),
+                managed_token_id!(LP_TOKEN_ID),
+            );
+        })
+        .assert_user_error("LP farm token already used by an active triple");
+
+    setup
+        .b_mock
+        .execute_tx(&owner_addr, &setup.proxy_wrapper, &rust_biguint!(0), |sc| {
+            sc.retire_farms_triple(DEFAULT_FARMS_TRIPLE_ID);
+        })
+        .assert_ok();
+
+    // retired triples can no longer be entered
+    let new_lp_amount = 1_000_000;
+    setup
+        .b_mock
+        .set_esdt_balance(&user_addr, LP_TOKEN_ID, &rust_biguint!(new_lp_amount));
+    let lp_farm_token_nonce = setup.enter_lp_farm(&user_addr, new_lp_amount);
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &setup.proxy_wrapper,
+            LP_FARM_TOKEN_ID,
+            lp_farm_token_nonce,
+            &rust_biguint!(new_lp_amount),
+            |sc| {
+                let _ = sc.stake_farm_tokens(OptionalValue::None);
+            },
+        )
+        .assert_user_error("Invalid first payment");
+
+    setup
+        .b_mock
+        .execute_tx(&owner_addr, &setup.proxy_wrapper, &rust_biguint!(0), |sc| {
+            let farms_triple_id = sc.register_farms_triple(
+                managed_address!(&pair_addr),
+                managed_address!(&lp_farm_addr),
+                managed_address!(&staking_farm_addr),
+                managed_token_id!(STAKING_TOKEN_ID),
+                managed_token_id!(LP_FARM_TOKEN_ID),
+                managed_token_id!(STAKING_FARM_TOKEN_ID),
+                managed_token_id!(LP_TOKEN_ID),
+            );
+            assert_eq!(farms_triple_id, DEFAULT_FARMS_TRIPLE_ID + 1);
+
+            let active_farms_triple_ids: Vec<u64> = sc
+                .get_active_farms_triples()
+                .into_iter()
+                .map(|entry| entry.into_tuple().0)
+                .collect();
+            assert_eq!(active_farms_triple_ids, vec![farms_triple_id]);
+        })
+        .assert_ok();
+
+    // positions of different triples can't be merged
+    let transfers = vec![
+        TxTokenTransfer {
+            token_identifier: LP_FARM_TOKEN_ID.to_vec(),
+            nonce: lp_farm_token_nonce,
+            value: rust_biguint!(new_lp_amount),
+        },
+        TxTokenTransfer {
+            token_identifier: DUAL_YIELD_TOKEN_ID.to_vec(),
+            nonce: first_dual_yield_token_nonce,
+            value: rust_biguint!(expected_staking_token_amount),
+        },
+    ];
+    setup
+        .b_mock
+        .execute_esdt_multi_transfer(&user_addr, &setup.proxy_wrapper, &transfers, |sc| {
+            let _ = sc.stake_farm_tokens(OptionalValue::None);
+        })
+        .assert_user_error("Dual yield token belongs to another farms triple");
+
+    let mut second_dual_yield_token_nonce = 0;
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &setup.proxy_wrapper,
+            LP_FARM_TOKEN_ID,
+            lp_farm_token_nonce,
+            &rust_biguint!(new_lp_amount),
+            |sc| {
+                let dual_yield_tokens = sc.stake_farm_tokens(OptionalValue::None).dual_yield_tokens;
+                second_dual_yield_token_nonce = dual_yield_tokens.token_nonce;
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.execute_in_managed_environment(|| {
+        let attributes: DualYieldTokenAttributes<DebugApi> = setup
+            .b_mock
+            .get_nft_attributes(
+                &user_addr,
+                DUAL_YIELD_TOKEN_ID,
+                second_dual_yield_token_nonce,
+            )
+            .unwrap();
+        assert_eq!(attributes.farms_triple_id, DEFAULT_FARMS_TRIPLE_ID + 1);
+    });
+
+    // positions of the retired triple can still be exited
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &setup.proxy_wrapper,
+            DUAL_YIELD_TOKEN_ID,
+            first_dual_yield_token_nonce,
+            &rust_biguint!(expected_staking_token_amount),
+            |sc| {
+                let _ = sc.unstake_farm_tokens(
+                    managed_biguint!(1),
+                    managed_biguint!(1),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn dual_yield_token_attributes_legacy_decode_test() {
+    DebugApi::dummy();
+
+    let mut legacy_encoded = Vec::new();
+    legacy_encoded.extend_from_slice(&5u64.to_be_bytes());
+    legacy_encoded.extend_from_slice(&[0, 0, 0, 1, 100]);
+    legacy_encoded.extend_from_slice(&7u64.to_be_bytes());
+    legacy_encoded.extend_from_slice(&[0, 0, 0, 1, 200]);
+
+    let attributes =
+        DualYieldTokenAttributes::<DebugApi>::top_decode(legacy_encoded.as_slice()).unwrap();
+    assert_eq!(
+        attributes,
+        DualYieldTokenAttributes {
+            lp_farm_token_nonce: 5,
+            lp_farm_token_amount: managed_biguint!(100),
+            staking_farm_token_nonce: 7,
+            staking_farm_token_amount: managed_biguint!(200),
+            farms_triple_id: DEFAULT_FARMS_TRIPLE_ID,
+        }
+    );
+}
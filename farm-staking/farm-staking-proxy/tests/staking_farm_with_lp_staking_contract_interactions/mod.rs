@@ -22,6 +22,7 @@ use farm_staking::{
     unstake_farm::UnstakeFarmModule,
 };
 use farm_staking_proxy::dual_yield_token::DualYieldTokenAttributes;
+use farm_staking_proxy::farms_registry::DEFAULT_FARMS_TRIPLE_ID;
 use farm_staking_proxy::proxy_actions::claim::ProxyClaimModule;
 
 use farm_staking_proxy::proxy_actions::stake::ProxyStakeModule;
@@ -193,6 +194,7 @@ where
                 lp_farm_token_amount: managed_biguint!(lp_farm_token_stake_amount),
                 staking_farm_token_nonce: expected_staking_farm_token_nonce,
                 staking_farm_token_amount: managed_biguint!(expected_staking_token_amount),
+                farms_triple_id: DEFAULT_FARMS_TRIPLE_ID,
             };
 
             self.b_mock.check_nft_balance(
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           17
+// Endpoints:                           22
 // Async Callback:                       1
-// Total number of exported functions:  20
+// Total number of exported functions:  25
 
 #![no_std]
 
@@ -28,6 +28,11 @@ multiversx_sc_wasm_adapter::endpoints! {
         getStakingTokenId => staking_token_id
         getFarmTokenId => staking_farm_token_id
         getLpTokenId => lp_token_id
+        registerFarmsTriple => register_farms_triple
+        retireFarmsTriple => retire_farms_triple
+        getActiveFarmsTriples => get_active_farms_triples
+        getFarmsTriple => farms_triple
+        getFarmsTripleIdForLpFarmToken => farms_triple_id_for_lp_farm_token
         getLpFarmTokenId => lp_farm_token_id
         setEnergyFactoryAddress => set_energy_factory_address
         getEnergyFactoryAddress => energy_factory_address
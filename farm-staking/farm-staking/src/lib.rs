@@ -19,6 +19,7 @@ pub mod claim_stake_farm_rewards;
 pub mod compound_stake_farm_rewards;
 pub mod custom_rewards;
 pub mod farm_token_roles;
+pub mod pending_unbond;
 pub mod stake_farm;
 pub mod token_attributes;
 pub mod unbond_farm;
@@ -49,6 +50,7 @@ pub trait FarmStaking:
     + compound_stake_farm_rewards::CompoundStakeFarmRewardsModule
     + unstake_farm::UnstakeFarmModule
     + unbond_farm::UnbondFarmModule
+    + pending_unbond::PendingUnbondModule
     + claim_only_boosted_staking_rewards::ClaimOnlyBoostedStakingRewardsModule
     + farm_boosted_yields::FarmBoostedYieldsModule
     + farm_boosted_yields::boosted_yields_factors::BoostedYieldsFactorsModule
@@ -0,0 +1,85 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use common_structs::{Epoch, Nonce};
+
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug,
+)]
+pub struct PendingUnbond<M: ManagedTypeApi> {
+    pub token_nonce: Nonce,
+    pub amount: BigUint<M>,
+    pub unlock_epoch: Epoch,
+}
+
+/// Keeps track of the unbond tokens that were not yet unbonded,
+/// per address that received them on unstake.
+#[multiversx_sc::module]
+pub trait PendingUnbondModule {
+    /// Up to `limit` of the pending unbond tokens created for the given user,
+    /// together with their remaining amount and the epoch at which they can be unbonded.
+    /// Starts from the given token nonce, or from the first one, and also returns the nonce to continue from, if any.
+    #[view(getPendingUnbonds)]
+    fn get_pending_unbonds(
+        &self,
+        user: ManagedAddress,
+        limit: usize,
+        opt_start_nonce: OptionalValue<Nonce>,
+    ) -> pagination::KeyPaginatedResult<Self::Api, Nonce, PendingUnbond<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let opt_next_nonce = pagination::for_each_in_key_page(
+            &self.pending_unbond_nonces(&user),
+            limit,
+            opt_start_nonce,
+            |token_nonce| result.push(self.pending_unbond(token_nonce).get()),
+        );
+
+        (opt_next_nonce, result).into()
+    }
+
+    fn add_pending_unbond(
+        &self,
+        user: &ManagedAddress,
+        token_nonce: Nonce,
+        amount: BigUint,
+        unlock_epoch: Epoch,
+    ) {
+        let _ = self.pending_unbond_nonces(user).insert(token_nonce);
+        self.pending_unbond_owner(token_nonce).set(user);
+        self.pending_unbond(token_nonce).set(PendingUnbond {
+            token_nonce,
+            amount,
+            unlock_epoch,
+        });
+    }
+
+    /// Unbond tokens created before the tracking was introduced are ignored.
+    fn remove_pending_unbond(&self, token_nonce: Nonce, amount: &BigUint) {
+        let owner_mapper = self.pending_unbond_owner(token_nonce);
+        if owner_mapper.is_empty() {
+            return;
+        }
+
+        let pending_unbond_mapper = self.pending_unbond(token_nonce);
+        let mut pending_unbond = pending_unbond_mapper.get();
+        if &pending_unbond.amount > amount {
+            pending_unbond.amount -= amount;
+            pending_unbond_mapper.set(pending_unbond);
+
+            return;
+        }
+
+        let owner = owner_mapper.take();
+        let _ = self.pending_unbond_nonces(&owner).remove(&token_nonce);
+        pending_unbond_mapper.clear();
+    }
+
+    #[storage_mapper("pendingUnbondNonces")]
+    fn pending_unbond_nonces(&self, user: &ManagedAddress) -> SetMapper<Nonce>;
+
+    #[storage_mapper("pendingUnbondOwner")]
+    fn pending_unbond_owner(&self, token_nonce: Nonce) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("pendingUnbond")]
+    fn pending_unbond(&self, token_nonce: Nonce) -> SingleValueMapper<PendingUnbond<Self::Api>>;
+}
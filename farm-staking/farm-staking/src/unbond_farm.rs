@@ -2,11 +2,23 @@ multiversx_sc::imports!();
 
 use contexts::storage_cache::StorageCache;
 
-use crate::token_attributes::UnbondSftAttributes;
+use crate::{custom_rewards::MAX_PERCENT, token_attributes::UnbondSftAttributes};
+
+pub mod fees_collector_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait FeesCollectorProxy {
+        #[payable("*")]
+        #[endpoint(depositSwapFees)]
+        fn deposit_swap_fees(&self);
+    }
+}
 
 #[multiversx_sc::module]
 pub trait UnbondFarmModule:
     crate::custom_rewards::CustomRewardsModule
+    + crate::pending_unbond::PendingUnbondModule
     + rewards::RewardsModule
     + config::ConfigModule
     + events::EventsModule
@@ -31,15 +43,7 @@ pub trait UnbondFarmModule:
     #[payable("*")]
     #[endpoint(unbondFarm)]
     fn unbond_farm(&self) -> EsdtTokenPayment {
-        let storage_cache = StorageCache::new(self);
-        self.validate_contract_state(storage_cache.contract_state, &storage_cache.farm_token_id);
-
-        let farm_token_mapper = self.farm_token();
-        let payment = self.call_value().single_esdt();
-        farm_token_mapper.require_same_token(&payment.token_identifier);
-
-        let attributes: UnbondSftAttributes =
-            farm_token_mapper.get_token_attributes(payment.token_nonce);
+        let (payment, attributes, storage_cache) = self.take_unbond_payment();
 
         let current_epoch = self.blockchain().get_block_epoch();
         require!(
@@ -47,8 +51,6 @@ pub trait UnbondFarmModule:
             "Unbond period not over"
         );
 
-        farm_token_mapper.nft_burn(payment.token_nonce, &payment.amount);
-
         let caller = self.blockchain().get_caller();
         let farming_tokens =
             EsdtTokenPayment::new(storage_cache.farming_token_id.clone(), 0, payment.amount);
@@ -56,4 +58,136 @@ pub trait UnbondFarmModule:
 
         farming_tokens
     }
+
+    /// Unbonds the tokens before the unbond period is over, for a fee.
+    /// The fee either goes to the remaining stakers, by being distributed as rewards to the current farm positions,
+    /// or to the fees collector, if one was configured.
+    /// No fee is taken if the unbond period is already over.
+    #[payable("*")]
+    #[endpoint(instantUnbondFarm)]
+    fn instant_unbond_farm(&self) -> EsdtTokenPayment {
+        let fee_percentage_mapper = self.instant_unbond_fee_percentage();
+        require!(
+            !fee_percentage_mapper.is_empty(),
+            "Instant unbond not enabled"
+        );
+
+        let (payment, attributes, mut storage_cache) = self.take_unbond_payment();
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        let fee_amount = if current_epoch >= attributes.unlock_epoch {
+            BigUint::zero()
+        } else {
+            &payment.amount * fee_percentage_mapper.get() / MAX_PERCENT
+        };
+        self.send_instant_unbond_fee(&mut storage_cache, fee_amount.clone());
+
+        let caller = self.blockchain().get_caller();
+        let farming_tokens = EsdtTokenPayment::new(
+            storage_cache.farming_token_id.clone(),
+            0,
+            payment.amount - fee_amount,
+        );
+        self.send_payment_non_zero(&caller, &farming_tokens);
+
+        farming_tokens
+    }
+
+    /// Enables instant unbonding, for the given fee percentage.
+    /// If a fees collector address is given, the fees are sent there.
+    /// Otherwise, they are distributed as rewards to the current farm positions.
+    #[endpoint(setInstantUnbondFee)]
+    fn set_instant_unbond_fee(
+        &self,
+        fee_percentage: u64,
+        opt_fees_collector_address: OptionalValue<ManagedAddress>,
+    ) {
+        self.require_caller_has_admin_permissions();
+        require!(
+            fee_percentage > 0 && fee_percentage < MAX_PERCENT,
+            "Invalid fee percentage"
+        );
+
+        match opt_fees_collector_address {
+            OptionalValue::Some(fees_collector_address) => {
+                require!(
+                    self.blockchain().is_smart_contract(&fees_collector_address),
+                    "Invalid fees collector address"
+                );
+                self.instant_unbond_fees_collector_address()
+                    .set(fees_collector_address);
+            }
+            OptionalValue::None => self.instant_unbond_fees_collector_address().clear(),
+        }
+
+        self.instant_unbond_fee_percentage().set(fee_percentage);
+    }
+
+    #[endpoint(disableInstantUnbond)]
+    fn disable_instant_unbond(&self) {
+        self.require_caller_has_admin_permissions();
+
+        self.instant_unbond_fee_percentage().clear();
+        self.instant_unbond_fees_collector_address().clear();
+    }
+
+    fn take_unbond_payment(&self) -> (EsdtTokenPayment, UnbondSftAttributes, StorageCache<Self>) {
+        let storage_cache = StorageCache::new(self);
+        self.validate_contract_state(storage_cache.contract_state, &storage_cache.farm_token_id);
+
+        let farm_token_mapper = self.farm_token();
+        let payment = self.call_value().single_esdt();
+        farm_token_mapper.require_same_token(&payment.token_identifier);
+
+        let attributes: UnbondSftAttributes =
+            farm_token_mapper.get_token_attributes(payment.token_nonce);
+
+        farm_token_mapper.nft_burn(payment.token_nonce, &payment.amount);
+        self.remove_pending_unbond(payment.token_nonce, &payment.amount);
+
+        (payment, attributes, storage_cache)
+    }
+
+    fn send_instant_unbond_fee(&self, storage_cache: &mut StorageCache<Self>, fee_amount: BigUint) {
+        if fee_amount == 0 {
+            return;
+        }
+
+        let fees_collector_mapper = self.instant_unbond_fees_collector_address();
+        if !fees_collector_mapper.is_empty() {
+            let _: IgnoreValue = self
+                .fees_collector_proxy_builder(fees_collector_mapper.get())
+                .deposit_swap_fees()
+                .with_esdt_transfer((storage_cache.farming_token_id.clone(), 0, fee_amount))
+                .execute_on_dest_context();
+
+            return;
+        }
+
+        // farming and reward token are the same
+        self.reward_capacity().update(|r| *r += &fee_amount);
+        if storage_cache.farm_token_supply == 0 {
+            return;
+        }
+
+        let rps_increase = &fee_amount * &storage_cache.division_safety_constant
+            / &storage_cache.farm_token_supply;
+        self.accumulated_rewards().update(|r| *r += &fee_amount);
+        storage_cache.reward_reserve += fee_amount;
+        storage_cache.reward_per_share += rps_increase;
+    }
+
+    #[proxy]
+    fn fees_collector_proxy_builder(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> fees_collector_proxy::Proxy<Self::Api>;
+
+    #[view(getInstantUnbondFeePercentage)]
+    #[storage_mapper("instantUnbondFeePercentage")]
+    fn instant_unbond_fee_percentage(&self) -> SingleValueMapper<u64>;
+
+    #[view(getInstantUnbondFeesCollectorAddress)]
+    #[storage_mapper("instantUnbondFeesCollectorAddress")]
+    fn instant_unbond_fees_collector_address(&self) -> SingleValueMapper<ManagedAddress>;
 }
@@ -30,6 +30,7 @@ pub trait UnstakeFarmModule:
     + weekly_rewards_splitting::locked_token_buckets::WeeklyRewardsLockedTokenBucketsModule
     + weekly_rewards_splitting::update_claim_progress_energy::UpdateClaimProgressEnergyModule
     + energy_query::EnergyQueryModule
+    + crate::pending_unbond::PendingUnbondModule
 {
     #[payable("*")]
     #[endpoint(unstakeFarm)]
@@ -111,15 +112,15 @@ pub trait UnstakeFarmModule:
     ) -> EsdtTokenPayment {
         let min_unbond_epochs = self.min_unbond_epochs().get();
         let current_epoch = self.blockchain().get_block_epoch();
+        let unlock_epoch = current_epoch + min_unbond_epochs;
         let nft_nonce = self.send().esdt_nft_create_compact(
             &farm_token_id,
             &amount,
-            &UnbondSftAttributes {
-                unlock_epoch: current_epoch + min_unbond_epochs,
-            },
+            &UnbondSftAttributes { unlock_epoch },
         );
         self.send()
             .direct_esdt(to, &farm_token_id, nft_nonce, &amount);
+        self.add_pending_unbond(to, nft_nonce, amount.clone(), unlock_epoch);
 
         EsdtTokenPayment::new(farm_token_id, nft_nonce, amount)
     }
@@ -18,6 +18,7 @@ use energy_query::{Energy, EnergyQueryModule};
 use farm_boosted_yields::boosted_yields_factors::BoostedYieldsFactorsModule;
 use farm_staking::claim_stake_farm_rewards::ClaimStakeFarmRewardsModule;
 use farm_staking::custom_rewards::CustomRewardsModule;
+use farm_staking::pending_unbond::{PendingUnbond, PendingUnbondModule};
 use farm_staking::stake_farm::StakeFarmModule;
 use farm_staking::token_attributes::{StakingFarmTokenAttributes, UnbondSftAttributes};
 use farm_staking::unbond_farm::UnbondFarmModule;
@@ -566,6 +567,90 @@ where
         );
     }
 
+    pub fn set_instant_unbond_fee(&mut self, fee_percentage: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.farm_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.set_instant_unbond_fee(fee_percentage, OptionalValue::None);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn instant_unbond_farm(
+        &mut self,
+        farm_token_nonce: u64,
+        farm_tokem_amount: u64,
+        expected_farming_token_out: u64,
+        expected_user_farming_token_balance: u64,
+    ) {
+        self.b_mock
+            .execute_esdt_transfer(
+                &self.user_address,
+                &self.farm_wrapper,
+                FARM_TOKEN_ID,
+                farm_token_nonce,
+                &rust_biguint!(farm_tokem_amount),
+                |sc| {
+                    let payment = sc.instant_unbond_farm();
+                    assert_eq!(
+                        payment.token_identifier,
+                        managed_token_id!(FARMING_TOKEN_ID)
+                    );
+                    assert_eq!(payment.token_nonce, 0);
+                    assert_eq!(payment.amount, managed_biguint!(expected_farming_token_out));
+                },
+            )
+            .assert_ok();
+
+        self.b_mock.check_esdt_balance(
+            &self.user_address,
+            FARMING_TOKEN_ID,
+            &rust_biguint!(expected_user_farming_token_balance),
+        );
+    }
+
+    pub fn check_pending_unbonds(
+        &mut self,
+        user: &Address,
+        expected_pending_unbonds: &[(u64, u64, u64)],
+    ) {
+        self.b_mock
+            .execute_query(&self.farm_wrapper, |sc| {
+                // query one entry per page, to go through the pagination as well
+                let mut pending_unbonds = Vec::new();
+                let mut opt_start_nonce = OptionalValue::None;
+                loop {
+                    let (opt_next_nonce, page) = sc
+                        .get_pending_unbonds(managed_address!(user), 1, opt_start_nonce)
+                        .into_tuple();
+                    pending_unbonds.extend(page);
+                    match opt_next_nonce {
+                        Some(next_nonce) => opt_start_nonce = OptionalValue::Some(next_nonce),
+                        None => break,
+                    }
+                }
+                assert_eq!(pending_unbonds.len(), expected_pending_unbonds.len());
+
+                for (i, (token_nonce, amount, unlock_epoch)) in
+                    expected_pending_unbonds.iter().enumerate()
+                {
+                    assert_eq!(
+                        pending_unbonds[i],
+                        PendingUnbond {
+                            token_nonce: *token_nonce,
+                            amount: managed_biguint!(*amount),
+                            unlock_epoch: *unlock_epoch,
+                        }
+                    );
+                }
+            })
+            .assert_ok();
+    }
+
     pub fn check_farm_token_supply(&mut self, expected_farm_token_supply: u64) {
         self.b_mock
             .execute_query(&self.farm_wrapper, |sc| {
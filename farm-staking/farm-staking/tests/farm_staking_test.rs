@@ -6,6 +6,7 @@ pub mod farm_staking_setup;
 use farm_staking::{
     custom_rewards::{BLOCKS_IN_YEAR, MAX_PERCENT},
     token_attributes::UnbondSftAttributes,
+    unbond_farm::UnbondFarmModule,
 };
 use farm_staking_setup::*;
 
@@ -285,6 +286,121 @@ fn test_unbond() {
     );
 }
 
+#[test]
+fn test_instant_unbond() {
+    DebugApi::dummy();
+    let mut farm_setup =
+        FarmStakingSetup::new(farm_staking::contract_obj, energy_factory::contract_obj);
+
+    let user_address = farm_setup.user_address.clone();
+    let user_address2 = farm_setup.user_address2.clone();
+
+    let farm_in_amount = 100_000_000;
+    let expected_farm_token_nonce = 1;
+    farm_setup.stake_farm(
+        &user_address,
+        farm_in_amount,
+        &[],
+        expected_farm_token_nonce,
+        0,
+        0,
+    );
+    // remaining staker, which receives the instant unbond fees
+    farm_setup.stake_farm(
+        &user_address2,
+        farm_in_amount,
+        &[],
+        expected_farm_token_nonce + 1,
+        0,
+        0,
+    );
+
+    let current_block = 10;
+    let current_epoch = 5;
+    farm_setup.set_block_epoch(current_epoch);
+    farm_setup.set_block_nonce(current_block);
+
+    let expected_rewards = 45;
+    let expected_ride_token_balance =
+        rust_biguint!(USER_TOTAL_RIDE_TOKENS) - farm_in_amount + expected_rewards;
+    let unbond_token_nonce = expected_farm_token_nonce + 2;
+    let unlock_epoch = current_epoch + MIN_UNBOND_EPOCHS;
+    farm_setup.unstake_farm(
+        &user_address,
+        farm_in_amount,
+        expected_farm_token_nonce,
+        expected_rewards,
+        &expected_ride_token_balance,
+        &expected_ride_token_balance,
+        unbond_token_nonce,
+        farm_in_amount,
+        &UnbondSftAttributes { unlock_epoch },
+    );
+    let expected_rps = 450_000;
+    farm_setup.check_farm_rps(expected_rps);
+    farm_setup.check_pending_unbonds(
+        &user_address,
+        &[(unbond_token_nonce, farm_in_amount, unlock_epoch)],
+    );
+
+    // instant unbond not enabled
+    farm_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user_address,
+            &farm_setup.farm_wrapper,
+            FARM_TOKEN_ID,
+            unbond_token_nonce,
+            &rust_biguint!(farm_in_amount),
+            |sc| {
+                let _ = sc.instant_unbond_farm();
+            },
+        )
+        .assert_user_error("Instant unbond not enabled");
+
+    // 5% fee, which goes to the remaining stakers
+    let fee_percentage = 500;
+    farm_setup.set_instant_unbond_fee(fee_percentage);
+
+    let first_unbond_amount = farm_in_amount / 2;
+    let fee_amount = first_unbond_amount * fee_percentage / MAX_PERCENT;
+    let mut user_ride_balance =
+        USER_TOTAL_RIDE_TOKENS - farm_in_amount + expected_rewards + first_unbond_amount
+            - fee_amount;
+    farm_setup.instant_unbond_farm(
+        unbond_token_nonce,
+        first_unbond_amount,
+        first_unbond_amount - fee_amount,
+        user_ride_balance,
+    );
+    farm_setup.check_rewards_capacity(TOTAL_REWARDS_AMOUNT + fee_amount);
+    let expected_rps = expected_rps + fee_amount * DIVISION_SAFETY_CONSTANT / farm_in_amount;
+    farm_setup.check_farm_rps(expected_rps);
+    farm_setup.check_pending_unbonds(
+        &user_address,
+        &[(
+            unbond_token_nonce,
+            farm_in_amount - first_unbond_amount,
+            unlock_epoch,
+        )],
+    );
+
+    // no fee after the unbond period is over
+    farm_setup.set_block_epoch(unlock_epoch);
+
+    let second_unbond_amount = farm_in_amount - first_unbond_amount;
+    user_ride_balance += second_unbond_amount;
+    farm_setup.instant_unbond_farm(
+        unbond_token_nonce,
+        second_unbond_amount,
+        second_unbond_amount,
+        user_ride_balance,
+    );
+    farm_setup.check_rewards_capacity(TOTAL_REWARDS_AMOUNT + fee_amount);
+    farm_setup.check_farm_rps(expected_rps);
+    farm_setup.check_pending_unbonds(&user_address, &[]);
+}
+
 #[test]
 fn test_withdraw_rewards() {
     DebugApi::dummy();
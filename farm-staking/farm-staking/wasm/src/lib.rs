@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           69
+// Endpoints:                           75
 // Async Callback:                       1
-// Total number of exported functions:  72
+// Total number of exported functions:  78
 
 #![no_std]
 
@@ -68,6 +68,12 @@ multiversx_sc_wasm_adapter::endpoints! {
         unstakeFarm => unstake_farm
         unstakeFarmThroughProxy => unstake_farm_through_proxy
         unbondFarm => unbond_farm
+        instantUnbondFarm => instant_unbond_farm
+        setInstantUnbondFee => set_instant_unbond_fee
+        disableInstantUnbond => disable_instant_unbond
+        getInstantUnbondFeePercentage => instant_unbond_fee_percentage
+        getInstantUnbondFeesCollectorAddress => instant_unbond_fees_collector_address
+        getPendingUnbonds => get_pending_unbonds
         claimBoostedRewards => claim_boosted_rewards
         collectUndistributedBoostedRewards => collect_undistributed_boosted_rewards
         getBoostedYieldsRewardsPercentage => boosted_yields_rewards_percentage
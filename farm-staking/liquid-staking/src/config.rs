@@ -0,0 +1,70 @@
+multiversx_sc::imports!();
+
+pub const MAX_PERCENTAGE: u64 = 10_000;
+pub const MAX_INSTANT_UNSTAKE_FEE_PERCENTAGE: u64 = 1_000;
+
+#[multiversx_sc::module]
+pub trait ConfigModule {
+    /// The percentage of the total staked value that is kept unstaked,
+    /// to be used for instant unstakes. New stakes refill the buffer up to this percentage.
+    #[only_owner]
+    #[endpoint(setBufferPercentage)]
+    fn set_buffer_percentage(&self, buffer_percentage: u64) {
+        require!(
+            buffer_percentage <= MAX_PERCENTAGE,
+            "Invalid buffer percentage"
+        );
+
+        self.buffer_percentage().set(buffer_percentage);
+    }
+
+    /// The fee taken from instant unstakes paid from the buffer.
+    /// The fee is not sent anywhere, it increases the value of the liquid token instead.
+    #[only_owner]
+    #[endpoint(setInstantUnstakeFeePercentage)]
+    fn set_instant_unstake_fee_percentage(&self, fee_percentage: u64) {
+        require!(
+            fee_percentage <= MAX_INSTANT_UNSTAKE_FEE_PERCENTAGE,
+            "Invalid fee percentage"
+        );
+
+        self.instant_unstake_fee_percentage().set(fee_percentage);
+    }
+
+    /// A pair of the liquid token and the staking token.
+    /// Instant unstakes are swapped through this pair when the buffer is not enough.
+    #[only_owner]
+    #[endpoint(setExitPairAddress)]
+    fn set_exit_pair_address(&self, pair_address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&pair_address),
+            "Invalid pair address"
+        );
+
+        self.exit_pair_address().set(pair_address);
+    }
+
+    #[view(getFarmStakingAddress)]
+    #[storage_mapper("farmStakingAddress")]
+    fn farm_staking_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getStakingTokenId)]
+    #[storage_mapper("stakingTokenId")]
+    fn staking_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getFarmTokenId)]
+    #[storage_mapper("farmTokenId")]
+    fn farm_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getBufferPercentage)]
+    #[storage_mapper("bufferPercentage")]
+    fn buffer_percentage(&self) -> SingleValueMapper<u64>;
+
+    #[view(getInstantUnstakeFeePercentage)]
+    #[storage_mapper("instantUnstakeFeePercentage")]
+    fn instant_unstake_fee_percentage(&self) -> SingleValueMapper<u64>;
+
+    #[view(getExitPairAddress)]
+    #[storage_mapper("exitPairAddress")]
+    fn exit_pair_address(&self) -> SingleValueMapper<ManagedAddress>;
+}
@@ -0,0 +1,80 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(TypeAbi, TopEncode)]
+pub struct LiquidStakingEvent<M: ManagedTypeApi> {
+    staking_token_amount: BigUint<M>,
+    liquid_token_amount: BigUint<M>,
+    staked_amount: BigUint<M>,
+    buffer_amount: BigUint<M>,
+    liquid_token_supply: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait EventsModule:
+    crate::config::ConfigModule
+    + crate::farm_interactions::FarmInteractionsModule
+    + crate::liquid_token::LiquidTokenModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    fn emit_stake_event(&self, staking_token_amount: BigUint, liquid_token_amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let epoch = self.blockchain().get_block_epoch();
+        self.stake_event(
+            &caller,
+            epoch,
+            &self.build_liquid_staking_event(staking_token_amount, liquid_token_amount),
+        );
+    }
+
+    fn emit_unstake_event(
+        &self,
+        staking_token_amount: BigUint,
+        liquid_token_amount: BigUint,
+        instant: bool,
+    ) {
+        let caller = self.blockchain().get_caller();
+        let epoch = self.blockchain().get_block_epoch();
+        self.unstake_event(
+            &caller,
+            epoch,
+            instant,
+            &self.build_liquid_staking_event(staking_token_amount, liquid_token_amount),
+        );
+    }
+
+    fn build_liquid_staking_event(
+        &self,
+        staking_token_amount: BigUint,
+        liquid_token_amount: BigUint,
+    ) -> LiquidStakingEvent<Self::Api> {
+        LiquidStakingEvent {
+            staking_token_amount,
+            liquid_token_amount,
+            staked_amount: self.get_staked_amount(),
+            buffer_amount: self.buffer_amount().get(),
+            liquid_token_supply: self.liquid_token_supply().get(),
+        }
+    }
+
+    #[event("stake")]
+    fn stake_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] epoch: u64,
+        stake_event: &LiquidStakingEvent<Self::Api>,
+    );
+
+    #[event("unstake")]
+    fn unstake_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] instant: bool,
+        unstake_event: &LiquidStakingEvent<Self::Api>,
+    );
+
+    #[view(getBufferAmount)]
+    #[storage_mapper("bufferAmount")]
+    fn buffer_amount(&self) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,96 @@
+multiversx_sc::imports!();
+
+use farm_staking::{
+    compound_stake_farm_rewards::ProxyTrait as _, stake_farm::ProxyTrait as _,
+    unstake_farm::ProxyTrait as _,
+};
+
+/// The contract holds a single farm-staking position for all the liquid token holders.
+#[multiversx_sc::module]
+pub trait FarmInteractionsModule: crate::config::ConfigModule {
+    /// Compounds the rewards of the current position, increasing the value of the liquid token
+    fn compound_position(&self) {
+        let position_mapper = self.farm_position();
+        if position_mapper.is_empty() {
+            return;
+        }
+
+        let position = position_mapper.get();
+        let new_position: EsdtTokenPayment = self
+            .farm_staking_proxy(self.farm_staking_address().get())
+            .compound_rewards()
+            .with_esdt_transfer(position)
+            .execute_on_dest_context();
+
+        position_mapper.set(new_position);
+    }
+
+    /// Stakes the tokens, merging them into the current position.
+    /// Returns the boosted rewards received, if any.
+    fn stake_into_position(&self, amount: BigUint) -> BigUint {
+        let mut payments = ManagedVec::from_single_item(EsdtTokenPayment::new(
+            self.staking_token_id().get(),
+            0,
+            amount,
+        ));
+        let position_mapper = self.farm_position();
+        if !position_mapper.is_empty() {
+            payments.push(position_mapper.get());
+        }
+
+        let enter_result: MultiValue2<EsdtTokenPayment, EsdtTokenPayment> = self
+            .farm_staking_proxy(self.farm_staking_address().get())
+            .stake_farm_endpoint(OptionalValue::<ManagedAddress>::None)
+            .with_multi_token_transfer(payments)
+            .execute_on_dest_context();
+        let (new_position, boosted_rewards) = enter_result.into_tuple();
+        position_mapper.set(new_position);
+
+        boosted_rewards.amount
+    }
+
+    /// Unstakes part of the current position.
+    /// Returns the unbond tokens and the rewards received.
+    fn unstake_from_position(&self, amount: BigUint) -> (EsdtTokenPayment, BigUint) {
+        let position_mapper = self.farm_position();
+        let mut position = position_mapper.get();
+        require!(position.amount >= amount, "Not enough staked tokens");
+
+        let exit_payment = EsdtTokenPayment::new(
+            position.token_identifier.clone(),
+            position.token_nonce,
+            amount,
+        );
+        let exit_result: MultiValue2<EsdtTokenPayment, EsdtTokenPayment> = self
+            .farm_staking_proxy(self.farm_staking_address().get())
+            .unstake_farm(OptionalValue::<ManagedAddress>::None)
+            .with_esdt_transfer(exit_payment.clone())
+            .execute_on_dest_context();
+        let (unbond_tokens, rewards) = exit_result.into_tuple();
+
+        position.amount -= exit_payment.amount;
+        if position.amount == 0 {
+            position_mapper.clear();
+        } else {
+            position_mapper.set(position);
+        }
+
+        (unbond_tokens, rewards.amount)
+    }
+
+    fn get_staked_amount(&self) -> BigUint {
+        let position_mapper = self.farm_position();
+        if position_mapper.is_empty() {
+            return BigUint::zero();
+        }
+
+        position_mapper.get().amount
+    }
+
+    #[proxy]
+    fn farm_staking_proxy(&self, sc_address: ManagedAddress) -> farm_staking::Proxy<Self::Api>;
+
+    #[view(getFarmPosition)]
+    #[storage_mapper("farmPosition")]
+    fn farm_position(&self) -> SingleValueMapper<EsdtTokenPayment>;
+}
@@ -0,0 +1,237 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod config;
+pub mod events;
+pub mod farm_interactions;
+pub mod liquid_token;
+
+use config::MAX_PERCENTAGE;
+use pair::pair_actions::swap::ProxyTrait as _;
+
+/// Wraps farm-staking positions into a fungible liquid token.
+/// All the staked tokens are held in a single farm-staking position, whose rewards are compounded,
+/// so the amount of staking tokens each liquid token is worth keeps increasing.
+#[multiversx_sc::contract]
+pub trait LiquidStaking:
+    config::ConfigModule
+    + farm_interactions::FarmInteractionsModule
+    + liquid_token::LiquidTokenModule
+    + events::EventsModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    #[init]
+    fn init(
+        &self,
+        farm_staking_address: ManagedAddress,
+        staking_token_id: TokenIdentifier,
+        farm_token_id: TokenIdentifier,
+    ) {
+        require!(
+            self.blockchain().is_smart_contract(&farm_staking_address),
+            "Invalid farm staking address"
+        );
+        require!(
+            staking_token_id.is_valid_esdt_identifier() && farm_token_id.is_valid_esdt_identifier(),
+            "Invalid token ID"
+        );
+
+        self.farm_staking_address().set(farm_staking_address);
+        self.staking_token_id().set(staking_token_id);
+        self.farm_token_id().set(farm_token_id);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Stakes the tokens and returns liquid tokens, at the current exchange rate.
+    #[payable("*")]
+    #[endpoint(stake)]
+    fn stake(&self) -> EsdtTokenPayment {
+        let (token_id, amount) = self.call_value().single_fungible_esdt();
+        require!(
+            token_id == self.staking_token_id().get(),
+            "Invalid payment token"
+        );
+
+        self.compound_position();
+
+        let total_value = self.get_total_staked_value();
+        let liquid_token_supply = self.liquid_token_supply().get();
+        let liquid_token_amount = if liquid_token_supply == 0 || total_value == 0 {
+            amount.clone()
+        } else {
+            &amount * &liquid_token_supply / &total_value
+        };
+        require!(liquid_token_amount > 0, "Amount too low");
+
+        let buffer_amount = self.buffer_amount().get();
+        let target_buffer_amount =
+            (&total_value + &amount) * self.buffer_percentage().get() / MAX_PERCENTAGE;
+        let mut buffered_amount = if target_buffer_amount > buffer_amount {
+            target_buffer_amount - &buffer_amount
+        } else {
+            BigUint::zero()
+        };
+        if buffered_amount > amount {
+            buffered_amount = amount.clone();
+        }
+
+        let amount_to_stake = &amount - &buffered_amount;
+        let mut new_buffer_amount = buffer_amount + buffered_amount;
+        if amount_to_stake > 0 {
+            new_buffer_amount += self.stake_into_position(amount_to_stake);
+        }
+        self.buffer_amount().set(new_buffer_amount);
+
+        self.liquid_token_supply()
+            .update(|supply| *supply += &liquid_token_amount);
+        let caller = self.blockchain().get_caller();
+        let liquid_tokens = self
+            .liquid_token()
+            .mint_and_send(&caller, liquid_token_amount.clone());
+
+        self.emit_stake_event(amount, liquid_token_amount);
+
+        liquid_tokens
+    }
+
+    /// Burns the liquid tokens and starts the regular unbond period for the tokens they are worth.
+    /// Returns the farm-staking unbond tokens, which can be redeemed through the farm-staking's unbondFarm.
+    /// If part of the value can only be covered from the buffer, that part is sent directly.
+    #[payable("*")]
+    #[endpoint(unstake)]
+    fn unstake(&self) -> ManagedVec<EsdtTokenPayment> {
+        let liquid_token_amount = self.take_liquid_token_payment();
+        let value = self.get_liquid_token_value(&liquid_token_amount);
+        self.burn_liquid_tokens(&liquid_token_amount);
+
+        let staked_amount = self.get_staked_amount();
+        let (amount_to_unstake, amount_from_buffer) = if value > staked_amount {
+            let amount_from_buffer = &value - &staked_amount;
+            (staked_amount, amount_from_buffer)
+        } else {
+            (value.clone(), BigUint::zero())
+        };
+
+        let mut output_payments = ManagedVec::new();
+        let mut buffer_amount = self.buffer_amount().get();
+        if amount_to_unstake > 0 {
+            let (unbond_tokens, rewards) = self.unstake_from_position(amount_to_unstake);
+            buffer_amount += rewards;
+            output_payments.push(unbond_tokens);
+        }
+        if amount_from_buffer > 0 {
+            buffer_amount -= &amount_from_buffer;
+            output_payments.push(EsdtTokenPayment::new(
+                self.staking_token_id().get(),
+                0,
+                amount_from_buffer,
+            ));
+        }
+        self.buffer_amount().set(buffer_amount);
+
+        let caller = self.blockchain().get_caller();
+        self.send().direct_multi(&caller, &output_payments);
+
+        self.emit_unstake_event(value, liquid_token_amount, false);
+
+        output_payments
+    }
+
+    /// Exits instantly, paying the staking tokens from the buffer, minus the instant unstake fee.
+    /// If the buffer is not enough, the liquid tokens are swapped through the exit pair instead.
+    #[payable("*")]
+    #[endpoint(instantUnstake)]
+    fn instant_unstake(&self, min_amount_out: BigUint) -> EsdtTokenPayment {
+        let liquid_token_amount = self.take_liquid_token_payment();
+        let value = self.get_liquid_token_value(&liquid_token_amount);
+        let fee = &value * self.instant_unstake_fee_percentage().get() / MAX_PERCENTAGE;
+        let amount_out = value - fee;
+
+        let caller = self.blockchain().get_caller();
+        let buffer_amount = self.buffer_amount().get();
+        if buffer_amount >= amount_out {
+            require!(amount_out >= min_amount_out, "Slippage exceeded");
+
+            self.burn_liquid_tokens(&liquid_token_amount);
+            self.buffer_amount().set(buffer_amount - &amount_out);
+
+            let output_payment =
+                EsdtTokenPayment::new(self.staking_token_id().get(), 0, amount_out.clone());
+            self.send()
+                .direct_non_zero_esdt_payment(&caller, &output_payment);
+
+            self.emit_unstake_event(amount_out, liquid_token_amount, true);
+
+            return output_payment;
+        }
+
+        require!(
+            !self.exit_pair_address().is_empty(),
+            "Not enough tokens in buffer"
+        );
+
+        let output_payment: EsdtTokenPayment = self
+            .exit_pair_proxy(self.exit_pair_address().get())
+            .swap_tokens_fixed_input(
+                self.staking_token_id().get(),
+                min_amount_out,
+                OptionalValue::<ManagedAddress>::None,
+            )
+            .with_esdt_transfer(EsdtTokenPayment::new(
+                self.liquid_token().get_token_id(),
+                0,
+                liquid_token_amount.clone(),
+            ))
+            .execute_on_dest_context();
+        self.send()
+            .direct_non_zero_esdt_payment(&caller, &output_payment);
+
+        self.emit_unstake_event(output_payment.amount.clone(), liquid_token_amount, true);
+
+        output_payment
+    }
+
+    /// Compounds the staking rewards. Can be called by anyone.
+    #[endpoint(compound)]
+    fn compound(&self) {
+        self.compound_position();
+    }
+
+    /// The amount of staking tokens the given amount of liquid tokens is worth,
+    /// with the rewards compounded up to the last interaction.
+    #[view(getLiquidTokenValue)]
+    fn get_liquid_token_value(&self, liquid_token_amount: &BigUint) -> BigUint {
+        let liquid_token_supply = self.liquid_token_supply().get();
+        if liquid_token_supply == 0 {
+            return liquid_token_amount.clone();
+        }
+
+        liquid_token_amount * &self.get_total_staked_value() / liquid_token_supply
+    }
+
+    #[view(getTotalStakedValue)]
+    fn get_total_staked_value(&self) -> BigUint {
+        self.get_staked_amount() + self.buffer_amount().get()
+    }
+
+    fn take_liquid_token_payment(&self) -> BigUint {
+        let (token_id, amount) = self.call_value().single_fungible_esdt();
+        self.liquid_token().require_same_token(&token_id);
+
+        self.compound_position();
+
+        amount
+    }
+
+    fn burn_liquid_tokens(&self, amount: &BigUint) {
+        self.liquid_token().burn(amount);
+        self.liquid_token_supply()
+            .update(|supply| *supply -= amount);
+    }
+
+    #[proxy]
+    fn exit_pair_proxy(&self, sc_address: ManagedAddress) -> pair::Proxy<Self::Api>;
+}
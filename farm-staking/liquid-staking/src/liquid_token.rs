@@ -0,0 +1,33 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait LiquidTokenModule:
+    multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(registerLiquidToken)]
+    fn register_liquid_token(
+        &self,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+        num_decimals: usize,
+    ) {
+        let register_cost = self.call_value().egld_value().clone_value();
+        self.liquid_token().issue_and_set_all_roles(
+            register_cost,
+            token_display_name,
+            token_ticker,
+            num_decimals,
+            None,
+        );
+    }
+
+    #[view(getLiquidTokenSupply)]
+    #[storage_mapper("liquidTokenSupply")]
+    fn liquid_token_supply(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getLiquidTokenId)]
+    #[storage_mapper("liquidTokenId")]
+    fn liquid_token(&self) -> FungibleTokenMapper;
+}
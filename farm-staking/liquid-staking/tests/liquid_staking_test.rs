@@ -0,0 +1,387 @@
+#![allow(deprecated)]
+
+use config::ConfigModule as FarmConfigModule;
+use energy_query::EnergyQueryModule;
+use farm_staking::{
+    custom_rewards::CustomRewardsModule, token_attributes::UnbondSftAttributes, FarmStaking,
+};
+use farm_token::FarmTokenModule;
+use liquid_staking::{
+    config::ConfigModule, events::EventsModule, farm_interactions::FarmInteractionsModule,
+    liquid_token::LiquidTokenModule, LiquidStaking,
+};
+use multiversx_sc::{
+    storage::mappers::StorageTokenWrapper,
+    types::{Address, EsdtLocalRole, ManagedAddress, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint, whitebox_legacy::*, DebugApi,
+};
+use pausable::{PausableModule, State};
+
+static STAKING_TOKEN_ID: &[u8] = b"RIDE-abcdef";
+static FARM_TOKEN_ID: &[u8] = b"FARM-abcdef";
+static LIQUID_TOKEN_ID: &[u8] = b"LRIDE-abcdef";
+const DIVISION_SAFETY_CONSTANT: u64 = 1_000_000_000_000;
+const MIN_UNBOND_EPOCHS: u64 = 5;
+const MAX_APR: u64 = 2_500; // 25%
+const PER_BLOCK_REWARD_AMOUNT: u64 = 5_000;
+const TOTAL_REWARDS_AMOUNT: u64 = 1_000_000_000_000;
+const USER_BALANCE: u64 = 5_000_000_000;
+const STAKE_AMOUNT: u64 = 1_000_000_000;
+
+struct LiquidStakingSetup<FarmObjBuilder, EnergyFactoryBuilder, LiquidStakingObjBuilder>
+where
+    FarmObjBuilder: 'static + Copy + Fn() -> farm_staking::ContractObj<DebugApi>,
+    EnergyFactoryBuilder: 'static + Copy + Fn() -> energy_factory::ContractObj<DebugApi>,
+    LiquidStakingObjBuilder: 'static + Copy + Fn() -> liquid_staking::ContractObj<DebugApi>,
+{
+    b_mock: BlockchainStateWrapper,
+    owner_address: Address,
+    first_user: Address,
+    second_user: Address,
+    _farm_wrapper: ContractObjWrapper<farm_staking::ContractObj<DebugApi>, FarmObjBuilder>,
+    _energy_factory_wrapper:
+        ContractObjWrapper<energy_factory::ContractObj<DebugApi>, EnergyFactoryBuilder>,
+    ls_wrapper: ContractObjWrapper<liquid_staking::ContractObj<DebugApi>, LiquidStakingObjBuilder>,
+}
+
+impl<FarmObjBuilder, EnergyFactoryBuilder, LiquidStakingObjBuilder>
+    LiquidStakingSetup<FarmObjBuilder, EnergyFactoryBuilder, LiquidStakingObjBuilder>
+where
+    FarmObjBuilder: 'static + Copy + Fn() -> farm_staking::ContractObj<DebugApi>,
+    EnergyFactoryBuilder: 'static + Copy + Fn() -> energy_factory::ContractObj<DebugApi>,
+    LiquidStakingObjBuilder: 'static + Copy + Fn() -> liquid_staking::ContractObj<DebugApi>,
+{
+    fn new(
+        farm_builder: FarmObjBuilder,
+        energy_factory_builder: EnergyFactoryBuilder,
+        ls_builder: LiquidStakingObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0);
+        let mut b_mock = BlockchainStateWrapper::new();
+        let owner_address = b_mock.create_user_account(&rust_zero);
+        let farm_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner_address),
+            farm_builder,
+            "farm-staking",
+        );
+        let energy_factory_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner_address),
+            energy_factory_builder,
+            "energy_factory.wasm",
+        );
+        let ls_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner_address),
+            ls_builder,
+            "liquid-staking",
+        );
+
+        b_mock
+            .execute_tx(&owner_address, &farm_wrapper, &rust_zero, |sc| {
+                sc.init(
+                    managed_token_id!(STAKING_TOKEN_ID),
+                    managed_biguint!(DIVISION_SAFETY_CONSTANT),
+                    managed_biguint!(MAX_APR),
+                    MIN_UNBOND_EPOCHS,
+                    ManagedAddress::<DebugApi>::zero(),
+                    MultiValueEncoded::new(),
+                );
+
+                sc.farm_token()
+                    .set_token_id(managed_token_id!(FARM_TOKEN_ID));
+                sc.per_block_reward_amount()
+                    .set(&managed_biguint!(PER_BLOCK_REWARD_AMOUNT));
+                sc.state().set(State::Active);
+                sc.produce_rewards_enabled().set(true);
+                sc.energy_factory_address()
+                    .set(managed_address!(energy_factory_wrapper.address_ref()));
+            })
+            .assert_ok();
+
+        b_mock.set_esdt_balance(
+            &owner_address,
+            STAKING_TOKEN_ID,
+            &rust_biguint!(TOTAL_REWARDS_AMOUNT),
+        );
+        b_mock
+            .execute_esdt_transfer(
+                &owner_address,
+                &farm_wrapper,
+                STAKING_TOKEN_ID,
+                0,
+                &rust_biguint!(TOTAL_REWARDS_AMOUNT),
+                |sc| {
+                    sc.top_up_rewards();
+                },
+            )
+            .assert_ok();
+
+        b_mock.set_esdt_local_roles(
+            farm_wrapper.address_ref(),
+            FARM_TOKEN_ID,
+            &[
+                EsdtLocalRole::NftCreate,
+                EsdtLocalRole::NftAddQuantity,
+                EsdtLocalRole::NftBurn,
+            ],
+        );
+        b_mock.set_esdt_local_roles(
+            farm_wrapper.address_ref(),
+            STAKING_TOKEN_ID,
+            &[EsdtLocalRole::Burn],
+        );
+
+        b_mock
+            .execute_tx(&owner_address, &ls_wrapper, &rust_zero, |sc| {
+                sc.init(
+                    managed_address!(farm_wrapper.address_ref()),
+                    managed_token_id!(STAKING_TOKEN_ID),
+                    managed_token_id!(FARM_TOKEN_ID),
+                );
+                sc.liquid_token()
+                    .set_token_id(managed_token_id!(LIQUID_TOKEN_ID));
+            })
+            .assert_ok();
+        b_mock.set_esdt_local_roles(
+            ls_wrapper.address_ref(),
+            LIQUID_TOKEN_ID,
+            &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+        );
+
+        let first_user = b_mock.create_user_account(&rust_zero);
+        let second_user = b_mock.create_user_account(&rust_zero);
+        b_mock.set_esdt_balance(&first_user, STAKING_TOKEN_ID, &rust_biguint!(USER_BALANCE));
+        b_mock.set_esdt_balance(&second_user, STAKING_TOKEN_ID, &rust_biguint!(USER_BALANCE));
+
+        LiquidStakingSetup {
+            b_mock,
+            owner_address,
+            first_user,
+            second_user,
+            _farm_wrapper: farm_wrapper,
+            _energy_factory_wrapper: energy_factory_wrapper,
+            ls_wrapper,
+        }
+    }
+
+    fn stake(&mut self, user: &Address, amount: u64, expected_liquid_tokens: u64) {
+        self.b_mock
+            .execute_esdt_transfer(
+                user,
+                &self.ls_wrapper,
+                STAKING_TOKEN_ID,
+                0,
+                &rust_biguint!(amount),
+                |sc| {
+                    let liquid_tokens = sc.stake();
+                    assert_eq!(
+                        liquid_tokens.amount,
+                        managed_biguint!(expected_liquid_tokens)
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    fn get_total_staked_value(&mut self) -> u64 {
+        let mut total_value = 0;
+        self.b_mock
+            .execute_query(&self.ls_wrapper, |sc| {
+                total_value = sc.get_total_staked_value().to_u64().unwrap();
+            })
+            .assert_ok();
+
+        total_value
+    }
+}
+
+#[test]
+fn liquid_staking_compounds_rewards_test() {
+    let mut setup = LiquidStakingSetup::new(
+        farm_staking::contract_obj,
+        energy_factory::contract_obj,
+        liquid_staking::contract_obj,
+    );
+
+    let first_user = setup.first_user.clone();
+    let second_user = setup.second_user.clone();
+    setup.stake(&first_user, STAKE_AMOUNT, STAKE_AMOUNT);
+    setup
+        .b_mock
+        .check_esdt_balance(&first_user, LIQUID_TOKEN_ID, &rust_biguint!(STAKE_AMOUNT));
+
+    setup.b_mock.set_block_nonce(100);
+    setup
+        .b_mock
+        .execute_tx(&first_user, &setup.ls_wrapper, &rust_biguint!(0), |sc| {
+            sc.compound();
+        })
+        .assert_ok();
+
+    // the rewards of the single position were compounded
+    let total_value = setup.get_total_staked_value();
+    assert!(total_value > STAKE_AMOUNT);
+
+    // later stakers receive less liquid tokens for the same amount
+    let expected_liquid_tokens = STAKE_AMOUNT * STAKE_AMOUNT / total_value;
+    setup.stake(&second_user, STAKE_AMOUNT, expected_liquid_tokens);
+
+    setup
+        .b_mock
+        .execute_query(&setup.ls_wrapper, |sc| {
+            assert_eq!(
+                sc.liquid_token_supply().get(),
+                managed_biguint!(STAKE_AMOUNT + expected_liquid_tokens)
+            );
+            assert_eq!(
+                sc.farm_position().get().amount,
+                managed_biguint!(total_value + STAKE_AMOUNT)
+            );
+            assert_eq!(
+                sc.get_liquid_token_value(&managed_biguint!(STAKE_AMOUNT)),
+                managed_biguint!(total_value)
+            );
+        })
+        .assert_ok();
+
+    // the first user unstakes through the regular unbond queue
+    setup.b_mock.set_block_epoch(2);
+    let mut unbond_token_nonce = 0;
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.ls_wrapper,
+            LIQUID_TOKEN_ID,
+            0,
+            &rust_biguint!(STAKE_AMOUNT),
+            |sc| {
+                let output_payments = sc.unstake();
+                assert_eq!(output_payments.len(), 1);
+
+                let unbond_tokens = output_payments.get(0);
+                assert_eq!(
+                    unbond_tokens.token_identifier,
+                    managed_token_id!(FARM_TOKEN_ID)
+                );
+                assert_eq!(unbond_tokens.amount, managed_biguint!(total_value));
+                unbond_token_nonce = unbond_tokens.token_nonce;
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.check_nft_balance(
+        &first_user,
+        FARM_TOKEN_ID,
+        unbond_token_nonce,
+        &rust_biguint!(total_value),
+        Some(&UnbondSftAttributes {
+            unlock_epoch: 2 + MIN_UNBOND_EPOCHS,
+        }),
+    );
+    setup
+        .b_mock
+        .check_esdt_balance(&first_user, LIQUID_TOKEN_ID, &rust_biguint!(0));
+}
+
+#[test]
+fn liquid_staking_instant_unstake_test() {
+    let mut setup = LiquidStakingSetup::new(
+        farm_staking::contract_obj,
+        energy_factory::contract_obj,
+        liquid_staking::contract_obj,
+    );
+
+    let owner_address = setup.owner_address.clone();
+    setup
+        .b_mock
+        .execute_tx(&owner_address, &setup.ls_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_buffer_percentage(1_000); // 10%
+            sc.set_instant_unstake_fee_percentage(100); // 1%
+        })
+        .assert_ok();
+
+    let first_user = setup.first_user.clone();
+    let second_user = setup.second_user.clone();
+    setup.stake(&first_user, STAKE_AMOUNT, STAKE_AMOUNT);
+    setup.stake(&second_user, STAKE_AMOUNT, STAKE_AMOUNT);
+
+    let buffer_amount = STAKE_AMOUNT * 2 / 10;
+    setup
+        .b_mock
+        .execute_query(&setup.ls_wrapper, |sc| {
+            assert_eq!(sc.buffer_amount().get(), managed_biguint!(buffer_amount));
+            assert_eq!(
+                sc.farm_position().get().amount,
+                managed_biguint!(STAKE_AMOUNT * 2 - buffer_amount)
+            );
+        })
+        .assert_ok();
+
+    // the buffer is not enough, and there is no exit pair
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.ls_wrapper,
+            LIQUID_TOKEN_ID,
+            0,
+            &rust_biguint!(STAKE_AMOUNT),
+            |sc| {
+                let _ = sc.instant_unstake(managed_biguint!(1));
+            },
+        )
+        .assert_user_error("Not enough tokens in buffer");
+
+    let unstake_amount = buffer_amount / 2;
+    let expected_amount_out = unstake_amount - unstake_amount / 100;
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.ls_wrapper,
+            LIQUID_TOKEN_ID,
+            0,
+            &rust_biguint!(unstake_amount),
+            |sc| {
+                let output_payment = sc.instant_unstake(managed_biguint!(expected_amount_out));
+                assert_eq!(output_payment.amount, managed_biguint!(expected_amount_out));
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.check_esdt_balance(
+        &first_user,
+        STAKING_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - STAKE_AMOUNT + expected_amount_out),
+    );
+
+    // the fee stays in the contract, for the remaining liquid token holders
+    setup
+        .b_mock
+        .execute_query(&setup.ls_wrapper, |sc| {
+            assert_eq!(
+                sc.buffer_amount().get(),
+                managed_biguint!(buffer_amount - expected_amount_out)
+            );
+            assert_eq!(
+                sc.liquid_token_supply().get(),
+                managed_biguint!(STAKE_AMOUNT * 2 - unstake_amount)
+            );
+            assert!(
+                sc.get_liquid_token_value(&managed_biguint!(STAKE_AMOUNT))
+                    > managed_biguint!(STAKE_AMOUNT)
+            );
+        })
+        .assert_ok();
+
+    setup.b_mock.check_esdt_balance(
+        setup.ls_wrapper.address_ref(),
+        STAKING_TOKEN_ID,
+        &rust_biguint!(buffer_amount - expected_amount_out),
+    );
+}
@@ -0,0 +1,46 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           20
+// Async Callback:                       1
+// Total number of exported functions:  23
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    liquid_staking
+    (
+        init => init
+        upgrade => upgrade
+        stake => stake
+        unstake => unstake
+        instantUnstake => instant_unstake
+        compound => compound
+        getLiquidTokenValue => get_liquid_token_value
+        getTotalStakedValue => get_total_staked_value
+        setBufferPercentage => set_buffer_percentage
+        setInstantUnstakeFeePercentage => set_instant_unstake_fee_percentage
+        setExitPairAddress => set_exit_pair_address
+        getFarmStakingAddress => farm_staking_address
+        getStakingTokenId => staking_token_id
+        getFarmTokenId => farm_token_id
+        getBufferPercentage => buffer_percentage
+        getInstantUnstakeFeePercentage => instant_unstake_fee_percentage
+        getExitPairAddress => exit_pair_address
+        getFarmPosition => farm_position
+        registerLiquidToken => register_liquid_token
+        getLiquidTokenSupply => liquid_token_supply
+        getLiquidTokenId => liquid_token
+        getBufferAmount => buffer_amount
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback! { liquid_staking }
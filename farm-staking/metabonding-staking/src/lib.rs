@@ -149,4 +149,29 @@ pub trait MetabondingStaking:
 
         result
     }
+
+    /// Paginated version of getSnapshot, over the users.
+    /// The users that unbond everything are swapped out of the list, so pages read while users unbond
+    /// can skip or repeat some of them, see pagination::Page.
+    #[view(getSnapshotPaginated)]
+    fn get_snapshot_paginated(
+        &self,
+        start_index: usize,
+        limit: usize,
+    ) -> pagination::PaginatedResult<Self::Api, SnapshotEntry<Self::Api>> {
+        let user_list = self.user_list();
+        let page = pagination::get_page(start_index, limit, user_list.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in page.indexes {
+            // the set mapper indexes start from 1
+            let user_address = user_list.get_by_index(index + 1);
+            let entry: UserEntry<Self::Api> = self.entry_for_user(&user_address).get();
+            if entry.stake_amount > 0 {
+                result.push((user_address, entry.stake_amount).into());
+            }
+        }
+
+        (page.next_index, result).into()
+    }
 }
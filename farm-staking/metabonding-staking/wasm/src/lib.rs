@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           10
+// Endpoints:                           11
 // Async Callback (empty):               1
-// Total number of exported functions:  13
+// Total number of exported functions:  14
 
 #![no_std]
 
@@ -26,6 +26,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         getStakedAmountForUser => get_staked_amount_for_user
         getUserEntry => get_user_entry
         getSnapshot => get_snapshot
+        getSnapshotPaginated => get_snapshot_paginated
         getLockedAssetTokenId => locked_asset_token_id
         getLockedAssetFactoryAddress => locked_asset_factory_address
         getTotalLockedAssetSupply => total_locked_asset_supply
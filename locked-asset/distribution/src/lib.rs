@@ -7,6 +7,7 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 mod global_op;
+pub mod merkle_distribution;
 
 const GAS_THRESHOLD: u64 = 100_000;
 const MAX_CLAIMABLE_DISTRIBUTION_ROUNDS: usize = 4;
@@ -31,7 +32,9 @@ pub struct CommunityDistribution<M: ManagedTypeApi> {
 }
 
 #[multiversx_sc::contract]
-pub trait Distribution: global_op::GlobalOperationModule {
+pub trait Distribution:
+    global_op::GlobalOperationModule + merkle_distribution::MerkleDistributionModule
+{
     #[proxy]
     fn locked_asset_factory_proxy(&self, to: ManagedAddress) -> factory::Proxy<Self::Api>;
 
@@ -0,0 +1,291 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use energy_factory::ProxyTrait as _;
+
+pub type MerkleHash<M> = ManagedByteArray<M, 32>;
+pub type CampaignId = u64;
+
+const BITMAP_WORD_BITS: u64 = 64;
+static BASE_TOKEN_ID_STORAGE_KEY: &[u8] = b"baseAssetTokenId";
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone)]
+pub struct MerkleCampaign<M: ManagedTypeApi> {
+    pub token_id: TokenIdentifier<M>,
+    pub merkle_root: MerkleHash<M>,
+    pub total_amount: BigUint<M>,
+    pub claimed_amount: BigUint<M>,
+    pub expiry_epoch: u64,
+    pub allow_unlocked_claims: bool,
+    pub leftovers_recovered: bool,
+}
+
+/// Distribution mode where the owner only posts the Merkle root of each campaign,
+/// instead of storing every user's amount.
+///
+/// Each leaf is keccak256(index as 8 bytes big-endian | user address | amount as big-endian bytes).
+/// Each node is the keccak256 of its two children concatenated in ascending order.
+#[multiversx_sc::module]
+pub trait MerkleDistributionModule {
+    #[only_owner]
+    #[endpoint(setEnergyFactoryAddress)]
+    fn set_energy_factory_address(&self, energy_factory_address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&energy_factory_address),
+            "Invalid SC address"
+        );
+
+        self.energy_factory_address().set(energy_factory_address);
+    }
+
+    #[only_owner]
+    #[endpoint(setTreasuryAddress)]
+    fn set_treasury_address(&self, treasury_address: ManagedAddress) {
+        require!(!treasury_address.is_zero(), "Invalid treasury address");
+
+        self.treasury_address().set(treasury_address);
+    }
+
+    /// Creates a campaign funded with the payment. The users can claim until the expiry epoch,
+    /// after which the leftovers can be sent to the treasury.
+    ///
+    /// If allow_unlocked_claims is false, the users can only claim energy-factory locked tokens,
+    /// so the payment must be the energy-factory base asset.
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(createMerkleCampaign)]
+    fn create_merkle_campaign(
+        &self,
+        merkle_root: MerkleHash<Self::Api>,
+        expiry_epoch: u64,
+        allow_unlocked_claims: bool,
+    ) -> CampaignId {
+        let payment = self.call_value().single_esdt();
+        require!(payment.token_nonce == 0, "Only fungible tokens accepted");
+        require!(payment.amount > 0, "Zero amount");
+        require!(
+            expiry_epoch > self.blockchain().get_block_epoch(),
+            "Expiry epoch in the past"
+        );
+        if !allow_unlocked_claims {
+            require!(
+                payment.token_identifier == self.get_energy_factory_base_token_id(),
+                "Only the base asset can be locked"
+            );
+        }
+
+        let campaign_id = self.last_merkle_campaign_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let campaign = MerkleCampaign {
+            token_id: payment.token_identifier,
+            merkle_root,
+            total_amount: payment.amount,
+            claimed_amount: BigUint::zero(),
+            expiry_epoch,
+            allow_unlocked_claims,
+            leftovers_recovered: false,
+        };
+        self.merkle_campaign(campaign_id).set(campaign);
+
+        campaign_id
+    }
+
+    /// Claims the caller's amount of a campaign, given its index in the tree and the Merkle proof.
+    ///
+    /// The tokens are locked in the energy factory for lock_epochs, which must be one of its lock options.
+    /// A lock_epochs of 0 claims the plain tokens, if the campaign allows it.
+    #[endpoint(claimMerkleDistribution)]
+    fn claim_merkle_distribution(
+        &self,
+        campaign_id: CampaignId,
+        index: u64,
+        amount: BigUint,
+        lock_epochs: u64,
+        proof: MultiValueEncoded<MerkleHash<Self::Api>>,
+    ) -> EsdtTokenPayment {
+        let campaign_mapper = self.merkle_campaign(campaign_id);
+        require!(!campaign_mapper.is_empty(), "Unknown campaign");
+
+        let mut campaign = campaign_mapper.get();
+        require!(
+            self.blockchain().get_block_epoch() < campaign.expiry_epoch,
+            "Campaign expired"
+        );
+        require!(
+            !self.is_merkle_distribution_claimed(campaign_id, index),
+            "Already claimed"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let leaf = self.compute_merkle_leaf(index, &caller, &amount);
+        require!(
+            self.verify_merkle_proof(leaf, proof, &campaign.merkle_root),
+            "Invalid proof"
+        );
+
+        campaign.claimed_amount += &amount;
+        require!(
+            campaign.claimed_amount <= campaign.total_amount,
+            "Claims exceed the campaign total"
+        );
+        self.set_merkle_distribution_claimed(campaign_id, index);
+        campaign_mapper.set(&campaign);
+
+        let payment = EsdtTokenPayment::new(campaign.token_id, 0, amount);
+        if lock_epochs == 0 {
+            require!(
+                campaign.allow_unlocked_claims,
+                "Only locked claims are allowed"
+            );
+
+            self.send().direct_esdt(
+                &caller,
+                &payment.token_identifier,
+                payment.token_nonce,
+                &payment.amount,
+            );
+
+            return payment;
+        }
+
+        let energy_factory_address = self.energy_factory_address().get();
+        self.energy_factory_proxy(energy_factory_address)
+            .lock_tokens_endpoint(lock_epochs, OptionalValue::Some(caller))
+            .with_esdt_transfer(payment)
+            .execute_on_dest_context()
+    }
+
+    /// Sends the unclaimed tokens of an expired campaign to the treasury
+    #[endpoint(recoverExpiredCampaign)]
+    fn recover_expired_campaign(&self, campaign_id: CampaignId) -> BigUint {
+        let campaign_mapper = self.merkle_campaign(campaign_id);
+        require!(!campaign_mapper.is_empty(), "Unknown campaign");
+
+        let mut campaign = campaign_mapper.get();
+        require!(
+            self.blockchain().get_block_epoch() >= campaign.expiry_epoch,
+            "Campaign not expired"
+        );
+        require!(!campaign.leftovers_recovered, "Leftovers already recovered");
+        require!(!self.treasury_address().is_empty(), "Treasury not set");
+
+        let leftovers = &campaign.total_amount - &campaign.claimed_amount;
+        campaign.leftovers_recovered = true;
+        campaign_mapper.set(&campaign);
+
+        if leftovers > 0 {
+            self.send().direct_esdt(
+                &self.treasury_address().get(),
+                &campaign.token_id,
+                0,
+                &leftovers,
+            );
+        }
+
+        leftovers
+    }
+
+    #[view(isMerkleDistributionClaimed)]
+    fn is_merkle_distribution_claimed(&self, campaign_id: CampaignId, index: u64) -> bool {
+        let word = self
+            .merkle_claimed_bitmap(campaign_id, index / BITMAP_WORD_BITS)
+            .get();
+        let mask = 1u64 << (index % BITMAP_WORD_BITS);
+
+        word & mask != 0
+    }
+
+    fn set_merkle_distribution_claimed(&self, campaign_id: CampaignId, index: u64) {
+        let mask = 1u64 << (index % BITMAP_WORD_BITS);
+        self.merkle_claimed_bitmap(campaign_id, index / BITMAP_WORD_BITS)
+            .update(|word| *word |= mask);
+    }
+
+    fn compute_merkle_leaf(
+        &self,
+        index: u64,
+        address: &ManagedAddress,
+        amount: &BigUint,
+    ) -> MerkleHash<Self::Api> {
+        let mut leaf_data = ManagedBuffer::new_from_bytes(&index.to_be_bytes());
+        leaf_data.append(address.as_managed_buffer());
+        leaf_data.append(&amount.to_bytes_be_buffer());
+
+        self.crypto().keccak256(leaf_data)
+    }
+
+    fn hash_merkle_pair(
+        &self,
+        first: &MerkleHash<Self::Api>,
+        second: &MerkleHash<Self::Api>,
+    ) -> MerkleHash<Self::Api> {
+        let (lower, higher) = if first.to_byte_array() <= second.to_byte_array() {
+            (first, second)
+        } else {
+            (second, first)
+        };
+
+        let mut node_data = lower.as_managed_buffer().clone();
+        node_data.append(higher.as_managed_buffer());
+
+        self.crypto().keccak256(node_data)
+    }
+
+    fn verify_merkle_proof(
+        &self,
+        leaf: MerkleHash<Self::Api>,
+        proof: MultiValueEncoded<MerkleHash<Self::Api>>,
+        merkle_root: &MerkleHash<Self::Api>,
+    ) -> bool {
+        let mut computed_hash = leaf;
+        for proof_element in proof {
+            computed_hash = self.hash_merkle_pair(&computed_hash, &proof_element);
+        }
+
+        &computed_hash == merkle_root
+    }
+
+    fn get_energy_factory_base_token_id(&self) -> TokenIdentifier {
+        let energy_factory_mapper = self.energy_factory_address();
+        require!(
+            !energy_factory_mapper.is_empty(),
+            "Energy factory address not set"
+        );
+
+        self.storage_raw().read_from_address(
+            &energy_factory_mapper.get(),
+            ManagedBuffer::new_from_bytes(BASE_TOKEN_ID_STORAGE_KEY),
+        )
+    }
+
+    #[proxy]
+    fn energy_factory_proxy(&self, to: ManagedAddress) -> energy_factory::Proxy<Self::Api>;
+
+    #[view(getMerkleCampaign)]
+    #[storage_mapper("merkle_campaign")]
+    fn merkle_campaign(
+        &self,
+        campaign_id: CampaignId,
+    ) -> SingleValueMapper<MerkleCampaign<Self::Api>>;
+
+    #[view(getLastMerkleCampaignId)]
+    #[storage_mapper("last_merkle_campaign_id")]
+    fn last_merkle_campaign_id(&self) -> SingleValueMapper<CampaignId>;
+
+    #[storage_mapper("merkle_claimed_bitmap")]
+    fn merkle_claimed_bitmap(
+        &self,
+        campaign_id: CampaignId,
+        word_index: u64,
+    ) -> SingleValueMapper<u64>;
+
+    #[view(getEnergyFactoryAddress)]
+    #[storage_mapper("energy_factory_address")]
+    fn energy_factory_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getTreasuryAddress)]
+    #[storage_mapper("treasury_address")]
+    fn treasury_address(&self) -> SingleValueMapper<ManagedAddress>;
+}
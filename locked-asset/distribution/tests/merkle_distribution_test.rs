@@ -0,0 +1,318 @@
+#![allow(deprecated)]
+
+use distribution::merkle_distribution::{MerkleDistributionModule, MerkleHash};
+use distribution::Distribution;
+use multiversx_sc::storage::mappers::StorageTokenWrapper;
+use multiversx_sc::types::{Address, EsdtLocalRole, ManagedByteArray, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint, whitebox_legacy::*, DebugApi,
+};
+
+use energy_factory::SimpleLockEnergy;
+use multiversx_sc_modules::pause::PauseModule;
+use simple_lock::locked_token::LockedTokenModule;
+
+pub const EPOCHS_IN_YEAR: u64 = 360;
+pub const EXPIRY_EPOCH: u64 = 20;
+
+pub static BASE_ASSET_TOKEN_ID: &[u8] = b"MEX-123456";
+pub static LOCKED_TOKEN_ID: &[u8] = b"LOCKED-123456";
+pub static LEGACY_LOCKED_TOKEN_ID: &[u8] = b"LEGACY-123456";
+pub static OTHER_TOKEN_ID: &[u8] = b"OTHER-123456";
+
+pub static LOCK_OPTIONS: &[u64] = &[EPOCHS_IN_YEAR, 2 * EPOCHS_IN_YEAR, 4 * EPOCHS_IN_YEAR]; // 1, 2 or 4 years
+pub static PENALTY_PERCENTAGES: &[u64] = &[4_000, 6_000, 8_000];
+
+fn to_proof(proof: &[[u8; 32]]) -> MultiValueEncoded<DebugApi, MerkleHash<DebugApi>> {
+    let mut result = MultiValueEncoded::new();
+    for proof_element in proof {
+        result.push(ManagedByteArray::new_from_bytes(proof_element));
+    }
+
+    result
+}
+
+#[test]
+fn merkle_distribution_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+
+    let owner_addr = b_mock.create_user_account(&rust_zero);
+    let treasury_addr = b_mock.create_user_account(&rust_zero);
+    let users: Vec<Address> = (0..3)
+        .map(|_| b_mock.create_user_account(&rust_zero))
+        .collect();
+    let amounts = [1_000u64, 2_000, 500];
+
+    let distribution_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner_addr),
+        distribution::contract_obj,
+        "distribution path",
+    );
+    let factory_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner_addr),
+        energy_factory::contract_obj,
+        "energy factory path",
+    );
+
+    b_mock.set_block_epoch(5);
+
+    // setup energy factory SC
+    b_mock
+        .execute_tx(&owner_addr, &factory_wrapper, &rust_zero, |sc| {
+            let mut lock_options = MultiValueEncoded::new();
+            for (option, penalty) in LOCK_OPTIONS.iter().zip(PENALTY_PERCENTAGES.iter()) {
+                lock_options.push((*option, *penalty).into());
+            }
+
+            // sc addresses don't matter here, we don't test that part
+            sc.init(
+                managed_token_id!(BASE_ASSET_TOKEN_ID),
+                managed_token_id!(LEGACY_LOCKED_TOKEN_ID),
+                managed_address!(distribution_wrapper.address_ref()),
+                0,
+                lock_options,
+            );
+
+            sc.locked_token()
+                .set_token_id(managed_token_id!(LOCKED_TOKEN_ID));
+            sc.set_paused(false);
+        })
+        .assert_ok();
+    b_mock.set_esdt_local_roles(
+        factory_wrapper.address_ref(),
+        BASE_ASSET_TOKEN_ID,
+        &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+    );
+    b_mock.set_esdt_local_roles(
+        factory_wrapper.address_ref(),
+        LOCKED_TOKEN_ID,
+        &[
+            EsdtLocalRole::NftCreate,
+            EsdtLocalRole::NftAddQuantity,
+            EsdtLocalRole::NftBurn,
+            EsdtLocalRole::Transfer,
+        ],
+    );
+
+    // setup distribution SC
+    b_mock
+        .execute_tx(&owner_addr, &distribution_wrapper, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(BASE_ASSET_TOKEN_ID),
+                managed_address!(factory_wrapper.address_ref()),
+            );
+            sc.set_energy_factory_address(managed_address!(factory_wrapper.address_ref()));
+            sc.set_treasury_address(managed_address!(&treasury_addr));
+        })
+        .assert_ok();
+
+    // leaves 0 and 1 are paired, and leaf 2 is paired with their node
+    let mut leaves = Vec::new();
+    let mut first_node = [0u8; 32];
+    let mut merkle_root = [0u8; 32];
+    b_mock
+        .execute_query(&distribution_wrapper, |sc| {
+            let leaf_hashes: Vec<MerkleHash<DebugApi>> = users
+                .iter()
+                .zip(amounts.iter())
+                .enumerate()
+                .map(|(index, (user, amount))| {
+                    sc.compute_merkle_leaf(
+                        index as u64,
+                        &managed_address!(user),
+                        &managed_biguint!(*amount),
+                    )
+                })
+                .collect();
+            let node = sc.hash_merkle_pair(&leaf_hashes[0], &leaf_hashes[1]);
+            let root = sc.hash_merkle_pair(&node, &leaf_hashes[2]);
+
+            leaves = leaf_hashes
+                .iter()
+                .map(|leaf| leaf.to_byte_array())
+                .collect();
+            first_node = node.to_byte_array();
+            merkle_root = root.to_byte_array();
+        })
+        .assert_ok();
+
+    let total_amount: u64 = amounts.iter().sum();
+    b_mock.set_esdt_balance(
+        &owner_addr,
+        BASE_ASSET_TOKEN_ID,
+        &rust_biguint!(total_amount),
+    );
+    b_mock
+        .execute_esdt_transfer(
+            &owner_addr,
+            &distribution_wrapper,
+            BASE_ASSET_TOKEN_ID,
+            0,
+            &rust_biguint!(total_amount),
+            |sc| {
+                let campaign_id = sc.create_merkle_campaign(
+                    ManagedByteArray::new_from_bytes(&merkle_root),
+                    EXPIRY_EPOCH,
+                    true,
+                );
+                assert_eq!(campaign_id, 1);
+            },
+        )
+        .assert_ok();
+
+    // first user claims the plain tokens
+    b_mock
+        .execute_tx(&users[0], &distribution_wrapper, &rust_zero, |sc| {
+            let _ = sc.claim_merkle_distribution(
+                1,
+                0,
+                managed_biguint!(amounts[0]),
+                0,
+                to_proof(&[leaves[1], leaves[2]]),
+            );
+        })
+        .assert_ok();
+    b_mock.check_esdt_balance(&users[0], BASE_ASSET_TOKEN_ID, &rust_biguint!(amounts[0]));
+
+    b_mock
+        .execute_tx(&users[0], &distribution_wrapper, &rust_zero, |sc| {
+            let _ = sc.claim_merkle_distribution(
+                1,
+                0,
+                managed_biguint!(amounts[0]),
+                0,
+                to_proof(&[leaves[1], leaves[2]]),
+            );
+        })
+        .assert_user_error("Already claimed");
+
+    // second user tries to claim more
+    b_mock
+        .execute_tx(&users[1], &distribution_wrapper, &rust_zero, |sc| {
+            let _ = sc.claim_merkle_distribution(
+                1,
+                1,
+                managed_biguint!(amounts[1] + 1),
+                LOCK_OPTIONS[0],
+                to_proof(&[leaves[0], leaves[2]]),
+            );
+        })
+        .assert_user_error("Invalid proof");
+
+    // second user claims locked tokens
+    b_mock
+        .execute_tx(&users[1], &distribution_wrapper, &rust_zero, |sc| {
+            let _ = sc.claim_merkle_distribution(
+                1,
+                1,
+                managed_biguint!(amounts[1]),
+                LOCK_OPTIONS[0],
+                to_proof(&[leaves[0], leaves[2]]),
+            );
+            assert!(sc.is_merkle_distribution_claimed(1, 1));
+            assert!(!sc.is_merkle_distribution_claimed(1, 2));
+        })
+        .assert_ok();
+    b_mock.check_nft_balance::<simple_lock::locked_token::LockedTokenAttributes<DebugApi>>(
+        &users[1],
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(amounts[1]),
+        None,
+    );
+
+    // third user does not claim in time
+    b_mock
+        .execute_tx(&treasury_addr, &distribution_wrapper, &rust_zero, |sc| {
+            let _ = sc.recover_expired_campaign(1);
+        })
+        .assert_user_error("Campaign not expired");
+
+    b_mock.set_block_epoch(EXPIRY_EPOCH);
+    b_mock
+        .execute_tx(&users[2], &distribution_wrapper, &rust_zero, |sc| {
+            let _ = sc.claim_merkle_distribution(
+                1,
+                2,
+                managed_biguint!(amounts[2]),
+                0,
+                to_proof(&[first_node]),
+            );
+        })
+        .assert_user_error("Campaign expired");
+
+    b_mock
+        .execute_tx(&treasury_addr, &distribution_wrapper, &rust_zero, |sc| {
+            let leftovers = sc.recover_expired_campaign(1);
+            assert_eq!(leftovers, managed_biguint!(amounts[2]));
+        })
+        .assert_ok();
+    b_mock.check_esdt_balance(
+        &treasury_addr,
+        BASE_ASSET_TOKEN_ID,
+        &rust_biguint!(amounts[2]),
+    );
+
+    b_mock
+        .execute_tx(&treasury_addr, &distribution_wrapper, &rust_zero, |sc| {
+            let _ = sc.recover_expired_campaign(1);
+        })
+        .assert_user_error("Leftovers already recovered");
+
+    // a locked-only campaign must distribute the base asset
+    b_mock.set_esdt_balance(&owner_addr, OTHER_TOKEN_ID, &rust_biguint!(total_amount));
+    b_mock
+        .execute_esdt_transfer(
+            &owner_addr,
+            &distribution_wrapper,
+            OTHER_TOKEN_ID,
+            0,
+            &rust_biguint!(total_amount),
+            |sc| {
+                let _ = sc.create_merkle_campaign(
+                    ManagedByteArray::new_from_bytes(&merkle_root),
+                    2 * EXPIRY_EPOCH,
+                    false,
+                );
+            },
+        )
+        .assert_user_error("Only the base asset can be locked");
+
+    // a locked-only campaign rejects the plain claims
+    b_mock.set_esdt_balance(
+        &owner_addr,
+        BASE_ASSET_TOKEN_ID,
+        &rust_biguint!(total_amount),
+    );
+    b_mock
+        .execute_esdt_transfer(
+            &owner_addr,
+            &distribution_wrapper,
+            BASE_ASSET_TOKEN_ID,
+            0,
+            &rust_biguint!(total_amount),
+            |sc| {
+                let campaign_id = sc.create_merkle_campaign(
+                    ManagedByteArray::new_from_bytes(&merkle_root),
+                    2 * EXPIRY_EPOCH,
+                    false,
+                );
+                assert_eq!(campaign_id, 2);
+            },
+        )
+        .assert_ok();
+    b_mock
+        .execute_tx(&users[2], &distribution_wrapper, &rust_zero, |sc| {
+            let _ = sc.claim_merkle_distribution(
+                2,
+                2,
+                managed_biguint!(amounts[2]),
+                0,
+                to_proof(&[first_node]),
+            );
+        })
+        .assert_user_error("Only locked claims are allowed");
+}
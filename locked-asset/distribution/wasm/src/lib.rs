@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           15
+// Endpoints:                           25
 // Async Callback (empty):               1
-// Total number of exported functions:  18
+// Total number of exported functions:  28
 
 #![no_std]
 
@@ -35,6 +35,16 @@ multiversx_sc_wasm_adapter::endpoints! {
         getAssetTokenId => asset_token_id
         startGlobalOperation => global_op_start
         endGlobalOperation => global_op_stop
+        setEnergyFactoryAddress => set_energy_factory_address
+        setTreasuryAddress => set_treasury_address
+        createMerkleCampaign => create_merkle_campaign
+        claimMerkleDistribution => claim_merkle_distribution
+        recoverExpiredCampaign => recover_expired_campaign
+        isMerkleDistributionClaimed => is_merkle_distribution_claimed
+        getMerkleCampaign => merkle_campaign
+        getLastMerkleCampaignId => last_merkle_campaign_id
+        getEnergyFactoryAddress => energy_factory_address
+        getTreasuryAddress => treasury_address
     )
 }
 
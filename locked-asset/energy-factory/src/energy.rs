@@ -142,6 +142,24 @@ impl<M: ManagedTypeApi> Energy<M> {
         self.add_after_token_lock(token_amount, new_unlock_epoch, current_epoch);
     }
 
+    /// Used for the sum of all the users' energy. All the entries must be depleted to the same epoch.
+    pub fn update_total_after_user_energy_change(
+        &mut self,
+        prev_user_energy: &Self,
+        new_user_energy: &Self,
+    ) {
+        self.amount += &new_user_energy.amount;
+        self.amount -= &prev_user_energy.amount;
+
+        // users with energy from before the total was tracked might not be accounted for
+        self.total_locked_tokens += &new_user_energy.total_locked_tokens;
+        if self.total_locked_tokens > prev_user_energy.total_locked_tokens {
+            self.total_locked_tokens -= &prev_user_energy.total_locked_tokens;
+        } else {
+            self.total_locked_tokens = BigUint::zero();
+        }
+    }
+
     #[inline]
     pub fn get_last_update_epoch(&self) -> Epoch {
         self.last_update_epoch
@@ -167,7 +185,9 @@ impl<M: ManagedTypeApi> Energy<M> {
 }
 
 #[multiversx_sc::module]
-pub trait EnergyModule: crate::events::EventsModule {
+pub trait EnergyModule:
+    crate::events::EventsModule + crate::energy_checkpoints::EnergyCheckpointsModule
+{
     fn update_energy<T, F: FnOnce(&mut Energy<Self::Api>) -> T>(
         &self,
         user: &ManagedAddress,
@@ -182,7 +202,15 @@ pub trait EnergyModule: crate::events::EventsModule {
 
     fn set_energy_entry(&self, user: &ManagedAddress, new_energy: Energy<Self::Api>) {
         let prev_energy = self.get_updated_energy_entry_for_user(user);
-        self.user_energy(user).set(&new_energy);
+        let energy_mapper = self.user_energy(user);
+        let opt_stored_energy = if !energy_mapper.is_empty() {
+            Some(energy_mapper.get())
+        } else {
+            None
+        };
+        self.record_energy_checkpoints(user, opt_stored_energy, &new_energy);
+
+        energy_mapper.set(&new_energy);
         self.emit_energy_updated_event(user, prev_energy, new_energy);
     }
 
@@ -207,6 +235,31 @@ pub trait EnergyModule: crate::events::EventsModule {
         energy.get_energy_amount()
     }
 
+    /// The user's energy at the given epoch, based on the recorded checkpoints
+    #[view(getEnergyAmountAtEpoch)]
+    fn get_energy_amount_at_epoch(&self, user: ManagedAddress, epoch: Epoch) -> BigUint {
+        let checkpoints = self.user_energy_checkpoints(&user);
+        if !checkpoints.is_empty() {
+            return self
+                .get_checkpointed_energy_amount(&checkpoints, epoch)
+                .unwrap_or_default();
+        }
+
+        // energy not updated since the checkpoints were introduced
+        let energy_mapper = self.user_energy(&user);
+        if energy_mapper.is_empty() {
+            return BigUint::zero();
+        }
+
+        let mut energy = energy_mapper.get();
+        if energy.get_last_update_epoch() > epoch {
+            return BigUint::zero();
+        }
+
+        energy.deplete(epoch);
+        energy.get_energy_amount()
+    }
+
     #[storage_mapper("userEnergy")]
     fn user_energy(&self, user: &ManagedAddress) -> SingleValueMapper<Energy<Self::Api>>;
 }
@@ -0,0 +1,147 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use common_structs::Epoch;
+
+use crate::energy::Energy;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct EnergyCheckpoint<M: ManagedTypeApi> {
+    pub epoch: Epoch,
+    pub energy: Energy<M>,
+}
+
+/// Keeps the history of the users' energy, and of the total energy, at most one entry per epoch.
+/// Used to find the energy at a past epoch, e.g. for snapshot voting.
+#[multiversx_sc::module]
+pub trait EnergyCheckpointsModule {
+    /// The sum of all the users' energy at the given epoch, based on the recorded checkpoints.
+    /// Returns nothing if the total was not tracked yet at that epoch.
+    #[view(getTotalEnergyAtEpoch)]
+    fn get_total_energy_at_epoch(&self, epoch: Epoch) -> OptionalValue<BigUint> {
+        self.get_checkpointed_energy_amount(&self.total_energy_checkpoints(), epoch)
+            .into()
+    }
+
+    /// prev_energy is the user's energy as it was stored, if any
+    fn record_energy_checkpoints(
+        &self,
+        user: &ManagedAddress,
+        opt_prev_energy: Option<Energy<Self::Api>>,
+        new_energy: &Energy<Self::Api>,
+    ) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut user_checkpoints = self.user_energy_checkpoints(user);
+        let mut prev_energy = Energy::new_zero_energy(current_epoch);
+        if let Some(stored_energy) = opt_prev_energy {
+            // energy entries created before the checkpoints were recorded
+            if user_checkpoints.is_empty() {
+                user_checkpoints.push(&EnergyCheckpoint {
+                    epoch: stored_energy.get_last_update_epoch(),
+                    energy: stored_energy.clone(),
+                });
+            }
+
+            prev_energy = stored_energy;
+            prev_energy.deplete(current_epoch);
+        }
+
+        let mut current_energy = new_energy.clone();
+        current_energy.deplete(current_epoch);
+        self.push_energy_checkpoint(&mut user_checkpoints, current_energy.clone());
+
+        // the total is only tracked once it accounts for everyone's energy,
+        // i.e. from the deploy, or from setTotalEnergyCheckpoint after the upgrade
+        let mut total_checkpoints = self.total_energy_checkpoints();
+        if total_checkpoints.is_empty() {
+            return;
+        }
+
+        let mut total_energy = self.get_last_checkpoint_energy(&total_checkpoints, current_epoch);
+        total_energy.update_total_after_user_energy_change(&prev_energy, &current_energy);
+        self.push_energy_checkpoint(&mut total_checkpoints, total_energy);
+    }
+
+    fn init_total_energy_checkpoints(&self, total_energy: Energy<Self::Api>) {
+        let mut total_checkpoints = self.total_energy_checkpoints();
+        require!(
+            total_checkpoints.is_empty(),
+            "Total energy checkpoints already set"
+        );
+
+        self.push_energy_checkpoint(&mut total_checkpoints, total_energy);
+    }
+
+    fn push_energy_checkpoint(
+        &self,
+        checkpoints: &mut VecMapper<EnergyCheckpoint<Self::Api>>,
+        energy: Energy<Self::Api>,
+    ) {
+        let checkpoint = EnergyCheckpoint {
+            epoch: energy.get_last_update_epoch(),
+            energy,
+        };
+
+        let len = checkpoints.len();
+        if len > 0 && checkpoints.get(len).epoch == checkpoint.epoch {
+            checkpoints.set(len, &checkpoint);
+        } else {
+            checkpoints.push(&checkpoint);
+        }
+    }
+
+    fn get_last_checkpoint_energy(
+        &self,
+        checkpoints: &VecMapper<EnergyCheckpoint<Self::Api>>,
+        current_epoch: Epoch,
+    ) -> Energy<Self::Api> {
+        let len = checkpoints.len();
+        if len == 0 {
+            return Energy::new_zero_energy(current_epoch);
+        }
+
+        let mut energy = checkpoints.get(len).energy;
+        energy.deplete(current_epoch);
+
+        energy
+    }
+
+    /// Returns None if there is no checkpoint at or before the given epoch
+    fn get_checkpointed_energy_amount(
+        &self,
+        checkpoints: &VecMapper<EnergyCheckpoint<Self::Api>>,
+        epoch: Epoch,
+    ) -> Option<BigUint> {
+        // last checkpoint with checkpoint.epoch <= epoch
+        let mut found_index = 0;
+        let mut low = 1;
+        let mut high = checkpoints.len();
+        while low <= high {
+            let mid = (low + high) / 2;
+            if checkpoints.get(mid).epoch <= epoch {
+                found_index = mid;
+                low = mid + 1;
+            } else {
+                high = mid - 1;
+            }
+        }
+
+        if found_index == 0 {
+            return None;
+        }
+
+        let mut energy = checkpoints.get(found_index).energy;
+        energy.deplete(epoch);
+
+        Some(energy.get_energy_amount())
+    }
+
+    #[storage_mapper("userEnergyCheckpoints")]
+    fn user_energy_checkpoints(
+        &self,
+        user: &ManagedAddress,
+    ) -> VecMapper<EnergyCheckpoint<Self::Api>>;
+
+    #[storage_mapper("totalEnergyCheckpoints")]
+    fn total_energy_checkpoints(&self) -> VecMapper<EnergyCheckpoint<Self::Api>>;
+}
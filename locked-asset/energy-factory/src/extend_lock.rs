@@ -16,6 +16,7 @@ pub trait ExtendLockModule:
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + crate::token_whitelist::TokenWhitelistModule
     + crate::energy::EnergyModule
+    + crate::energy_checkpoints::EnergyCheckpointsModule
     + crate::lock_options::LockOptionsModule
     + crate::events::EventsModule
     + crate::migration::SimpleLockMigrationModule
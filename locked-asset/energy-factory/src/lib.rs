@@ -3,6 +3,7 @@
 multiversx_sc::imports!();
 
 pub mod energy;
+pub mod energy_checkpoints;
 pub mod events;
 pub mod extend_lock;
 pub mod local_roles;
@@ -32,6 +33,7 @@ pub trait SimpleLockEnergy:
     + simple_lock::token_attributes::TokenAttributesModule
     + token_whitelist::TokenWhitelistModule
     + energy::EnergyModule
+    + energy_checkpoints::EnergyCheckpointsModule
     + lock_options::LockOptionsModule
     + lock_options_endpoints::LockOptionsEndpointsModule
     + unlock_with_penalty::UnlockWithPenaltyModule
@@ -88,6 +90,9 @@ pub trait SimpleLockEnergy:
         self.old_locked_asset_factory_address()
             .set_if_empty(&old_locked_asset_factory_address);
 
+        let current_epoch = self.blockchain().get_block_epoch();
+        self.init_total_energy_checkpoints(Energy::new_zero_energy(current_epoch));
+
         self.set_paused(true);
     }
 
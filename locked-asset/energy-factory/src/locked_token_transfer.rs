@@ -6,6 +6,7 @@ use crate::energy::Energy;
 pub trait LockedTokenTransferModule:
     utils::UtilsModule
     + crate::energy::EnergyModule
+    + crate::energy_checkpoints::EnergyCheckpointsModule
     + crate::events::EventsModule
     + multiversx_sc_modules::pause::PauseModule
 {
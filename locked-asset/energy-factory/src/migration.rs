@@ -16,12 +16,25 @@ pub trait SimpleLockMigrationModule:
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + crate::token_whitelist::TokenWhitelistModule
     + crate::energy::EnergyModule
+    + crate::energy_checkpoints::EnergyCheckpointsModule
     + crate::events::EventsModule
     + crate::lock_options::LockOptionsModule
     + multiversx_sc_modules::pause::PauseModule
     + utils::UtilsModule
     + legacy_token_decode_module::LegacyTokenDecodeModule
 {
+    /// Sets the total energy, from which on it is tracked, as the sum of all the users' energy.
+    /// Meant to be used once, while paused, right after the upgrade that introduced the checkpoints.
+    #[only_owner]
+    #[endpoint(setTotalEnergyCheckpoint)]
+    fn set_total_energy_checkpoint(&self, amount: BigInt, total_locked_tokens: BigUint) {
+        self.require_paused();
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        let total_energy = Energy::new(amount, current_epoch, total_locked_tokens);
+        self.init_total_energy_checkpoints(total_energy);
+    }
+
     /// Sets the energy amounts and token amounts for users. Overwrites any existing values.
     /// Expects any number of pairs of (user address, token amount, energy amount).
     #[only_owner]
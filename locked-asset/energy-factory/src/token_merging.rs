@@ -61,6 +61,7 @@ pub trait TokenMergingModule:
     + multiversx_sc_modules::pause::PauseModule
     + crate::penalty::LocalPenaltyModule
     + crate::energy::EnergyModule
+    + crate::energy_checkpoints::EnergyCheckpointsModule
     + crate::events::EventsModule
     + crate::lock_options::LockOptionsModule
     + utils::UtilsModule
@@ -22,6 +22,7 @@ pub trait UnlockWithPenaltyModule:
     + simple_lock::token_attributes::TokenAttributesModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + crate::energy::EnergyModule
+    + crate::energy_checkpoints::EnergyCheckpointsModule
     + crate::lock_options::LockOptionsModule
     + crate::events::EventsModule
     + multiversx_sc_modules::pause::PauseModule
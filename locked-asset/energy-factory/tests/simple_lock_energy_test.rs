@@ -3,10 +3,13 @@
 mod energy_factory_setup;
 
 use energy_factory::{
-    energy::EnergyModule, locked_token_transfer::LockedTokenTransferModule, SimpleLockEnergy,
+    energy::EnergyModule, energy_checkpoints::EnergyCheckpointsModule,
+    locked_token_transfer::LockedTokenTransferModule, migration::SimpleLockMigrationModule,
+    SimpleLockEnergy,
 };
 use energy_factory_setup::*;
-use multiversx_sc::types::BigUint;
+use multiversx_sc::types::{BigInt, BigUint};
+use multiversx_sc_modules::pause::PauseModule;
 use simple_lock::locked_token::LockedTokenAttributes;
 
 use multiversx_sc_scenario::{
@@ -566,3 +569,213 @@ fn extend_lock_period_endpoint_test() {
         LOCK_OPTIONS[1] * energy_per_epoch.clone() - energy_per_epoch.clone()
     );
 }
+
+#[test]
+fn energy_checkpoints_test() {
+    let mut setup = SimpleLockEnergySetup::new(energy_factory::contract_obj);
+    let first_user = setup.first_user.clone();
+    let second_user = setup.second_user.clone();
+    let half_balance = USER_BALANCE / 2;
+
+    setup.b_mock.set_block_epoch(1);
+    setup
+        .lock(
+            &first_user,
+            BASE_ASSET_TOKEN_ID,
+            half_balance,
+            LOCK_OPTIONS[0],
+        )
+        .assert_ok();
+    let first_unlock_epoch = to_start_of_month(1 + LOCK_OPTIONS[0]);
+
+    setup.b_mock.set_block_epoch(10);
+    setup
+        .lock(
+            &second_user,
+            BASE_ASSET_TOKEN_ID,
+            half_balance,
+            LOCK_OPTIONS[0],
+        )
+        .assert_ok();
+    let second_unlock_epoch = to_start_of_month(10 + LOCK_OPTIONS[0]);
+
+    // the second lock in the same epoch only updates the epoch's checkpoint
+    setup.b_mock.set_block_epoch(20);
+    setup
+        .lock(
+            &first_user,
+            BASE_ASSET_TOKEN_ID,
+            half_balance / 2,
+            LOCK_OPTIONS[0],
+        )
+        .assert_ok();
+    setup
+        .lock(
+            &first_user,
+            BASE_ASSET_TOKEN_ID,
+            half_balance / 2,
+            LOCK_OPTIONS[0],
+        )
+        .assert_ok();
+    let third_unlock_epoch = to_start_of_month(20 + LOCK_OPTIONS[0]);
+
+    setup.b_mock.set_block_epoch(30);
+    setup
+        .b_mock
+        .execute_query(&setup.sc_wrapper, |sc| {
+            let energy_at = |unlock_epoch: u64, epoch: u64| {
+                managed_biguint!(half_balance) * (unlock_epoch - epoch)
+            };
+
+            assert_eq!(
+                sc.user_energy_checkpoints(&managed_address!(&first_user))
+                    .len(),
+                2
+            );
+            assert_eq!(
+                sc.get_energy_amount_at_epoch(managed_address!(&first_user), 0),
+                managed_biguint!(0)
+            );
+            assert_eq!(
+                sc.get_energy_amount_at_epoch(managed_address!(&first_user), 5),
+                energy_at(first_unlock_epoch, 5)
+            );
+            assert_eq!(
+                sc.get_energy_amount_at_epoch(managed_address!(&second_user), 5),
+                managed_biguint!(0)
+            );
+            assert_eq!(
+                sc.get_energy_amount_at_epoch(managed_address!(&first_user), 25),
+                energy_at(first_unlock_epoch, 25) + energy_at(third_unlock_epoch, 25)
+            );
+            assert_eq!(
+                sc.get_energy_amount_at_epoch(managed_address!(&first_user), 30),
+                sc.get_energy_amount_for_user(managed_address!(&first_user))
+            );
+
+            assert_eq!(
+                sc.get_total_energy_at_epoch(5).into_option().unwrap(),
+                energy_at(first_unlock_epoch, 5)
+            );
+            assert_eq!(
+                sc.get_total_energy_at_epoch(15).into_option().unwrap(),
+                energy_at(first_unlock_epoch, 15) + energy_at(second_unlock_epoch, 15)
+            );
+            assert_eq!(
+                sc.get_total_energy_at_epoch(25).into_option().unwrap(),
+                energy_at(first_unlock_epoch, 25)
+                    + energy_at(second_unlock_epoch, 25)
+                    + energy_at(third_unlock_epoch, 25)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn total_energy_checkpoint_after_upgrade_test() {
+    let mut setup = SimpleLockEnergySetup::new(energy_factory::contract_obj);
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    let second_user = setup.second_user.clone();
+    let half_balance = USER_BALANCE / 2;
+
+    setup.b_mock.set_block_epoch(1);
+    for user in [&first_user, &second_user] {
+        setup
+            .lock(user, BASE_ASSET_TOKEN_ID, half_balance, LOCK_OPTIONS[0])
+            .assert_ok();
+    }
+
+    // state right after the upgrade that introduced the checkpoints
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.total_energy_checkpoints().clear();
+            sc.user_energy_checkpoints(&managed_address!(&first_user))
+                .clear();
+            sc.user_energy_checkpoints(&managed_address!(&second_user))
+                .clear();
+        })
+        .assert_ok();
+
+    // legacy user updating before the total is set
+    setup.b_mock.set_block_epoch(5);
+    setup
+        .lock(
+            &first_user,
+            BASE_ASSET_TOKEN_ID,
+            half_balance / 2,
+            LOCK_OPTIONS[0],
+        )
+        .assert_ok();
+
+    setup.b_mock.set_block_epoch(6);
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_total_energy_checkpoint(BigInt::zero(), BigUint::zero());
+        })
+        .assert_user_error("Contract is not paused");
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_paused(true);
+
+            let mut total_energy_amount = BigInt::zero();
+            let mut total_locked_tokens = BigUint::zero();
+            for user in [&first_user, &second_user] {
+                let energy = sc.get_updated_energy_entry_for_user(&managed_address!(user));
+                total_energy_amount += energy.get_energy_amount_raw();
+                total_locked_tokens += energy.get_total_locked_tokens();
+            }
+            sc.set_total_energy_checkpoint(total_energy_amount, total_locked_tokens);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_total_energy_checkpoint(BigInt::zero(), BigUint::zero());
+        })
+        .assert_user_error("Total energy checkpoints already set");
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_paused(false);
+        })
+        .assert_ok();
+
+    // legacy user updating after the total is set
+    setup.b_mock.set_block_epoch(8);
+    setup
+        .lock(
+            &second_user,
+            BASE_ASSET_TOKEN_ID,
+            half_balance / 2,
+            LOCK_OPTIONS[0],
+        )
+        .assert_ok();
+
+    setup.b_mock.set_block_epoch(10);
+    setup
+        .b_mock
+        .execute_query(&setup.sc_wrapper, |sc| {
+            assert!(sc.get_total_energy_at_epoch(5).into_option().is_none());
+
+            for epoch in [6, 8, 10] {
+                assert_eq!(
+                    sc.get_total_energy_at_epoch(epoch).into_option().unwrap(),
+                    sc.get_energy_amount_at_epoch(managed_address!(&first_user), epoch)
+                        + sc.get_energy_amount_at_epoch(managed_address!(&second_user), epoch)
+                );
+            }
+            assert_eq!(
+                sc.get_total_energy_at_epoch(10).into_option().unwrap(),
+                sc.get_energy_amount_for_user(managed_address!(&first_user))
+                    + sc.get_energy_amount_for_user(managed_address!(&second_user))
+            );
+        })
+        .assert_ok();
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           33
+// Endpoints:                           36
 // Async Callback:                       1
-// Total number of exported functions:  36
+// Total number of exported functions:  39
 
 #![no_std]
 
@@ -29,6 +29,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         getLegacyLockedTokenId => legacy_locked_token_id
         getEnergyEntryForUser => get_updated_energy_entry_for_user
         getEnergyAmountForUser => get_energy_amount_for_user
+        getEnergyAmountAtEpoch => get_energy_amount_at_epoch
+        getTotalEnergyAtEpoch => get_total_energy_at_epoch
         addLockOptions => add_lock_options
         getLockOptions => get_lock_options_view
         unlockEarly => unlock_early
@@ -37,6 +39,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         setTokenUnstakeAddress => set_token_unstake_address
         revertUnstake => revert_unstake
         getTokenUnstakeScAddress => token_unstake_sc_address
+        setTotalEnergyCheckpoint => set_total_energy_checkpoint
         setEnergyForOldTokens => set_energy_for_old_tokens
         updateEnergyAfterOldTokenUnlock => update_energy_after_old_token_unlock
         migrateOldTokens => migrate_old_tokens
@@ -183,6 +183,35 @@ pub trait LkmexTransfer:
         result
     }
 
+    /// Paginated version of getScheduledTransfers, over the senders.
+    /// Withdrawn or canceled transfers can make the pages read in between
+    /// skip or repeat senders, see pagination::Page.
+    #[view(getScheduledTransfersPaginated)]
+    fn get_scheduled_transfers_paginated(
+        &self,
+        receiver: ManagedAddress,
+        start_index: usize,
+        limit: usize,
+    ) -> pagination::PaginatedResult<Self::Api, ScheduledTransfer<Self::Api>> {
+        let all_senders = self.all_senders(&receiver);
+        let page = pagination::get_page(start_index, limit, all_senders.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in page.indexes {
+            // the set mapper indexes start from 1
+            let sender = all_senders.get_by_index(index + 1);
+            let locked_funds = self.locked_funds(&receiver, &sender).get();
+            let scheduled_transfer = ScheduledTransfer {
+                sender,
+                locked_funds,
+            };
+
+            result.push(scheduled_transfer);
+        }
+
+        (page.next_index, result).into()
+    }
+
     #[storage_mapper("lockedFunds")]
     fn locked_funds(
         &self,
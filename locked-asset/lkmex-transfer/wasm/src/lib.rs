@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           11
+// Endpoints:                           12
 // Async Callback (empty):               1
-// Total number of exported functions:  14
+// Total number of exported functions:  15
 
 #![no_std]
 
@@ -24,6 +24,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         cancelTransfer => cancel_transfer
         lockFunds => lock_funds
         getScheduledTransfers => get_scheduled_transfers
+        getScheduledTransfersPaginated => get_scheduled_transfers_paginated
         getAllSenders => all_senders
         setEnergyFactoryAddress => set_energy_factory_address
         getEnergyFactoryAddress => energy_factory_address
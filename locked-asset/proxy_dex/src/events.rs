@@ -55,6 +55,24 @@ pub struct CompoundRewardsProxyEvent<M: ManagedTypeApi> {
     new_wrapped_farm_attributes: WrappedFarmTokenAttributes<M>,
 }
 
+#[derive(TypeAbi, TopEncode)]
+pub struct SwapProxyEvent<M: ManagedTypeApi> {
+    locked_tokens_in: EsdtTokenPayment<M>,
+    tokens_out: EgldOrEsdtTokenPayment<M>,
+    unlock_epoch: u64,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct MigrateFarmProxyEvent<M: ManagedTypeApi> {
+    target_farm_address: ManagedAddress<M>,
+    old_wrapped_farm_token: EsdtTokenPayment<M>,
+    old_wrapped_farm_attributes: WrappedFarmTokenAttributes<M>,
+    new_wrapped_farm_token: EsdtTokenPayment<M>,
+    new_wrapped_farm_attributes: WrappedFarmTokenAttributes<M>,
+    reward_tokens: EsdtTokenPayment<M>,
+    boosted_reward_tokens: EsdtTokenPayment<M>,
+}
+
 #[multiversx_sc::module]
 pub trait EventsModule {
     fn emit_add_liquidity_proxy_event(
@@ -221,6 +239,64 @@ pub trait EventsModule {
         )
     }
 
+    fn emit_swap_proxy_event(
+        self,
+        caller: &ManagedAddress,
+        pair_address: &ManagedAddress,
+        locked_tokens_in: EsdtTokenPayment,
+        tokens_out: EgldOrEsdtTokenPayment,
+        unlock_epoch: u64,
+    ) {
+        let epoch = self.blockchain().get_block_epoch();
+        let block = self.blockchain().get_block_nonce();
+        let timestamp = self.blockchain().get_block_timestamp();
+        self.swap_proxy_event(
+            caller,
+            pair_address,
+            epoch,
+            block,
+            timestamp,
+            &SwapProxyEvent {
+                locked_tokens_in,
+                tokens_out,
+                unlock_epoch,
+            },
+        )
+    }
+
+    fn emit_migrate_farm_proxy_event(
+        self,
+        caller: &ManagedAddress,
+        farm_address: &ManagedAddress,
+        target_farm_address: ManagedAddress,
+        old_wrapped_farm_token: EsdtTokenPayment,
+        old_wrapped_farm_attributes: WrappedFarmTokenAttributes<Self::Api>,
+        new_wrapped_farm_token: EsdtTokenPayment,
+        new_wrapped_farm_attributes: WrappedFarmTokenAttributes<Self::Api>,
+        reward_tokens: EsdtTokenPayment,
+        boosted_reward_tokens: EsdtTokenPayment,
+    ) {
+        let epoch = self.blockchain().get_block_epoch();
+        let block = self.blockchain().get_block_nonce();
+        let timestamp = self.blockchain().get_block_timestamp();
+        self.migrate_farm_proxy_event(
+            caller,
+            farm_address,
+            epoch,
+            block,
+            timestamp,
+            &MigrateFarmProxyEvent {
+                target_farm_address,
+                old_wrapped_farm_token,
+                old_wrapped_farm_attributes,
+                new_wrapped_farm_token,
+                new_wrapped_farm_attributes,
+                reward_tokens,
+                boosted_reward_tokens,
+            },
+        )
+    }
+
     #[event("add_liquidity_proxy")]
     fn add_liquidity_proxy_event(
         self,
@@ -286,4 +362,26 @@ pub trait EventsModule {
         #[indexed] timestamp: u64,
         compound_rewards_farm_proxy_event: &CompoundRewardsProxyEvent<Self::Api>,
     );
+
+    #[event("swap_proxy")]
+    fn swap_proxy_event(
+        self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] pair_address: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+        swap_proxy_event: &SwapProxyEvent<Self::Api>,
+    );
+
+    #[event("migrate_farm_proxy")]
+    fn migrate_farm_proxy_event(
+        self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] farm_address: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+        migrate_farm_proxy_event: &MigrateFarmProxyEvent<Self::Api>,
+    );
 }
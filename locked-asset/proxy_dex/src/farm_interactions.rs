@@ -2,6 +2,7 @@ multiversx_sc::imports!();
 
 use farm::{
     base_functions::{ClaimRewardsResultType, ClaimRewardsResultWrapper},
+    migration::MigrateToFarmResultType,
     EnterFarmResultType, ExitFarmWithPartialPosResultType,
 };
 use farm_with_locked_rewards::ProxyTrait as _;
@@ -16,6 +17,12 @@ pub struct ExitFarmResultWrapper<M: ManagedTypeApi> {
     pub reward_tokens: EsdtTokenPayment<M>,
 }
 
+pub struct MigrateToFarmResultWrapper<M: ManagedTypeApi> {
+    pub new_farm_token: EsdtTokenPayment<M>,
+    pub rewards: EsdtTokenPayment<M>,
+    pub boosted_rewards: EsdtTokenPayment<M>,
+}
+
 #[multiversx_sc::module]
 pub trait FarmInteractionsModule {
     fn call_enter_farm(
@@ -77,6 +84,27 @@ pub trait FarmInteractionsModule {
         }
     }
 
+    fn call_migrate_to_farm(
+        &self,
+        user: ManagedAddress,
+        farm_address: ManagedAddress,
+        target_farm_address: ManagedAddress,
+        farm_token: EsdtTokenPayment,
+    ) -> MigrateToFarmResultWrapper<Self::Api> {
+        let raw_result: MigrateToFarmResultType<Self::Api> = self
+            .farm_contract_proxy(farm_address)
+            .migrate_to_farm_endpoint(target_farm_address, OptionalValue::Some(user))
+            .with_esdt_transfer(farm_token)
+            .execute_on_dest_context();
+        let (new_farm_token, rewards, boosted_rewards) = raw_result.into_tuple();
+
+        MigrateToFarmResultWrapper {
+            new_farm_token,
+            rewards,
+            boosted_rewards,
+        }
+    }
+
     #[proxy]
     fn farm_contract_proxy(&self, to: ManagedAddress)
         -> farm_with_locked_rewards::Proxy<Self::Api>;
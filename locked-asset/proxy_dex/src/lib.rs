@@ -12,6 +12,7 @@ pub mod pair_interactions;
 pub mod proxy_common;
 pub mod proxy_farm;
 pub mod proxy_pair;
+pub mod proxy_swap;
 pub mod wrapped_farm_attributes;
 pub mod wrapped_farm_token_merge;
 pub mod wrapped_lp_attributes;
@@ -22,6 +23,7 @@ pub trait ProxyDexImpl:
     proxy_common::ProxyCommonModule
     + crate::other_sc_whitelist::OtherScWhitelistModule
     + proxy_pair::ProxyPairModule
+    + proxy_swap::ProxySwapModule
     + pair_interactions::PairInteractionsModule
     + proxy_farm::ProxyFarmModule
     + farm_interactions::FarmInteractionsModule
@@ -4,6 +4,7 @@ use pair::pair_actions::{
     add_liq::ProxyTrait as _,
     common_result_types::{AddLiquidityResultType, RemoveLiquidityResultType},
     remove_liq::ProxyTrait as _,
+    swap::ProxyTrait as _,
 };
 
 pub struct AddLiquidityResultWrapper<M: ManagedTypeApi> {
@@ -87,6 +88,23 @@ pub trait PairInteractionsModule {
         }
     }
 
+    fn call_swap_fixed_input(
+        &self,
+        pair_address: ManagedAddress,
+        token_in: EsdtTokenPayment,
+        token_out: TokenIdentifier,
+        amount_out_min: BigUint,
+    ) -> EsdtTokenPayment {
+        self.pair_contract_proxy(pair_address)
+            .swap_tokens_fixed_input(
+                token_out,
+                amount_out_min,
+                OptionalValue::<ManagedAddress>::None,
+            )
+            .with_esdt_transfer(token_in)
+            .execute_on_dest_context()
+    }
+
     #[proxy]
     fn pair_contract_proxy(&self, to: ManagedAddress) -> pair::Proxy<Self::Api>;
 }
@@ -21,6 +21,8 @@ pub struct EnterFarmResult<M: ManagedTypeApi> {
 pub type EnterFarmProxyResultType<M> = MultiValue2<EsdtTokenPayment<M>, EsdtTokenPayment<M>>;
 pub type ExitFarmProxyResultType<M> = MultiValue2<EsdtTokenPayment<M>, EsdtTokenPayment<M>>;
 pub type ClaimRewardsFarmProxyResultType<M> = MultiValue2<EsdtTokenPayment<M>, EsdtTokenPayment<M>>;
+pub type MigrateFarmProxyResultType<M> =
+    MultiValue3<EsdtTokenPayment<M>, EsdtTokenPayment<M>, EsdtTokenPayment<M>>;
 
 #[multiversx_sc::module]
 pub trait ProxyFarmModule:
@@ -340,6 +342,74 @@ pub trait ProxyFarmModule:
         (new_wrapped_token, claim_result.rewards).into()
     }
 
+    /// Migrates the farm position behind the wrapped farm tokens to the target farm,
+    /// without exit penalty. Both farms must be intermediated, and the target farm
+    /// must be a migration target of the current farm.
+    /// Returns the new wrapped farm tokens, the rewards and the boosted rewards of the target farm.
+    #[payable("*")]
+    #[endpoint(migrateFarmProxy)]
+    fn migrate_farm_proxy(
+        &self,
+        farm_address: ManagedAddress,
+        target_farm_address: ManagedAddress,
+        opt_original_caller: OptionalValue<ManagedAddress>,
+    ) -> MigrateFarmProxyResultType<Self::Api> {
+        self.require_is_intermediated_farm(&farm_address);
+        self.require_is_intermediated_farm(&target_farm_address);
+        self.require_wrapped_farm_token_id_not_empty();
+        self.require_wrapped_lp_token_id_not_empty();
+
+        let wrapped_farm_token_mapper = self.wrapped_farm_token();
+        let payment = self.call_value().single_esdt();
+        wrapped_farm_token_mapper.require_same_token(&payment.token_identifier);
+
+        let wrapped_farm_attributes: WrappedFarmTokenAttributes<Self::Api> =
+            self.get_attributes_as_part_of_fixed_supply(&payment, &wrapped_farm_token_mapper);
+
+        let caller = self.blockchain().get_caller();
+        let original_caller = self.get_orig_caller_from_opt(&caller, opt_original_caller);
+
+        let migrate_result = self.call_migrate_to_farm(
+            original_caller.clone(),
+            farm_address.clone(),
+            target_farm_address.clone(),
+            wrapped_farm_attributes.farm_token.clone(),
+        );
+
+        let new_wrapped_farm_attributes = WrappedFarmTokenAttributes {
+            farm_token: migrate_result.new_farm_token,
+            proxy_farming_token: wrapped_farm_attributes.proxy_farming_token.clone(),
+        };
+        let new_token_amount = new_wrapped_farm_attributes.get_total_supply();
+        let new_wrapped_token =
+            wrapped_farm_token_mapper.nft_create(new_token_amount, &new_wrapped_farm_attributes);
+
+        self.send_payment_non_zero(&caller, &new_wrapped_token);
+        self.send_payment_non_zero(&caller, &migrate_result.rewards);
+        self.send_payment_non_zero(&caller, &migrate_result.boosted_rewards);
+
+        wrapped_farm_token_mapper.nft_burn(payment.token_nonce, &payment.amount);
+
+        self.emit_migrate_farm_proxy_event(
+            &original_caller,
+            &farm_address,
+            target_farm_address,
+            payment,
+            wrapped_farm_attributes,
+            new_wrapped_token.clone(),
+            new_wrapped_farm_attributes,
+            migrate_result.rewards.clone(),
+            migrate_result.boosted_rewards.clone(),
+        );
+
+        (
+            new_wrapped_token,
+            migrate_result.rewards,
+            migrate_result.boosted_rewards,
+        )
+            .into()
+    }
+
     #[payable("*")]
     #[endpoint(increaseProxyFarmTokenEnergy)]
     fn increase_proxy_farm_token_energy_endpoint(&self, lock_epochs: Epoch) -> EsdtTokenPayment {
@@ -0,0 +1,96 @@
+multiversx_sc::imports!();
+
+use simple_lock::{locked_token::LockedTokenAttributes, ProxyTrait as _};
+
+#[multiversx_sc::module]
+pub trait ProxySwapModule:
+    crate::proxy_common::ProxyCommonModule
+    + crate::other_sc_whitelist::OtherScWhitelistModule
+    + crate::pair_interactions::PairInteractionsModule
+    + crate::energy_update::EnergyUpdateModule
+    + crate::events::EventsModule
+    + energy_query::EnergyQueryModule
+    + utils::UtilsModule
+    + legacy_token_decode_module::LegacyTokenDecodeModule
+{
+    #[only_owner]
+    #[endpoint(setSimpleLockAddress)]
+    fn set_simple_lock_address(&self, simple_lock_address: ManagedAddress) {
+        self.require_sc_address(&simple_lock_address);
+        self.simple_lock_address().set(simple_lock_address);
+    }
+
+    /// Swaps the underlying base asset of the locked tokens through the given intermediated pair.
+    /// The output tokens are locked through the simple-lock contract, until the same unlock epoch.
+    /// The energy of the locked tokens is removed from the caller.
+    ///
+    /// Only locked tokens of the energy factory are accepted.
+    #[payable("*")]
+    #[endpoint(swapProxy)]
+    fn swap_proxy(
+        &self,
+        pair_address: ManagedAddress,
+        token_out: TokenIdentifier,
+        amount_out_min: BigUint,
+    ) -> EgldOrEsdtTokenPayment {
+        self.require_is_intermediated_pair(&pair_address);
+        require!(
+            !self.simple_lock_address().is_empty(),
+            "Simple lock address not set"
+        );
+
+        let payment = self.call_value().single_esdt();
+        let locked_token_id = self.get_locked_token_id();
+        require!(
+            payment.token_identifier == locked_token_id,
+            "Only energy factory locked tokens can be swapped"
+        );
+
+        let attributes: LockedTokenAttributes<Self::Api> = self
+            .blockchain()
+            .get_token_attributes(&payment.token_identifier, payment.token_nonce);
+
+        let caller = self.blockchain().get_caller();
+        self.burn_locked_tokens_and_update_energy(
+            &payment.token_identifier,
+            payment.token_nonce,
+            &payment.amount,
+            &caller,
+        );
+
+        let asset_token_id = self.get_base_token_id();
+        self.send()
+            .esdt_local_mint(&asset_token_id, 0, &payment.amount);
+
+        let swapped_tokens = self.call_swap_fixed_input(
+            pair_address.clone(),
+            EsdtTokenPayment::new(asset_token_id, 0, payment.amount.clone()),
+            token_out,
+            amount_out_min,
+        );
+
+        let simple_lock_address = self.simple_lock_address().get();
+        let output_tokens: EgldOrEsdtTokenPayment = self
+            .simple_lock_proxy(simple_lock_address)
+            .lock_tokens_endpoint(attributes.unlock_epoch, OptionalValue::Some(caller.clone()))
+            .with_esdt_transfer(swapped_tokens)
+            .execute_on_dest_context();
+
+        self.emit_swap_proxy_event(
+            &caller,
+            &pair_address,
+            payment,
+            output_tokens.clone(),
+            attributes.unlock_epoch,
+        );
+
+        output_tokens
+    }
+
+    #[proxy]
+    fn simple_lock_proxy(&self, sc_address: ManagedAddress) -> simple_lock::Proxy<Self::Api>;
+
+    #[view(getSimpleLockAddress)]
+    #[storage_mapper("simpleLockAddress")]
+    fn simple_lock_address(&self) -> SingleValueMapper<ManagedAddress>;
+}
@@ -107,6 +107,7 @@ where
             &mut b_mock,
             &owner,
             farm_locked_builder,
+            FARM_LOCKED_TOKEN_ID,
             simple_lock_wrapper.address_ref(),
         );
         let proxy_wrapper = setup_proxy(
@@ -286,10 +287,11 @@ where
     pair_wrapper
 }
 
-fn setup_farm_locked<FarmLockedObjBuilder>(
+pub fn setup_farm_locked<FarmLockedObjBuilder>(
     b_mock: &mut BlockchainStateWrapper,
     owner: &Address,
     farm_builder: FarmLockedObjBuilder,
+    farm_token_id: &[u8],
     simple_lock_addr: &Address,
 ) -> ContractObjWrapper<farm_with_locked_rewards::ContractObj<DebugApi>, FarmLockedObjBuilder>
 where
@@ -319,8 +321,8 @@ where
                 MultiValueEncoded::new(),
             );
 
-            let farm_token_id = managed_token_id!(FARM_LOCKED_TOKEN_ID);
-            sc.farm_token().set_token_id(farm_token_id);
+            sc.farm_token()
+                .set_token_id(managed_token_id!(farm_token_id));
 
             sc.per_block_reward_amount()
                 .set(&managed_biguint!(PER_BLOCK_REWARD_AMOUNT));
@@ -349,7 +351,7 @@ where
     ];
     b_mock.set_esdt_local_roles(
         farm_wrapper.address_ref(),
-        FARM_LOCKED_TOKEN_ID,
+        farm_token_id,
         &farm_token_roles[..],
     );
 
@@ -7,10 +7,11 @@ use config::ConfigModule;
 use energy_factory::{energy::EnergyModule, SimpleLockEnergy};
 use energy_query::Energy;
 use farm::exit_penalty::DEFAULT_PENALTY_PERCENT;
+use farm::migration::FarmMigrationModule;
 use farm::MAX_PERCENT;
 use multiversx_sc::{
     codec::{multi_types::OptionalValue, Empty},
-    types::{BigInt, EsdtLocalRole, EsdtTokenPayment},
+    types::{BigInt, EsdtLocalRole, EsdtTokenPayment, MultiValueEncoded},
 };
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_token_id, managed_token_id_wrapped, rust_biguint,
@@ -18,14 +19,17 @@ use multiversx_sc_scenario::{
 };
 use num_traits::ToPrimitive;
 use proxy_dex::{
-    proxy_farm::ProxyFarmModule, proxy_pair::ProxyPairModule,
-    wrapped_farm_attributes::WrappedFarmTokenAttributes,
+    other_sc_whitelist::OtherScWhitelistModule, proxy_farm::ProxyFarmModule,
+    proxy_pair::ProxyPairModule, wrapped_farm_attributes::WrappedFarmTokenAttributes,
     wrapped_farm_token_merge::WrappedFarmTokenMerge,
     wrapped_lp_attributes::WrappedLpTokenAttributes,
 };
 use proxy_dex_test_setup::*;
+use sc_whitelist_module::SCWhitelistModule;
 use simple_lock::locked_token::LockedTokenAttributes;
 
+static NEW_FARM_LOCKED_TOKEN_ID: &[u8] = b"NFARML-123456";
+
 #[test]
 fn farm_proxy_setup_test() {
     let _ = ProxySetup::new(
@@ -2761,3 +2765,197 @@ fn total_farm_position_migration_mechanism_test() {
         })
         .assert_ok();
 }
+
+#[test]
+fn migrate_farm_proxy_test() {
+    let mut setup = ProxySetup::new(
+        proxy_dex::contract_obj,
+        pair::contract_obj,
+        farm_with_locked_rewards::contract_obj,
+        energy_factory::contract_obj,
+    );
+    let rust_zero = rust_biguint!(0u64);
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    let farm_addr = setup.farm_locked_wrapper.address_ref().clone();
+
+    // setup the new farm
+    let new_farm_wrapper = setup_farm_locked(
+        &mut setup.b_mock,
+        &owner,
+        farm_with_locked_rewards::contract_obj,
+        NEW_FARM_LOCKED_TOKEN_ID,
+        setup.simple_lock_wrapper.address_ref(),
+    );
+    let new_farm_addr = new_farm_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .execute_tx(&owner, &new_farm_wrapper, &rust_zero, |sc| {
+            sc.add_sc_address_to_whitelist(managed_address!(&farm_addr));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.simple_lock_wrapper, &rust_zero, |sc| {
+            sc.add_sc_address_to_whitelist(managed_address!(&new_farm_addr));
+        })
+        .assert_ok();
+
+    // user enters the old farm through the proxy
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.proxy_wrapper,
+            LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(USER_BALANCE),
+            |sc| {
+                sc.enter_farm_proxy_endpoint(managed_address!(&farm_addr), OptionalValue::None);
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.set_block_epoch(2);
+    setup.b_mock.set_block_nonce(100);
+
+    // new farm is not intermediated
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.proxy_wrapper,
+            WRAPPED_FARM_TOKEN_ID,
+            1,
+            &rust_biguint!(USER_BALANCE),
+            |sc| {
+                let _ = sc.migrate_farm_proxy(
+                    managed_address!(&farm_addr),
+                    managed_address!(&new_farm_addr),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Not an intermediated farm");
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.proxy_wrapper, &rust_zero, |sc| {
+            sc.intermediated_farms()
+                .insert(managed_address!(&new_farm_addr));
+        })
+        .assert_ok();
+
+    // new farm is not a migration target of the old farm
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.proxy_wrapper,
+            WRAPPED_FARM_TOKEN_ID,
+            1,
+            &rust_biguint!(USER_BALANCE),
+            |sc| {
+                let _ = sc.migrate_farm_proxy(
+                    managed_address!(&farm_addr),
+                    managed_address!(&new_farm_addr),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Invalid migration target farm");
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.farm_locked_wrapper, &rust_zero, |sc| {
+            let mut farm_addresses = MultiValueEncoded::new();
+            farm_addresses.push(managed_address!(&new_farm_addr));
+            sc.add_migration_target_farms(farm_addresses);
+        })
+        .assert_ok();
+
+    // migrate before the minimum farming epochs passed, no penalty is applied
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.proxy_wrapper,
+            WRAPPED_FARM_TOKEN_ID,
+            1,
+            &rust_biguint!(USER_BALANCE),
+            |sc| {
+                let _ = sc.migrate_farm_proxy(
+                    managed_address!(&farm_addr),
+                    managed_address!(&new_farm_addr),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    // check user balance
+    setup.b_mock.check_nft_balance::<Empty>(
+        &first_user,
+        WRAPPED_FARM_TOKEN_ID,
+        1,
+        &rust_zero,
+        None,
+    );
+    setup.b_mock.check_nft_balance(
+        &first_user,
+        WRAPPED_FARM_TOKEN_ID,
+        2,
+        &rust_biguint!(USER_BALANCE),
+        Some(&WrappedFarmTokenAttributes::<DebugApi> {
+            proxy_farming_token: EsdtTokenPayment {
+                token_identifier: managed_token_id!(LOCKED_TOKEN_ID),
+                token_nonce: 1,
+                amount: managed_biguint!(USER_BALANCE),
+            },
+            farm_token: EsdtTokenPayment {
+                token_identifier: managed_token_id!(NEW_FARM_LOCKED_TOKEN_ID),
+                token_nonce: 1,
+                amount: managed_biguint!(USER_BALANCE),
+            },
+        }),
+    );
+    // locked rewards have the same unlock epoch as the user's tokens, so the nonce is reused
+    setup.b_mock.check_nft_balance::<Empty>(
+        &first_user,
+        LOCKED_TOKEN_ID,
+        1,
+        &(rust_biguint!(PER_BLOCK_REWARD_AMOUNT) * 100u32),
+        None,
+    );
+
+    // check proxy and farm balances
+    setup
+        .b_mock
+        .check_nft_balance::<FarmTokenAttributes<DebugApi>>(
+            setup.proxy_wrapper.address_ref(),
+            NEW_FARM_LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(USER_BALANCE),
+            None,
+        );
+    setup.b_mock.check_esdt_balance(
+        setup.farm_locked_wrapper.address_ref(),
+        MEX_TOKEN_ID,
+        &rust_zero,
+    );
+    setup
+        .b_mock
+        .check_esdt_balance(&new_farm_addr, MEX_TOKEN_ID, &rust_biguint!(USER_BALANCE));
+
+    // the user's total farm position moved to the new farm
+    setup
+        .b_mock
+        .execute_query(&new_farm_wrapper, |sc| {
+            assert_eq!(
+                sc.user_total_farm_position(&managed_address!(&first_user))
+                    .get(),
+                managed_biguint!(USER_BALANCE)
+            );
+        })
+        .assert_ok();
+}
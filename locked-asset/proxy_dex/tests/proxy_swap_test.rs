@@ -0,0 +1,276 @@
+#![allow(deprecated)]
+
+mod proxy_dex_test_setup;
+
+use energy_factory::energy::EnergyModule;
+use energy_query::Energy;
+use multiversx_sc::{
+    codec::Empty,
+    storage::mappers::StorageTokenWrapper,
+    types::{BigInt, EgldOrEsdtTokenIdentifier, EsdtLocalRole},
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, managed_token_id_wrapped, rust_biguint,
+    whitebox_legacy::TxTokenTransfer, DebugApi,
+};
+use proxy_dex::{proxy_pair::ProxyPairModule, proxy_swap::ProxySwapModule};
+use proxy_dex_test_setup::*;
+use simple_lock::{
+    locked_token::{LockedTokenAttributes, LockedTokenModule},
+    SimpleLock,
+};
+
+const SIMPLE_LOCK_TOKEN_ID: &[u8] = b"SLOCK-123456";
+
+#[test]
+fn swap_proxy_test() {
+    let mut setup = ProxySetup::new(
+        proxy_dex::contract_obj,
+        pair::contract_obj,
+        farm_with_locked_rewards::contract_obj,
+        energy_factory::contract_obj,
+    );
+    let rust_zero = rust_biguint!(0u64);
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    let second_user = setup.second_user.clone();
+    let pair_addr = setup.pair_wrapper.address_ref().clone();
+    let full_balance = rust_biguint!(USER_BALANCE);
+    let swap_amount = 1_000u64;
+    let expected_wegld_amount = 498u64;
+
+    // second user adds liquidity, 1 EGLD = 2 MEX
+    let payments = vec![
+        TxTokenTransfer {
+            token_identifier: LOCKED_TOKEN_ID.to_vec(),
+            nonce: 2,
+            value: rust_biguint!(1_000_000_000u64),
+        },
+        TxTokenTransfer {
+            token_identifier: WEGLD_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: rust_biguint!(500_000_000u64),
+        },
+    ];
+    setup
+        .b_mock
+        .execute_esdt_multi_transfer(&second_user, &setup.proxy_wrapper, &payments, |sc| {
+            sc.add_liquidity_proxy(
+                managed_address!(&pair_addr),
+                managed_biguint!(1_000_000_000u64),
+                managed_biguint!(500_000_000u64),
+            );
+        })
+        .assert_ok();
+
+    // swap without simple lock address set
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.proxy_wrapper,
+            LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(swap_amount),
+            |sc| {
+                let _ = sc.swap_proxy(
+                    managed_address!(&pair_addr),
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    managed_biguint!(1u64),
+                );
+            },
+        )
+        .assert_user_error("Simple lock address not set");
+
+    // setup simple lock
+    let simple_lock_wrapper = setup.b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        simple_lock::contract_obj,
+        "simple lock",
+    );
+    setup
+        .b_mock
+        .execute_tx(&owner, &simple_lock_wrapper, &rust_zero, |sc| {
+            sc.init();
+            sc.locked_token()
+                .set_token_id(managed_token_id!(SIMPLE_LOCK_TOKEN_ID));
+        })
+        .assert_ok();
+    setup.b_mock.set_esdt_local_roles(
+        simple_lock_wrapper.address_ref(),
+        SIMPLE_LOCK_TOKEN_ID,
+        &[
+            EsdtLocalRole::NftCreate,
+            EsdtLocalRole::NftAddQuantity,
+            EsdtLocalRole::NftBurn,
+        ],
+    );
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.proxy_wrapper, &rust_zero, |sc| {
+            sc.set_simple_lock_address(managed_address!(simple_lock_wrapper.address_ref()));
+        })
+        .assert_ok();
+
+    // slippage check
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.proxy_wrapper,
+            LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(swap_amount),
+            |sc| {
+                let _ = sc.swap_proxy(
+                    managed_address!(&pair_addr),
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    managed_biguint!(expected_wegld_amount + 1),
+                );
+            },
+        )
+        .assert_user_error("Slippage exceeded");
+
+    // swap locked MEX for locked WEGLD
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.proxy_wrapper,
+            LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(swap_amount),
+            |sc| {
+                let output_tokens = sc.swap_proxy(
+                    managed_address!(&pair_addr),
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    managed_biguint!(expected_wegld_amount),
+                );
+                assert_eq!(
+                    output_tokens.token_identifier,
+                    EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(SIMPLE_LOCK_TOKEN_ID))
+                );
+                assert_eq!(
+                    output_tokens.amount,
+                    managed_biguint!(expected_wegld_amount)
+                );
+            },
+        )
+        .assert_ok();
+
+    // check user balances
+    setup.b_mock.check_nft_balance::<Empty>(
+        &first_user,
+        LOCKED_TOKEN_ID,
+        1,
+        &(&full_balance - &rust_biguint!(swap_amount)),
+        None,
+    );
+    setup.b_mock.check_nft_balance(
+        &first_user,
+        SIMPLE_LOCK_TOKEN_ID,
+        1,
+        &rust_biguint!(expected_wegld_amount),
+        Some(&LockedTokenAttributes::<DebugApi> {
+            original_token_id: managed_token_id_wrapped!(WEGLD_TOKEN_ID),
+            original_token_nonce: 0,
+            unlock_epoch: LOCK_OPTIONS[0],
+        }),
+    );
+
+    // check energy was removed for the swapped tokens
+    setup
+        .b_mock
+        .execute_query(&setup.simple_lock_wrapper, |sc| {
+            let block_epoch = 1u64;
+            let lock_epochs = LOCK_OPTIONS[0] - block_epoch;
+            let expected_energy_amount = BigInt::from((USER_BALANCE - swap_amount) as i64)
+                * BigInt::from(lock_epochs as i64);
+            let expected_energy = Energy::new(
+                expected_energy_amount,
+                block_epoch,
+                managed_biguint!(USER_BALANCE - swap_amount),
+            );
+            let actual_energy =
+                sc.get_updated_energy_entry_for_user(&managed_address!(&first_user));
+            assert_eq!(expected_energy, actual_energy);
+        })
+        .assert_ok();
+
+    // non-locked tokens are rejected
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.proxy_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(swap_amount),
+            |sc| {
+                let _ = sc.swap_proxy(
+                    managed_address!(&pair_addr),
+                    managed_token_id!(MEX_TOKEN_ID),
+                    managed_biguint!(1u64),
+                );
+            },
+        )
+        .assert_user_error("Only energy factory locked tokens can be swapped");
+
+    // tokens are unlocked directly, once the unlock epoch has passed
+    setup.b_mock.set_block_epoch(LOCK_OPTIONS[0]);
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.proxy_wrapper,
+            LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(swap_amount),
+            |sc| {
+                let _ = sc.swap_proxy(
+                    managed_address!(&pair_addr),
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    managed_biguint!(1u64),
+                );
+            },
+        )
+        .assert_ok();
+    setup.b_mock.check_nft_balance::<Empty>(
+        &first_user,
+        SIMPLE_LOCK_TOKEN_ID,
+        1,
+        &rust_biguint!(expected_wegld_amount),
+        None,
+    );
+}
+
+#[test]
+fn swap_proxy_non_intermediated_pair_test() {
+    let mut setup = ProxySetup::new(
+        proxy_dex::contract_obj,
+        pair::contract_obj,
+        farm_with_locked_rewards::contract_obj,
+        energy_factory::contract_obj,
+    );
+    let first_user = setup.first_user.clone();
+    let farm_addr = setup.farm_locked_wrapper.address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.proxy_wrapper,
+            LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(1_000u64),
+            |sc| {
+                let _ = sc.swap_proxy(
+                    managed_address!(&farm_addr),
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    managed_biguint!(1u64),
+                );
+            },
+        )
+        .assert_user_error("Not an intermediated pair");
+}
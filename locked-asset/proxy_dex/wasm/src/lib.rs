@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           30
+// Endpoints:                           34
 // Async Callback:                       1
-// Total number of exported functions:  33
+// Total number of exported functions:  37
 
 #![no_std]
 
@@ -39,9 +39,13 @@ multiversx_sc_wasm_adapter::endpoints! {
         addLiquidityProxy => add_liquidity_proxy
         removeLiquidityProxy => remove_liquidity_proxy
         increaseProxyPairTokenEnergy => increase_proxy_pair_token_energy_endpoint
+        setSimpleLockAddress => set_simple_lock_address
+        swapProxy => swap_proxy
+        getSimpleLockAddress => simple_lock_address
         enterFarmProxy => enter_farm_proxy_endpoint
         exitFarmProxy => exit_farm_proxy
         claimRewardsProxy => claim_rewards_proxy
+        migrateFarmProxy => migrate_farm_proxy
         increaseProxyFarmTokenEnergy => increase_proxy_farm_token_energy_endpoint
         mergeWrappedFarmTokens => merge_wrapped_farm_tokens_endpoint
         mergeWrappedLpTokens => merge_wrapped_lp_tokens_endpoint
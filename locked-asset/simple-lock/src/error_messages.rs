@@ -10,3 +10,8 @@ pub static MUST_USE_DIFFERENT_TOKENS_ERR_MSG: &[u8] = b"Tokens must be different
 pub static INVALID_SC_ADDRESS_ERR_MSG: &[u8] = b"Invalid SC address";
 pub static LP_REMOVAL_WRONG_PAIR: &[u8] =
     b"Liquidty Pool address does not match the given token pair";
+pub static INVALID_UNLOCK_SCHEDULE_ERR_MSG: &[u8] = b"Invalid unlock schedule";
+pub static NOTHING_TO_UNLOCK_ERR_MSG: &[u8] = b"Nothing to unlock";
+pub static CANNOT_UNLOCK_EARLIER_ERR_MSG: &[u8] = b"New schedule cannot unlock earlier";
+pub static MUST_HAVE_SAME_ORIGINAL_TOKEN_ERR_MSG: &[u8] =
+    b"All locked tokens must have the same original token";
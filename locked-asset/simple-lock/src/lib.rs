@@ -9,6 +9,7 @@ pub mod locked_token;
 pub mod lp_interactions;
 pub mod proxy_farm;
 pub mod proxy_lp;
+pub mod schedule_lock;
 pub mod token_attributes;
 
 #[multiversx_sc::contract]
@@ -21,6 +22,7 @@ pub trait SimpleLock:
     + lp_interactions::LpInteractionsModule
     + farm_interactions::FarmInteractionsModule
     + token_attributes::TokenAttributesModule
+    + schedule_lock::ScheduleLockModule
 {
     #[init]
     fn init(&self) {}
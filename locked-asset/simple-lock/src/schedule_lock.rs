@@ -0,0 +1,390 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use common_structs::{
+    Epoch, EpochAmountPair, UnlockMilestoneEx, UnlockScheduleEx, MAX_MILESTONES_IN_SCHEDULE,
+    PERCENTAGE_TOTAL_EX,
+};
+
+use crate::error_messages::*;
+
+pub type UnlockMilestoneArg = MultiValue2<Epoch, u64>;
+pub type UnlockResultType<M> = MultiValue2<EgldOrEsdtTokenPayment<M>, EsdtTokenPayment<M>>;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedDecode, NestedEncode, PartialEq, Debug, Clone)]
+pub struct ScheduleLockedTokenAttributes<M: ManagedTypeApi> {
+    pub original_token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub original_token_nonce: u64,
+    pub unlock_schedule: UnlockScheduleEx<M>,
+}
+
+#[multiversx_sc::module]
+pub trait ScheduleLockModule:
+    crate::token_attributes::TokenAttributesModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueScheduleLockedToken)]
+    fn issue_schedule_locked_token(
+        &self,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+        num_decimals: usize,
+    ) {
+        let payment_amount = self.call_value().egld_value().clone_value();
+
+        self.schedule_locked_token().issue_and_set_all_roles(
+            EsdtTokenType::Meta,
+            payment_amount,
+            token_display_name,
+            token_ticker,
+            num_decimals,
+            None,
+        );
+    }
+
+    /// Locks any token (including EGLD) according to the given unlock schedule,
+    /// as a list of (unlock_epoch, unlock_percent) pairs, with percentages out of 100_000.
+    /// Epochs must be strictly increasing, and the percentages must add up to 100_000.
+    /// The part of the schedule that already matured is sent unlocked.
+    /// The tokens are sent to the given destination, which may be the caller.
+    ///
+    /// Output payments: the unlocked tokens and the SCHEDULE LOCKED tokens, either of them may be empty
+    #[payable("*")]
+    #[endpoint(lockTokensWithSchedule)]
+    fn lock_tokens_with_schedule(
+        &self,
+        destination: ManagedAddress,
+        unlock_milestones: MultiValueEncoded<UnlockMilestoneArg>,
+    ) -> UnlockResultType<Self::Api> {
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(payment.amount > 0, NO_PAYMENT_ERR_MSG);
+
+        let unlock_schedule = self.build_unlock_schedule(unlock_milestones);
+        let attributes = ScheduleLockedTokenAttributes {
+            original_token_id: payment.token_identifier,
+            original_token_nonce: payment.token_nonce,
+            unlock_schedule,
+        };
+
+        self.lock_with_schedule_and_send(&destination, payment.amount, attributes)
+    }
+
+    /// Unlocks the matured part of the SCHEDULE LOCKED tokens.
+    /// The rest is locked again, with the remaining milestones.
+    ///
+    /// Output payments: the unlocked tokens and the remaining SCHEDULE LOCKED tokens
+    #[payable("*")]
+    #[endpoint(unlockScheduledTokens)]
+    fn unlock_scheduled_tokens(
+        &self,
+        opt_destination: OptionalValue<ManagedAddress>,
+    ) -> UnlockResultType<Self::Api> {
+        let payment = self.call_value().single_esdt();
+        let attributes = self.burn_schedule_locked_tokens(&payment);
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            self.get_matured_percent(&attributes.unlock_schedule, current_epoch) > 0,
+            NOTHING_TO_UNLOCK_ERR_MSG
+        );
+
+        let dest_address = self.schedule_lock_dest(opt_destination);
+        self.lock_with_schedule_and_send(&dest_address, payment.amount, attributes)
+    }
+
+    /// Replaces the unlock schedule of the SCHEDULE LOCKED tokens with a longer one.
+    /// At any epoch, the new schedule must not have unlocked more than the old one,
+    /// and all the new milestones must be in the future.
+    #[payable("*")]
+    #[endpoint(extendScheduledLock)]
+    fn extend_scheduled_lock(
+        &self,
+        unlock_milestones: MultiValueEncoded<UnlockMilestoneArg>,
+    ) -> EsdtTokenPayment {
+        let payment = self.call_value().single_esdt();
+        let mut attributes = self.burn_schedule_locked_tokens(&payment);
+
+        let new_schedule = self.build_unlock_schedule(unlock_milestones);
+        let current_epoch = self.blockchain().get_block_epoch();
+        let first_milestone = new_schedule.unlock_milestones.get(0);
+        require!(
+            first_milestone.unlock_epoch > current_epoch,
+            INVALID_UNLOCK_SCHEDULE_ERR_MSG
+        );
+
+        for milestone in &new_schedule.unlock_milestones {
+            let old_percent =
+                self.get_matured_percent(&attributes.unlock_schedule, milestone.unlock_epoch);
+            let new_percent = self.get_matured_percent(&new_schedule, milestone.unlock_epoch);
+            require!(new_percent <= old_percent, CANNOT_UNLOCK_EARLIER_ERR_MSG);
+        }
+
+        attributes.unlock_schedule = new_schedule;
+        let caller = self.blockchain().get_caller();
+        let (_, locked_payment) = self
+            .lock_with_schedule_and_send(&caller, payment.amount, attributes)
+            .into_tuple();
+
+        locked_payment
+    }
+
+    /// Merges SCHEDULE LOCKED tokens with the same original token into a single position.
+    /// Each milestone of the new schedule unlocks the same amount of tokens
+    /// as the merged positions would have unlocked at that epoch. Any matured part is sent unlocked.
+    #[payable("*")]
+    #[endpoint(mergeScheduledTokens)]
+    fn merge_scheduled_tokens(&self) -> EsdtTokenPayment {
+        let payments = self.call_value().all_esdt_transfers().clone_value();
+        require!(payments.len() > 1, INVALID_PAYMENTS_ERR_MSG);
+
+        let mut opt_original_token: Option<(EgldOrEsdtTokenIdentifier, u64)> = None;
+        let mut epoch_amounts: ManagedVec<EpochAmountPair<Self::Api>> = ManagedVec::new();
+        let mut total_amount = BigUint::zero();
+        for payment in &payments {
+            let attributes = self.burn_schedule_locked_tokens(&payment);
+            match &opt_original_token {
+                Some((token_id, token_nonce)) => require!(
+                    token_id == &attributes.original_token_id
+                        && *token_nonce == attributes.original_token_nonce,
+                    MUST_HAVE_SAME_ORIGINAL_TOKEN_ERR_MSG
+                ),
+                None => {
+                    opt_original_token = Some((
+                        attributes.original_token_id.clone(),
+                        attributes.original_token_nonce,
+                    ))
+                }
+            }
+
+            let mut amount_processed = BigUint::zero();
+            let last_index = attributes.unlock_schedule.unlock_milestones.len() - 1;
+            for (i, milestone) in attributes
+                .unlock_schedule
+                .unlock_milestones
+                .iter()
+                .enumerate()
+            {
+                let milestone_amount = if i == last_index {
+                    &payment.amount - &amount_processed
+                } else {
+                    &payment.amount * milestone.unlock_percent / PERCENTAGE_TOTAL_EX
+                };
+                amount_processed += &milestone_amount;
+
+                self.add_epoch_amount(&mut epoch_amounts, milestone.unlock_epoch, milestone_amount);
+            }
+
+            total_amount += &payment.amount;
+        }
+
+        require!(
+            epoch_amounts.len() <= MAX_MILESTONES_IN_SCHEDULE,
+            INVALID_UNLOCK_SCHEDULE_ERR_MSG
+        );
+
+        // rounding leftovers go to the last milestone, so nothing unlocks earlier
+        let mut unlock_milestones = ManagedVec::new();
+        let mut total_percent = 0;
+        let last_index = epoch_amounts.len() - 1;
+        for (i, epoch_amount) in epoch_amounts.iter().enumerate() {
+            let mut unlock_percent = (epoch_amount.amount * PERCENTAGE_TOTAL_EX / &total_amount)
+                .to_u64()
+                .unwrap_or_default();
+            if i == last_index {
+                unlock_percent = PERCENTAGE_TOTAL_EX - total_percent;
+            } else if unlock_percent == 0 {
+                continue;
+            }
+            total_percent += unlock_percent;
+
+            unlock_milestones.push(UnlockMilestoneEx {
+                unlock_epoch: epoch_amount.epoch,
+                unlock_percent,
+            });
+        }
+        let unlock_schedule = UnlockScheduleEx { unlock_milestones };
+
+        let (original_token_id, original_token_nonce) = opt_original_token.unwrap();
+        let attributes = ScheduleLockedTokenAttributes {
+            original_token_id,
+            original_token_nonce,
+            unlock_schedule,
+        };
+        let caller = self.blockchain().get_caller();
+        let (_, locked_payment) = self
+            .lock_with_schedule_and_send(&caller, total_amount, attributes)
+            .into_tuple();
+
+        locked_payment
+    }
+
+    /// The amount of original tokens that can be unlocked for the given SCHEDULE LOCKED tokens
+    #[view(getScheduledUnlockableAmount)]
+    fn get_scheduled_unlockable_amount(&self, token_nonce: u64, amount: BigUint) -> BigUint {
+        let attributes: ScheduleLockedTokenAttributes<Self::Api> = self
+            .schedule_locked_token()
+            .get_token_attributes(token_nonce);
+        let current_epoch = self.blockchain().get_block_epoch();
+        let matured_percent = self.get_matured_percent(&attributes.unlock_schedule, current_epoch);
+
+        amount * matured_percent / PERCENTAGE_TOTAL_EX
+    }
+
+    fn build_unlock_schedule(
+        &self,
+        unlock_milestones: MultiValueEncoded<UnlockMilestoneArg>,
+    ) -> UnlockScheduleEx<Self::Api> {
+        require!(
+            !unlock_milestones.is_empty() && unlock_milestones.len() <= MAX_MILESTONES_IN_SCHEDULE,
+            INVALID_UNLOCK_SCHEDULE_ERR_MSG
+        );
+
+        let mut milestones = ManagedVec::new();
+        let mut last_epoch = 0;
+        for milestone in unlock_milestones {
+            let (unlock_epoch, unlock_percent) = milestone.into_tuple();
+            require!(
+                unlock_epoch > last_epoch && unlock_percent > 0,
+                INVALID_UNLOCK_SCHEDULE_ERR_MSG
+            );
+            last_epoch = unlock_epoch;
+
+            milestones.push(UnlockMilestoneEx {
+                unlock_epoch,
+                unlock_percent,
+            });
+        }
+
+        let unlock_schedule = UnlockScheduleEx {
+            unlock_milestones: milestones,
+        };
+        require!(
+            unlock_schedule.get_total_percent() == PERCENTAGE_TOTAL_EX,
+            INVALID_UNLOCK_SCHEDULE_ERR_MSG
+        );
+
+        unlock_schedule
+    }
+
+    fn burn_schedule_locked_tokens(
+        &self,
+        payment: &EsdtTokenPayment,
+    ) -> ScheduleLockedTokenAttributes<Self::Api> {
+        require!(payment.amount > 0, NO_PAYMENT_ERR_MSG);
+
+        let schedule_locked_token_mapper = self.schedule_locked_token();
+        schedule_locked_token_mapper.require_same_token(&payment.token_identifier);
+
+        let attributes = schedule_locked_token_mapper.get_token_attributes(payment.token_nonce);
+        schedule_locked_token_mapper.nft_burn(payment.token_nonce, &payment.amount);
+
+        attributes
+    }
+
+    /// Sends the matured part of the amount as the original token,
+    /// and locks the rest with the remaining milestones.
+    fn lock_with_schedule_and_send(
+        &self,
+        to: &ManagedAddress,
+        amount: BigUint,
+        mut attributes: ScheduleLockedTokenAttributes<Self::Api>,
+    ) -> UnlockResultType<Self::Api> {
+        let schedule_locked_token_id = self.schedule_locked_token().get_token_id();
+        let current_epoch = self.blockchain().get_block_epoch();
+        let matured_percent = self.get_matured_percent(&attributes.unlock_schedule, current_epoch);
+        let unlocked_amount = &amount * matured_percent / PERCENTAGE_TOTAL_EX;
+        let locked_amount = amount - &unlocked_amount;
+
+        let unlocked_payment = EgldOrEsdtTokenPayment::new(
+            attributes.original_token_id.clone(),
+            attributes.original_token_nonce,
+            unlocked_amount,
+        );
+        if unlocked_payment.amount > 0 {
+            self.send().direct(
+                to,
+                &unlocked_payment.token_identifier,
+                unlocked_payment.token_nonce,
+                &unlocked_payment.amount,
+            );
+        }
+
+        if locked_amount == 0 {
+            let locked_payment = EsdtTokenPayment::new(schedule_locked_token_id, 0, locked_amount);
+            return (unlocked_payment, locked_payment).into();
+        }
+
+        attributes
+            .unlock_schedule
+            .clear_unlockable_entries(current_epoch);
+        attributes.unlock_schedule.reallocate_percentages();
+
+        let schedule_locked_token_mapper = self.schedule_locked_token();
+        let token_name = attributes.original_token_id.clone().into_name();
+        let sft_nonce = self.get_or_create_nonce_for_attributes(
+            &schedule_locked_token_mapper,
+            &token_name,
+            &attributes,
+        );
+        let locked_payment =
+            schedule_locked_token_mapper.nft_add_quantity_and_send(to, sft_nonce, locked_amount);
+
+        (unlocked_payment, locked_payment).into()
+    }
+
+    fn get_matured_percent(
+        &self,
+        unlock_schedule: &UnlockScheduleEx<Self::Api>,
+        epoch: Epoch,
+    ) -> u64 {
+        let mut matured_percent = 0;
+        for milestone in &unlock_schedule.unlock_milestones {
+            if milestone.unlock_epoch <= epoch {
+                matured_percent += milestone.unlock_percent;
+            }
+        }
+
+        matured_percent
+    }
+
+    fn add_epoch_amount(
+        &self,
+        epoch_amounts: &mut ManagedVec<EpochAmountPair<Self::Api>>,
+        epoch: Epoch,
+        amount: BigUint,
+    ) {
+        let mut new_epoch_amounts = ManagedVec::new();
+        let mut opt_new_entry = Some(EpochAmountPair { epoch, amount });
+        for mut epoch_amount in epoch_amounts.iter() {
+            if let Some(new_entry) = opt_new_entry.take() {
+                if epoch_amount.epoch == epoch {
+                    epoch_amount.amount += new_entry.amount;
+                } else if epoch_amount.epoch > epoch {
+                    new_epoch_amounts.push(new_entry);
+                } else {
+                    opt_new_entry = Some(new_entry);
+                }
+            }
+
+            new_epoch_amounts.push(epoch_amount);
+        }
+        if let Some(new_entry) = opt_new_entry {
+            new_epoch_amounts.push(new_entry);
+        }
+
+        *epoch_amounts = new_epoch_amounts;
+    }
+
+    fn schedule_lock_dest(&self, opt_destination: OptionalValue<ManagedAddress>) -> ManagedAddress {
+        match opt_destination {
+            OptionalValue::Some(dest) => dest,
+            OptionalValue::None => self.blockchain().get_caller(),
+        }
+    }
+
+    #[view(getScheduleLockedTokenId)]
+    #[storage_mapper("scheduleLockedTokenId")]
+    fn schedule_locked_token(&self) -> NonFungibleTokenMapper;
+}
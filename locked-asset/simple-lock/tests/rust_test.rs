@@ -1,16 +1,21 @@
 #![allow(deprecated)]
 
+use common_structs::{UnlockMilestoneEx, UnlockScheduleEx};
 use multiversx_sc::codec::multi_types::OptionalValue;
-use multiversx_sc::types::EsdtLocalRole;
-use multiversx_sc_scenario::{managed_biguint, managed_token_id_wrapped, whitebox_legacy::*};
+use multiversx_sc::types::{EsdtLocalRole, ManagedVec, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id_wrapped, whitebox_legacy::*,
+};
 use multiversx_sc_scenario::{managed_token_id, rust_biguint, DebugApi};
 
 use multiversx_sc::storage::mappers::StorageTokenWrapper;
 use simple_lock::locked_token::*;
+use simple_lock::schedule_lock::*;
 use simple_lock::SimpleLock;
 
 const FREE_TOKEN_ID: &[u8] = b"FREEEEE-123456";
 const LOCKED_TOKEN_ID: &[u8] = b"NOOO0-123456";
+const SCHEDULE_LOCKED_TOKEN_ID: &[u8] = b"SCHED-123456";
 
 #[test]
 fn lock_unlock_test() {
@@ -162,3 +167,245 @@ fn lock_unlock_test() {
         )
         .assert_ok();
 }
+
+#[test]
+fn schedule_lock_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+
+    let user_addr = b_mock.create_user_account(&rust_zero);
+    let owner_addr = b_mock.create_user_account(&rust_zero);
+    let sc_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner_addr),
+        simple_lock::contract_obj,
+        "Some path",
+    );
+
+    b_mock.set_block_epoch(5);
+
+    b_mock
+        .execute_tx(&owner_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.init();
+            sc.schedule_locked_token()
+                .set_token_id(managed_token_id!(SCHEDULE_LOCKED_TOKEN_ID));
+        })
+        .assert_ok();
+
+    b_mock.set_esdt_local_roles(
+        sc_wrapper.address_ref(),
+        SCHEDULE_LOCKED_TOKEN_ID,
+        &[
+            EsdtLocalRole::NftCreate,
+            EsdtLocalRole::NftAddQuantity,
+            EsdtLocalRole::NftBurn,
+        ],
+    );
+
+    b_mock.set_esdt_balance(&user_addr, FREE_TOKEN_ID, &rust_biguint!(2_000));
+
+    // percentages must add up to 100%
+    b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &sc_wrapper,
+            FREE_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let mut milestones = MultiValueEncoded::new();
+                milestones.push((10, 25_000).into());
+                milestones.push((20, 50_000).into());
+                let _ = sc.lock_tokens_with_schedule(managed_address!(&user_addr), milestones);
+            },
+        )
+        .assert_user_error("Invalid unlock schedule");
+
+    // lock 25% until epoch 10, and 75% until epoch 20
+    b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &sc_wrapper,
+            FREE_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let mut milestones = MultiValueEncoded::new();
+                milestones.push((10, 25_000).into());
+                milestones.push((20, 75_000).into());
+                let (unlocked_payment, locked_payment) = sc
+                    .lock_tokens_with_schedule(managed_address!(&user_addr), milestones)
+                    .into_tuple();
+                assert_eq!(unlocked_payment.amount, managed_biguint!(0));
+                assert_eq!(locked_payment.token_nonce, 1);
+                assert_eq!(locked_payment.amount, managed_biguint!(1_000));
+            },
+        )
+        .assert_ok();
+
+    b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &sc_wrapper,
+            SCHEDULE_LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(1_000),
+            |sc| {
+                let _ = sc.unlock_scheduled_tokens(OptionalValue::None);
+            },
+        )
+        .assert_user_error("Nothing to unlock");
+
+    // unlock the first milestone
+    b_mock.set_block_epoch(10);
+    b_mock
+        .execute_query(&sc_wrapper, |sc| {
+            assert_eq!(
+                sc.get_scheduled_unlockable_amount(1, managed_biguint!(1_000)),
+                managed_biguint!(250)
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &sc_wrapper,
+            SCHEDULE_LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(1_000),
+            |sc| {
+                let _ = sc.unlock_scheduled_tokens(OptionalValue::None);
+            },
+        )
+        .assert_ok();
+    b_mock.check_esdt_balance(&user_addr, FREE_TOKEN_ID, &rust_biguint!(1_250));
+
+    DebugApi::dummy();
+    b_mock.check_nft_balance(
+        &user_addr,
+        SCHEDULE_LOCKED_TOKEN_ID,
+        2,
+        &rust_biguint!(750),
+        Some(&schedule_attributes(&[(20, 100_000)])),
+    );
+
+    // cannot unlock anything earlier than the current schedule
+    b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &sc_wrapper,
+            SCHEDULE_LOCKED_TOKEN_ID,
+            2,
+            &rust_biguint!(750),
+            |sc| {
+                let mut milestones = MultiValueEncoded::new();
+                milestones.push((15, 10_000).into());
+                milestones.push((30, 90_000).into());
+                let _ = sc.extend_scheduled_lock(milestones);
+            },
+        )
+        .assert_user_error("New schedule cannot unlock earlier");
+
+    b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &sc_wrapper,
+            SCHEDULE_LOCKED_TOKEN_ID,
+            2,
+            &rust_biguint!(750),
+            |sc| {
+                let mut milestones = MultiValueEncoded::new();
+                milestones.push((25, 50_000).into());
+                milestones.push((30, 50_000).into());
+                let locked_payment = sc.extend_scheduled_lock(milestones);
+                assert_eq!(locked_payment.token_nonce, 3);
+            },
+        )
+        .assert_ok();
+
+    // lock some more, then merge the two positions
+    b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &sc_wrapper,
+            FREE_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let mut milestones = MultiValueEncoded::new();
+                milestones.push((12, 50_000).into());
+                milestones.push((30, 50_000).into());
+                let _ = sc.lock_tokens_with_schedule(managed_address!(&user_addr), milestones);
+            },
+        )
+        .assert_ok();
+
+    let merge_payments = [
+        TxTokenTransfer {
+            token_identifier: SCHEDULE_LOCKED_TOKEN_ID.to_vec(),
+            nonce: 3,
+            value: rust_biguint!(750),
+        },
+        TxTokenTransfer {
+            token_identifier: SCHEDULE_LOCKED_TOKEN_ID.to_vec(),
+            nonce: 4,
+            value: rust_biguint!(1_000),
+        },
+    ];
+    b_mock
+        .execute_esdt_multi_transfer(&user_addr, &sc_wrapper, &merge_payments, |sc| {
+            let locked_payment = sc.merge_scheduled_tokens();
+            assert_eq!(locked_payment.token_nonce, 5);
+            assert_eq!(locked_payment.amount, managed_biguint!(1_750));
+        })
+        .assert_ok();
+
+    // 500 tokens at epoch 12, 375 at epoch 25 and 875 at epoch 30
+    b_mock.check_nft_balance(
+        &user_addr,
+        SCHEDULE_LOCKED_TOKEN_ID,
+        5,
+        &rust_biguint!(1_750),
+        Some(&schedule_attributes(&[
+            (12, 28_571),
+            (25, 21_428),
+            (30, 50_001),
+        ])),
+    );
+
+    // the whole position unlocks after the last milestone
+    b_mock.set_block_epoch(30);
+    b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &sc_wrapper,
+            SCHEDULE_LOCKED_TOKEN_ID,
+            5,
+            &rust_biguint!(1_750),
+            |sc| {
+                let (unlocked_payment, locked_payment) =
+                    sc.unlock_scheduled_tokens(OptionalValue::None).into_tuple();
+                assert_eq!(unlocked_payment.amount, managed_biguint!(1_750));
+                assert_eq!(locked_payment.amount, managed_biguint!(0));
+            },
+        )
+        .assert_ok();
+    b_mock.check_esdt_balance(&user_addr, FREE_TOKEN_ID, &rust_biguint!(2_000));
+}
+
+fn schedule_attributes(milestones: &[(u64, u64)]) -> ScheduleLockedTokenAttributes<DebugApi> {
+    let mut unlock_milestones = ManagedVec::new();
+    for (unlock_epoch, unlock_percent) in milestones {
+        unlock_milestones.push(UnlockMilestoneEx {
+            unlock_epoch: *unlock_epoch,
+            unlock_percent: *unlock_percent,
+        });
+    }
+
+    ScheduleLockedTokenAttributes {
+        original_token_id: managed_token_id_wrapped!(FREE_TOKEN_ID),
+        original_token_nonce: 0,
+        unlock_schedule: UnlockScheduleEx { unlock_milestones },
+    }
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           19
+// Endpoints:                           26
 // Async Callback:                       1
-// Total number of exported functions:  22
+// Total number of exported functions:  29
 
 #![no_std]
 
@@ -39,6 +39,13 @@ multiversx_sc_wasm_adapter::endpoints! {
         farmClaimRewardsLockedToken => farm_claim_rewards_locked_token
         getKnownFarms => known_farms
         getFarmProxyTokenId => farm_proxy_token
+        issueScheduleLockedToken => issue_schedule_locked_token
+        lockTokensWithSchedule => lock_tokens_with_schedule
+        unlockScheduledTokens => unlock_scheduled_tokens
+        extendScheduledLock => extend_scheduled_lock
+        mergeScheduledTokens => merge_scheduled_tokens
+        getScheduledUnlockableAmount => get_scheduled_unlockable_amount
+        getScheduleLockedTokenId => schedule_locked_token
     )
 }
 
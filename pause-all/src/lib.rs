@@ -149,6 +149,26 @@ pub trait PauseAll:
     #[proxy]
     fn pause_proxy(&self, addr: ManagedAddress) -> pause_proxy::Proxy<Self::Api>;
 
+    /// Paginated version of getPausableContracts.
+    /// Removing contracts between the calls can make the pages skip or repeat some, see pagination::Page.
+    #[view(getPausableContractsPaginated)]
+    fn get_pausable_contracts_paginated(
+        &self,
+        start_index: usize,
+        limit: usize,
+    ) -> pagination::PaginatedResult<Self::Api, ManagedAddress> {
+        let mapper = self.pausable_contracts();
+        let page = pagination::get_page(start_index, limit, mapper.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in page.indexes {
+            // the set mapper indexes start from 1
+            result.push(mapper.get_by_index(index + 1));
+        }
+
+        (page.next_index, result).into()
+    }
+
     #[view(getPausableContracts)]
     #[storage_mapper("pausableContracts")]
     fn pausable_contracts(&self) -> UnorderedSetMapper<ManagedAddress>;
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            7
+// Endpoints:                            8
 // Async Callback (empty):               1
-// Total number of exported functions:  10
+// Total number of exported functions:  11
 
 #![no_std]
 
@@ -26,6 +26,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         pauseAll => pause_all
         resumeSelected => resume_selected
         resumeAll => resume_all
+        getPausableContractsPaginated => get_pausable_contracts_paginated
         getPausableContracts => pausable_contracts
     )
 }